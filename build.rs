@@ -0,0 +1,24 @@
+// /build.rs
+// Purpose: Generation of the C header of the C API (OUT_DIR/vrconnect.h, and
+// include/vrconnect.h when VRC_GENERATE_HEADER=1)
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/ffi");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+    println!("cargo:rerun-if-env-changed=VRC_GENERATE_HEADER");
+
+    let crate_dir = std::env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not set");
+    let out_dir = std::env::var("OUT_DIR").expect("OUT_DIR not set");
+    let config = cbindgen::Config::from_file(format!("{}/cbindgen.toml", crate_dir)).unwrap_or_default();
+
+    match cbindgen::Builder::new().with_crate(&crate_dir).with_config(config).generate() {
+        Ok(bindings) => {
+            // The source tree is only written on request (it may be read-only)
+            bindings.write_to_file(format!("{}/vrconnect.h", out_dir));
+            if std::env::var("VRC_GENERATE_HEADER").is_ok_and(|value| value == "1") {
+                bindings.write_to_file(format!("{}/include/vrconnect.h", crate_dir));
+            }
+        }
+        Err(e) => println!("cargo:warning=C header not generated: {}", e),
+    }
+}
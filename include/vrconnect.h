@@ -0,0 +1,409 @@
+#ifndef VRCONNECT_H
+#define VRCONNECT_H
+
+/* Generated by cbindgen from src/ffi, do not edit */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Size of the name fields, including the terminating NUL
+#define VRC_NAME_LEN 64
+
+// Size of the unit field, including the terminating NUL
+#define VRC_UNIT_LEN 16
+
+// Size of the message field, including the terminating NUL
+#define VRC_MESSAGE_LEN 256
+
+typedef enum VrcAlarmAction {
+  VRC_ALARM_ACTION_RAISED,
+  VRC_ALARM_ACTION_CLEARED,
+  VRC_ALARM_ACTION_ESCALATED,
+  VRC_ALARM_ACTION_ACKNOWLEDGED,
+  VRC_ALARM_ACTION_SILENCED,
+} VrcAlarmAction;
+
+typedef enum VrcAlarmCondition {
+  VRC_ALARM_CONDITION_LOW,
+  VRC_ALARM_CONDITION_HIGH,
+  VRC_ALARM_CONDITION_DATA_STALE,
+  VRC_ALARM_CONDITION_SENSOR_OFF,
+  VRC_ALARM_CONDITION_CLOCK_DRIFT,
+} VrcAlarmCondition;
+
+typedef enum VrcAlarmPriority {
+  VRC_ALARM_PRIORITY_LOW,
+  VRC_ALARM_PRIORITY_MEDIUM,
+  VRC_ALARM_PRIORITY_HIGH,
+} VrcAlarmPriority;
+
+// ID SRS: SRS-MOD-FFITYPES-001
+// Title: VrcStatus
+//
+// Description: VRConnect shall report the outcome of C API calls as status
+// codes, with details available from `vrc_last_error`.
+//
+// Version: V1.0
+typedef enum VrcStatus {
+  VRC_STATUS_OK = 0,
+  VRC_STATUS_INVALID_ARGUMENT = -1,
+  VRC_STATUS_NOT_RUNNING = -2,
+  VRC_STATUS_ALREADY_RUNNING = -3,
+  VRC_STATUS_QUEUE_FULL = -4,
+  VRC_STATUS_FAILED = -5,
+} VrcStatus;
+
+// ID SRS: SRS-MOD-FFIAPI-001
+// Title: VrcProcessor
+//
+// Description: VRConnect shall expose an embedded processor to C callers as
+// an opaque handle.
+//
+// Version: V1.0
+typedef struct VrcProcessor VrcProcessor;
+
+// ID SRS: SRS-MOD-FFITYPES-002
+// Title: VrcValue
+//
+// Description: VRConnect shall provide the latest numeric value of a track
+// as a plain C structure (composite values such as NIBP as one value per
+// component, e.g. "NIBP.systolic").
+//
+// Version: V1.0
+typedef struct VrcValue {
+  char device_id[VRC_NAME_LEN];
+  char room_name[VRC_NAME_LEN];
+  char track_name[VRC_NAME_LEN];
+  char unit[VRC_UNIT_LEN];
+  double value;
+  // Milliseconds since the Unix epoch
+  int64_t timestamp_ms;
+} VrcValue;
+
+// ID SRS: SRS-MOD-FFITYPES-004
+// Title: VrcEvent
+//
+// Description: VRConnect shall provide an alarm event as a plain C
+// structure; missing values and limits are NaN.
+//
+// Version: V1.0
+typedef struct VrcEvent {
+  char device_id[VRC_NAME_LEN];
+  char room_name[VRC_NAME_LEN];
+  char parameter[VRC_NAME_LEN];
+  enum VrcAlarmCondition condition;
+  enum VrcAlarmAction action;
+  enum VrcAlarmPriority priority;
+  double value;
+  double limit;
+  bool silenced;
+  char message[VRC_MESSAGE_LEN];
+  // Milliseconds since the Unix epoch
+  int64_t timestamp_ms;
+} VrcEvent;
+
+// ID SRS: SRS-MOD-FFITYPES-003
+// Title: VrcWaveform
+//
+// Description: VRConnect shall provide a waveform segment as a plain C
+// structure; `points` is valid during the callback, or points to the
+// caller buffer when polled.
+//
+// Version: V1.0
+typedef struct VrcWaveform {
+  char device_id[VRC_NAME_LEN];
+  char room_name[VRC_NAME_LEN];
+  char track_name[VRC_NAME_LEN];
+  char unit[VRC_UNIT_LEN];
+  // Samples per second, 0 if unknown
+  double sample_rate;
+  // Milliseconds since the Unix epoch
+  int64_t timestamp_ms;
+  const double *points;
+  size_t point_count;
+} VrcWaveform;
+
+// Called with every updated value
+typedef void (*VrcValueCallback)(const struct VrcValue *value, void *user_data);
+
+// Called with every alarm event
+typedef void (*VrcEventCallback)(const struct VrcEvent *event, void *user_data);
+
+// Called with every waveform segment
+typedef void (*VrcWaveformCallback)(const struct VrcWaveform *waveform, void *user_data);
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// ID SRS: SRS-FN-FFIAPI-001
+// Title: vrc_create
+//
+// Description: VRConnect shall create a processor from command-line style
+// arguments (same options and environment variables as the executable).
+//
+// Version: V1.0
+//
+// # Arguments
+// * `argc` - Number of arguments, 0 for the defaults
+// * `argv` - Arguments, without the program name (may be NULL if argc is 0)
+//
+// # Returns
+// Processor handle, or NULL on invalid arguments
+//
+// # Safety
+// `argv` must point to `argc` NUL-terminated strings.
+struct VrcProcessor *vrc_create(int argc, const char *const *argv);
+
+// ID SRS: SRS-FN-FFIAPI-002
+// Title: vrc_start
+//
+// Description: VRConnect shall start the processor on a background thread,
+// receiving fed frames and, if a port is given, Socket.IO connections.
+//
+// Version: V1.0
+//
+// # Arguments
+// * `processor` - Processor handle
+// * `port` - Socket.IO port to listen on, 0 to only receive fed frames
+//
+// # Returns
+// Status code
+//
+// # Safety
+// `processor` must be a handle returned by `vrc_create`.
+enum VrcStatus vrc_start(struct VrcProcessor *processor, uint16_t port);
+
+// ID SRS: SRS-FN-FFIAPI-003
+// Title: vrc_stop
+//
+// Description: VRConnect shall stop a running processor, draining
+// in-flight data within the shutdown timeout, and report an error that
+// stopped it earlier.
+//
+// Version: V1.0
+//
+// # Arguments
+// * `processor` - Processor handle
+//
+// # Returns
+// Status code (Failed with the last error set if the processor stopped on
+// an error)
+//
+// # Safety
+// `processor` must be a handle returned by `vrc_create`.
+enum VrcStatus vrc_stop(struct VrcProcessor *processor);
+
+// ID SRS: SRS-FN-FFIAPI-004
+// Title: vrc_destroy
+//
+// Description: VRConnect shall stop the processor if running and release
+// its handle.
+//
+// Version: V1.0
+//
+// # Arguments
+// * `processor` - Processor handle (NULL is ignored)
+//
+// # Safety
+// `processor` must be a handle returned by `vrc_create`, not used afterwards.
+void vrc_destroy(struct VrcProcessor *processor);
+
+// ID SRS: SRS-FN-FFIAPI-005
+// Title: vrc_feed_frame
+//
+// Description: VRConnect shall queue a raw frame (JSON, compressed or not,
+// as sent by VitalRecorder) for processing.
+//
+// Version: V1.0
+//
+// # Arguments
+// * `processor` - Processor handle
+// * `data` - Frame bytes
+// * `len` - Number of bytes
+//
+// # Returns
+// Status code (QUEUE_FULL when frames are fed faster than processed)
+//
+// # Safety
+// `processor` must be a handle returned by `vrc_create` and `data` must
+// point to `len` readable bytes.
+enum VrcStatus vrc_feed_frame(struct VrcProcessor *processor, const uint8_t *data, size_t len);
+
+// ID SRS: SRS-FN-FFIAPI-006
+// Title: vrc_latest_values
+//
+// Description: VRConnect shall copy the latest value of every track into a
+// caller buffer.
+//
+// Version: V1.0
+//
+// # Arguments
+// * `processor` - Processor handle
+// * `out` - Buffer of `capacity` values (may be NULL if capacity is 0)
+// * `capacity` - Buffer capacity
+//
+// # Returns
+// Total number of values (more than `capacity` if the buffer is too small)
+//
+// # Safety
+// `processor` must be a handle returned by `vrc_create` and `out` must
+// point to `capacity` writable values.
+size_t vrc_latest_values(const struct VrcProcessor *processor,
+                         struct VrcValue *out,
+                         size_t capacity);
+
+// ID SRS: SRS-FN-FFIAPI-007
+// Title: vrc_poll_event
+//
+// Description: VRConnect shall hand the oldest queued alarm event to the
+// caller (events are only queued while no event callback is registered).
+//
+// Version: V1.0
+//
+// # Arguments
+// * `processor` - Processor handle
+// * `out` - Event to fill
+//
+// # Returns
+// true if an event was written
+//
+// # Safety
+// `processor` must be a handle returned by `vrc_create` and `out` must
+// point to a writable event.
+bool vrc_poll_event(const struct VrcProcessor *processor, struct VrcEvent *out);
+
+// ID SRS: SRS-FN-FFIAPI-008
+// Title: vrc_poll_waveform
+//
+// Description: VRConnect shall hand the oldest queued waveform segment to
+// the caller, its points copied into a caller buffer (segments are only
+// queued while no waveform callback is registered).
+//
+// Version: V1.0
+//
+// # Arguments
+// * `processor` - Processor handle
+// * `out` - Segment to fill; `points` is set to `points` and `point_count`
+//   to the number of points copied
+// * `points` - Buffer of `capacity` points (extra points are dropped)
+// * `capacity` - Buffer capacity
+//
+// # Returns
+// true if a segment was written
+//
+// # Safety
+// `processor` must be a handle returned by `vrc_create`, `out` must point
+// to a writable segment and `points` to `capacity` writable values.
+bool vrc_poll_waveform(const struct VrcProcessor *processor,
+                       struct VrcWaveform *out,
+                       double *points,
+                       size_t capacity);
+
+// ID SRS: SRS-FN-FFIAPI-009
+// Title: vrc_set_value_callback
+//
+// Description: VRConnect shall call a function with every updated value,
+// on the processor thread.
+//
+// Version: V1.0
+//
+// # Arguments
+// * `processor` - Processor handle
+// * `callback` - Function to call, NULL to unregister
+// * `user_data` - Pointer passed back to the callback
+//
+// # Returns
+// Status code, once a call in progress of the previous callback returned
+//
+// # Safety
+// `processor` must be a handle returned by `vrc_create`; the callback must
+// be callable from another thread with `user_data`, and must not set a
+// callback itself. `user_data` of a replaced callback may be released
+// when this function returns.
+enum VrcStatus vrc_set_value_callback(struct VrcProcessor *processor,
+                                      VrcValueCallback callback,
+                                      void *user_data);
+
+// ID SRS: SRS-FN-FFIAPI-010
+// Title: vrc_set_event_callback
+//
+// Description: VRConnect shall call a function with every alarm event, on
+// the processor thread, instead of queuing it for polling.
+//
+// Version: V1.0
+//
+// # Arguments
+// * `processor` - Processor handle
+// * `callback` - Function to call, NULL to unregister
+// * `user_data` - Pointer passed back to the callback
+//
+// # Returns
+// Status code, once a call in progress of the previous callback returned
+//
+// # Safety
+// `processor` must be a handle returned by `vrc_create`; the callback must
+// be callable from another thread with `user_data`, and must not set a
+// callback itself. `user_data` of a replaced callback may be released
+// when this function returns.
+enum VrcStatus vrc_set_event_callback(struct VrcProcessor *processor,
+                                      VrcEventCallback callback,
+                                      void *user_data);
+
+// ID SRS: SRS-FN-FFIAPI-011
+// Title: vrc_set_waveform_callback
+//
+// Description: VRConnect shall call a function with every waveform segment,
+// on the processor thread, instead of queuing it for polling; the points
+// are only valid during the call.
+//
+// Version: V1.0
+//
+// # Arguments
+// * `processor` - Processor handle
+// * `callback` - Function to call, NULL to unregister
+// * `user_data` - Pointer passed back to the callback
+//
+// # Returns
+// Status code, once a call in progress of the previous callback returned
+//
+// # Safety
+// `processor` must be a handle returned by `vrc_create`; the callback must
+// be callable from another thread with `user_data`, and must not set a
+// callback itself. `user_data` of a replaced callback may be released
+// when this function returns.
+enum VrcStatus vrc_set_waveform_callback(struct VrcProcessor *processor,
+                                         VrcWaveformCallback callback,
+                                         void *user_data);
+
+// ID SRS: SRS-FN-FFIAPI-012
+// Title: vrc_last_error
+//
+// Description: VRConnect shall describe the last error of a C API call on
+// the calling thread.
+//
+// Version: V1.0
+//
+// # Returns
+// NUL-terminated message, valid until the next failing call on this thread
+const char *vrc_last_error(void);
+
+// ID SRS: SRS-FN-FFIAPI-013
+// Title: vrc_version
+//
+// Description: VRConnect shall report the version of the library.
+//
+// Version: V1.0
+//
+// # Returns
+// NUL-terminated version string
+const char *vrc_version(void);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* VRCONNECT_H */
@@ -0,0 +1,751 @@
+// /src/alarm/engine.rs
+// Module: alarm.engine
+// Purpose: Clinical alarm evaluation with delay, hysteresis, escalation and silencing
+
+use crate::alarm::limits::{AlarmLimit, AlarmLimitSet};
+use crate::domain::{
+    AlarmAction, AlarmCondition, AlarmEvent, AlarmPriority, ProcessedData, ProcessedTrack,
+};
+use crate::pipeline::UnitConversion;
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::{mpsc, Mutex};
+
+/// ID SRS: SRS-MOD-ALARMENGINE-001
+/// Title: AlarmEngine
+///
+/// Description: VRConnect shall evaluate processed numeric values against
+/// configured limits, tracking alarm state per device, room and parameter and
+/// emitting raise, clear, escalate, acknowledge and silence events.
+///
+/// Version: V1.0
+pub struct AlarmEngine {
+    limits: AlarmLimitSet,
+    states: HashMap<String, AlarmState>,
+    silences: Vec<Silence>,
+    min_quality: f64,
+    unit_conversions: Vec<UnitConversion>,
+}
+
+/// Alarm state for one device/room/parameter
+#[derive(Debug, Clone)]
+struct AlarmState {
+    device_id: String,
+    room_name: String,
+    parameter: String,
+    unit: String,
+    condition: AlarmCondition,
+    threshold: f64,
+    value: f64,
+    priority: AlarmPriority,
+    since: DateTime<Utc>,
+    active: bool,
+    acknowledged: bool,
+    last_escalation: DateTime<Utc>,
+}
+
+/// Silence window for a device, optionally restricted to one room
+#[derive(Debug, Clone)]
+struct Silence {
+    device_id: String,
+    room_name: Option<String>,
+    until: DateTime<Utc>,
+}
+
+/// ID SRS: SRS-MOD-ALARMENGINE-002
+/// Title: AlarmHandle
+///
+/// Description: VRConnect shall expose a cloneable handle to acknowledge and
+/// silence alarms, forwarding resulting events to all outputs.
+///
+/// Version: V1.0
+#[derive(Clone)]
+pub struct AlarmHandle {
+    engine: Arc<Mutex<AlarmEngine>>,
+    events: mpsc::UnboundedSender<AlarmEvent>,
+}
+
+impl AlarmEngine {
+    /// ID SRS: SRS-FN-ALARMENGINE-001
+    /// Title: new
+    ///
+    /// Description: VRConnect shall construct an AlarmEngine with the given
+    /// limit set and no active alarms.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `limits` - Alarm limits with overrides
+    ///
+    /// # Returns
+    /// New AlarmEngine instance
+    pub fn new(limits: AlarmLimitSet) -> Self {
+        Self {
+            limits,
+            states: HashMap::new(),
+            silences: Vec::new(),
+            min_quality: 0.0,
+            unit_conversions: Vec::new(),
+        }
+    }
+
+    /// ID SRS: SRS-FN-ALARMENGINE-010
+    /// Title: with_min_quality
+    ///
+    /// Description: VRConnect shall ignore values whose signal quality score is
+    /// below a minimum, so artifacts neither raise nor clear alarms.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `min_quality` - Minimum quality score (0 disables suppression)
+    ///
+    /// # Returns
+    /// AlarmEngine with artifact suppression
+    pub fn with_min_quality(mut self, min_quality: f64) -> Self {
+        self.min_quality = min_quality;
+        self
+    }
+
+    /// ID SRS: SRS-FN-ALARMENGINE-011
+    /// Title: with_unit_conversions
+    ///
+    /// Description: VRConnect shall keep limits expressed in clinical units
+    /// when the pipeline converts units: the limits of a track in a converted
+    /// unit are converted the same way before evaluation.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `unit_conversions` - Conversions applied by the pipeline
+    ///
+    /// # Returns
+    /// AlarmEngine with the conversions
+    pub fn with_unit_conversions(mut self, unit_conversions: Vec<UnitConversion>) -> Self {
+        self.unit_conversions = unit_conversions;
+        self
+    }
+
+    /// ID SRS: SRS-FN-ALARMENGINE-002
+    /// Title: evaluate
+    ///
+    /// Description: VRConnect shall evaluate every reliable track of the
+    /// processed data against its resolved limits, using the data timestamp as
+    /// evaluation time.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `data` - Processed vital data
+    ///
+    /// # Returns
+    /// Alarm events produced by this evaluation
+    pub fn evaluate(&mut self, data: &ProcessedData) -> Vec<AlarmEvent> {
+        let now = data.timestamp;
+        let mut events = Vec::new();
+
+        self.silences.retain(|silence| silence.until > now);
+
+        for track in &data.all_tracks {
+            if track
+                .quality
+                .as_ref()
+                .is_some_and(|quality| !quality.is_reliable(self.min_quality))
+            {
+                log::debug!("Alarm evaluation skipped for unreliable {}/{}", track.room_name, track.name);
+                continue;
+            }
+
+            let limits: Vec<AlarmLimit> = self
+                .limits
+                .resolve(&data.device_id, &track.room_name, &track.name)
+                .into_iter()
+                .cloned()
+                .map(|limit| self.limit_in_unit(limit, &track.unit))
+                .collect();
+
+            for limit in limits {
+                if let Some(value) = Self::limit_value(&limit, track) {
+                    self.evaluate_value(&limit, &data.device_id, track, value, now, &mut events);
+                }
+            }
+        }
+
+        events
+    }
+
+    /// ID SRS: SRS-FN-ALARMENGINE-003
+    /// Title: acknowledge
+    ///
+    /// Description: VRConnect shall acknowledge an active alarm, stopping its
+    /// escalation until it clears.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `alarm_id` - Alarm identifier (device/room/parameter)
+    /// * `now` - Acknowledgment time
+    ///
+    /// # Returns
+    /// Acknowledged event, or None if the alarm is not active
+    pub fn acknowledge(&mut self, alarm_id: &str, now: DateTime<Utc>) -> Option<AlarmEvent> {
+        let silenced = self
+            .states
+            .get(alarm_id)
+            .map(|state| self.is_silenced(&state.device_id, &state.room_name, now))
+            .unwrap_or(false);
+
+        let state = self.states.get_mut(alarm_id).filter(|state| state.active)?;
+        state.acknowledged = true;
+
+        log::info!("Alarm acknowledged: {}", alarm_id);
+        Some(Self::make_event(state, AlarmAction::Acknowledged, silenced, now))
+    }
+
+    /// ID SRS: SRS-FN-ALARMENGINE-004
+    /// Title: silence
+    ///
+    /// Description: VRConnect shall silence alarms of a device (optionally one
+    /// room) for a duration; alarms keep being tracked and their events are
+    /// flagged as silenced.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `device_id` - VitalRecorder device identifier
+    /// * `room_name` - Optional room restriction
+    /// * `duration` - Silence duration
+    /// * `now` - Silence start time
+    ///
+    /// # Returns
+    /// Silenced events for currently active alarms
+    pub fn silence(
+        &mut self,
+        device_id: &str,
+        room_name: Option<&str>,
+        duration: Duration,
+        now: DateTime<Utc>,
+    ) -> Vec<AlarmEvent> {
+        self.silences.push(Silence {
+            device_id: device_id.to_string(),
+            room_name: room_name.map(str::to_string),
+            until: now + duration,
+        });
+
+        log::info!(
+            "Alarms silenced for {} (room: {}) during {}s",
+            device_id,
+            room_name.unwrap_or("all"),
+            duration.num_seconds()
+        );
+
+        self.states
+            .values()
+            .filter(|state| {
+                state.active
+                    && state.device_id == device_id
+                    && room_name.is_none_or(|room| state.room_name == room)
+            })
+            .map(|state| Self::make_event(state, AlarmAction::Silenced, true, now))
+            .collect()
+    }
+
+    /// ID SRS: SRS-FN-ALARMENGINE-005
+    /// Title: evaluate_value
+    ///
+    /// Description: VRConnect shall update alarm state for one value: start a
+    /// pending violation, raise it once the delay elapsed, escalate unacknowledged
+    /// alarms, and clear active alarms only beyond the hysteresis band.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `limit` - Resolved alarm limit
+    /// * `device_id` - VitalRecorder device identifier
+    /// * `track` - Track providing the value
+    /// * `value` - Numeric value to evaluate
+    /// * `now` - Evaluation time
+    /// * `events` - Output event list
+    fn evaluate_value(
+        &mut self,
+        limit: &AlarmLimit,
+        device_id: &str,
+        track: &ProcessedTrack,
+        value: f64,
+        now: DateTime<Utc>,
+        events: &mut Vec<AlarmEvent>,
+    ) {
+        let parameter = limit.key();
+        let alarm_id = AlarmEvent::alarm_id_for(device_id, &track.room_name, &parameter);
+        let silenced = self.is_silenced(device_id, &track.room_name, now);
+        let violation = Self::check_violation(limit, value);
+
+        if let Some(state) = self.states.get_mut(&alarm_id) {
+            state.value = value;
+
+            if state.active {
+                if !Self::is_cleared(limit, state.condition, value) {
+                    let escalation_due = limit.escalation_secs.is_some_and(|secs| {
+                        Self::elapsed_secs(state.last_escalation, now) >= secs
+                    });
+
+                    if escalation_due && !state.acknowledged && state.priority < AlarmPriority::High {
+                        state.priority = state.priority.escalated();
+                        state.last_escalation = now;
+                        log::warn!("Alarm escalated: {} ({:?})", alarm_id, state.priority);
+                        events.push(Self::make_event(state, AlarmAction::Escalated, silenced, now));
+                    }
+                    return;
+                }
+
+                log::info!("Alarm cleared: {}", alarm_id);
+                events.push(Self::make_event(state, AlarmAction::Cleared, silenced, now));
+                self.states.remove(&alarm_id);
+            } else if violation.map(|(condition, _)| condition) != Some(state.condition) {
+                self.states.remove(&alarm_id);
+            }
+        }
+
+        let Some((condition, threshold)) = violation else {
+            return;
+        };
+
+        let state = self.states.entry(alarm_id.clone()).or_insert_with(|| AlarmState {
+            device_id: device_id.to_string(),
+            room_name: track.room_name.clone(),
+            parameter,
+            unit: track.unit.clone(),
+            condition,
+            threshold,
+            value,
+            priority: limit.priority,
+            since: now,
+            active: false,
+            acknowledged: false,
+            last_escalation: now,
+        });
+
+        if !state.active && Self::elapsed_secs(state.since, now) >= limit.delay_secs {
+            state.active = true;
+            state.last_escalation = now;
+            log::warn!("Alarm raised: {} ({:?})", alarm_id, state.condition);
+            events.push(Self::make_event(state, AlarmAction::Raised, silenced, now));
+        }
+    }
+
+    /// ID SRS: SRS-FN-ALARMENGINE-012
+    /// Title: limit_in_unit
+    ///
+    /// Description: VRConnect shall convert the thresholds and hysteresis of
+    /// a limit to the unit of a track converted by the pipeline.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `limit` - Alarm limit in clinical units
+    /// * `unit` - Unit of the track
+    ///
+    /// # Returns
+    /// Limit in the unit of the track
+    fn limit_in_unit(&self, mut limit: AlarmLimit, unit: &str) -> AlarmLimit {
+        if let Some(conversion) = self.unit_conversions.iter().find(|conversion| conversion.targets(unit)) {
+            limit.low = limit.low.map(|low| conversion.convert(low));
+            limit.high = limit.high.map(|high| conversion.convert(high));
+            limit.hysteresis *= conversion.factor.abs();
+        }
+        limit
+    }
+
+    /// ID SRS: SRS-FN-ALARMENGINE-006
+    /// Title: limit_value
+    ///
+    /// Description: VRConnect shall extract the value a limit applies to: the
+    /// named component of a composite value, or the numeric raw value.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `limit` - Alarm limit
+    /// * `track` - Processed track
+    ///
+    /// # Returns
+    /// Value to evaluate, or None if unavailable
+    fn limit_value(limit: &AlarmLimit, track: &ProcessedTrack) -> Option<f64> {
+        match &limit.component {
+            Some(component) => track
+                .components
+                .as_ref()?
+                .iter()
+                .find(|c| c.name.eq_ignore_ascii_case(component))
+                .map(|c| c.value),
+            None => track.raw_value,
+        }
+    }
+
+    /// ID SRS: SRS-FN-ALARMENGINE-007
+    /// Title: check_violation
+    ///
+    /// Description: VRConnect shall determine whether a value violates the low
+    /// or high threshold of a limit.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `limit` - Alarm limit
+    /// * `value` - Value to check
+    ///
+    /// # Returns
+    /// Violated condition and threshold, or None
+    fn check_violation(limit: &AlarmLimit, value: f64) -> Option<(AlarmCondition, f64)> {
+        if let Some(low) = limit.low.filter(|low| value < *low) {
+            return Some((AlarmCondition::Low, low));
+        }
+
+        limit
+            .high
+            .filter(|high| value > *high)
+            .map(|high| (AlarmCondition::High, high))
+    }
+
+    /// ID SRS: SRS-FN-ALARMENGINE-008
+    /// Title: is_cleared
+    ///
+    /// Description: VRConnect shall consider an active alarm cleared only once
+    /// the value is back inside the limit by at least the hysteresis margin.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `limit` - Alarm limit
+    /// * `condition` - Active alarm condition
+    /// * `value` - Current value
+    ///
+    /// # Returns
+    /// True if the alarm clears
+    fn is_cleared(limit: &AlarmLimit, condition: AlarmCondition, value: f64) -> bool {
+        match condition {
+            AlarmCondition::Low => limit.low.is_none_or(|low| value >= low + limit.hysteresis),
+            AlarmCondition::High => limit.high.is_none_or(|high| value <= high - limit.hysteresis),
+            AlarmCondition::DataStale | AlarmCondition::SensorOff | AlarmCondition::ClockDrift => true,
+        }
+    }
+
+    fn is_silenced(&self, device_id: &str, room_name: &str, now: DateTime<Utc>) -> bool {
+        self.silences.iter().any(|silence| {
+            silence.until > now
+                && silence.device_id == device_id
+                && silence.room_name.as_deref().is_none_or(|room| room == room_name)
+        })
+    }
+
+    fn elapsed_secs(since: DateTime<Utc>, now: DateTime<Utc>) -> f64 {
+        (now - since).num_milliseconds() as f64 / 1000.0
+    }
+
+    /// ID SRS: SRS-FN-ALARMENGINE-009
+    /// Title: make_event
+    ///
+    /// Description: VRConnect shall build an AlarmEvent from alarm state with a
+    /// human-readable message.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `state` - Alarm state
+    /// * `action` - Event action
+    /// * `silenced` - Whether alarms are currently silenced
+    /// * `now` - Event time
+    ///
+    /// # Returns
+    /// New AlarmEvent
+    fn make_event(
+        state: &AlarmState,
+        action: AlarmAction,
+        silenced: bool,
+        now: DateTime<Utc>,
+    ) -> AlarmEvent {
+        let message = format!(
+            "{} {} {:?}: {} {} (limit {})",
+            state.parameter,
+            state.condition.label(),
+            action,
+            state.value,
+            state.unit,
+            state.threshold
+        );
+
+        AlarmEvent {
+            alarm_id: AlarmEvent::alarm_id_for(&state.device_id, &state.room_name, &state.parameter),
+            device_id: state.device_id.clone(),
+            room_name: state.room_name.clone(),
+            parameter: state.parameter.clone(),
+            condition: state.condition,
+            action,
+            priority: state.priority,
+            value: Some(state.value),
+            limit: Some(state.threshold),
+            silenced,
+            message,
+            timestamp: now,
+        }
+    }
+}
+
+impl AlarmHandle {
+    /// ID SRS: SRS-FN-ALARMHANDLE-001
+    /// Title: new
+    ///
+    /// Description: VRConnect shall construct an AlarmHandle sharing the alarm
+    /// engine and the event channel feeding the outputs.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `engine` - Shared alarm engine
+    /// * `events` - Event channel sender
+    ///
+    /// # Returns
+    /// New AlarmHandle instance
+    pub fn new(engine: Arc<Mutex<AlarmEngine>>, events: mpsc::UnboundedSender<AlarmEvent>) -> Self {
+        Self { engine, events }
+    }
+
+    /// ID SRS: SRS-FN-ALARMHANDLE-002
+    /// Title: acknowledge
+    ///
+    /// Description: VRConnect shall acknowledge an alarm and forward the event.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `alarm_id` - Alarm identifier
+    ///
+    /// # Returns
+    /// True if an active alarm was acknowledged
+    pub async fn acknowledge(&self, alarm_id: &str) -> bool {
+        let event = self.engine.lock().await.acknowledge(alarm_id, Utc::now());
+
+        match event {
+            Some(event) => {
+                let _ = self.events.send(event);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// ID SRS: SRS-FN-ALARMHANDLE-003
+    /// Title: silence
+    ///
+    /// Description: VRConnect shall silence alarms of a device or room and
+    /// forward the resulting events.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `device_id` - VitalRecorder device identifier
+    /// * `room_name` - Optional room restriction
+    /// * `duration` - Silence duration
+    ///
+    /// # Returns
+    /// Number of active alarms silenced
+    pub async fn silence(
+        &self,
+        device_id: &str,
+        room_name: Option<&str>,
+        duration: std::time::Duration,
+    ) -> usize {
+        let duration = Duration::from_std(duration).unwrap_or_else(|_| Duration::seconds(0));
+        let events = self
+            .engine
+            .lock()
+            .await
+            .silence(device_id, room_name, duration, Utc::now());
+
+        let count = events.len();
+        for event in events {
+            let _ = self.events.send(event);
+        }
+        count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{ArtifactFlag, ProcessedRoom, SignalQuality, TrackType, ValueComponent};
+    use chrono::TimeZone;
+
+    fn track(name: &str, value: f64) -> ProcessedTrack {
+        ProcessedTrack::synthetic(name, Some(value), "bpm", 0, "OR-1", Utc::now())
+    }
+
+    fn frame(track: ProcessedTrack, secs: i64) -> ProcessedData {
+        let mut data = ProcessedData::new(
+            "VR1".to_string(),
+            vec![ProcessedRoom {
+                room_index: 0,
+                room_name: "OR-1".to_string(),
+                tracks: vec![track],
+            }],
+        );
+        data.timestamp = Utc.timestamp_opt(1_700_000_000 + secs, 0).unwrap();
+        data
+    }
+
+    fn engine(limit: AlarmLimit) -> AlarmEngine {
+        AlarmEngine::new(AlarmLimitSet {
+            defaults: vec![limit],
+            overrides: Vec::new(),
+        })
+    }
+
+    fn hr_limit() -> AlarmLimit {
+        let mut limit = AlarmLimit::new("HR", Some(50.0), Some(120.0), AlarmPriority::Low);
+        limit.delay_secs = 5.0;
+        limit.hysteresis = 5.0;
+        limit.escalation_secs = Some(30.0);
+        limit
+    }
+
+    #[test]
+    fn test_raise_after_delay() {
+        let mut engine = engine(hr_limit());
+
+        assert!(engine.evaluate(&frame(track("HR", 130.0), 0)).is_empty());
+        assert!(engine.evaluate(&frame(track("HR", 131.0), 3)).is_empty());
+
+        let events = engine.evaluate(&frame(track("HR", 132.0), 5));
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].action, AlarmAction::Raised);
+        assert_eq!(events[0].condition, AlarmCondition::High);
+        assert_eq!(events[0].alarm_id, "VR1/OR-1/HR");
+        assert_eq!(events[0].value, Some(132.0));
+    }
+
+    #[test]
+    fn test_pending_violation_reset_before_delay() {
+        let mut engine = engine(hr_limit());
+
+        engine.evaluate(&frame(track("HR", 130.0), 0));
+        engine.evaluate(&frame(track("HR", 100.0), 2));
+        assert!(engine.evaluate(&frame(track("HR", 130.0), 6)).is_empty());
+    }
+
+    #[test]
+    fn test_clear_with_hysteresis() {
+        let mut engine = engine(hr_limit());
+        engine.evaluate(&frame(track("HR", 130.0), 0));
+        engine.evaluate(&frame(track("HR", 130.0), 5));
+
+        // Inside the limit but within the hysteresis band: still active
+        assert!(engine.evaluate(&frame(track("HR", 118.0), 6)).is_empty());
+
+        let events = engine.evaluate(&frame(track("HR", 114.0), 7));
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].action, AlarmAction::Cleared);
+    }
+
+    #[test]
+    fn test_escalation_and_acknowledge() {
+        let mut engine = engine(hr_limit());
+        engine.evaluate(&frame(track("HR", 40.0), 0));
+        engine.evaluate(&frame(track("HR", 40.0), 5));
+
+        let events = engine.evaluate(&frame(track("HR", 40.0), 35));
+        assert_eq!(events[0].action, AlarmAction::Escalated);
+        assert_eq!(events[0].priority, AlarmPriority::Medium);
+
+        let ack = engine
+            .acknowledge("VR1/OR-1/HR", Utc.timestamp_opt(1_700_000_036, 0).unwrap())
+            .unwrap();
+        assert_eq!(ack.action, AlarmAction::Acknowledged);
+
+        assert!(engine.evaluate(&frame(track("HR", 40.0), 70)).is_empty());
+        assert!(engine.acknowledge("VR1/OR-1/SPO2", Utc::now()).is_none());
+    }
+
+    #[test]
+    fn test_silence_flags_events() {
+        let mut limit = hr_limit();
+        limit.delay_secs = 0.0;
+        let mut engine = engine(limit);
+
+        let start = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+        assert!(engine.silence("VR1", None, Duration::seconds(60), start).is_empty());
+
+        let events = engine.evaluate(&frame(track("HR", 150.0), 1));
+        assert!(events[0].silenced);
+
+        let events = engine.evaluate(&frame(track("HR", 100.0), 61));
+        assert_eq!(events[0].action, AlarmAction::Cleared);
+        assert!(!events[0].silenced);
+    }
+
+    #[test]
+    fn test_component_limit() {
+        let mut limit = AlarmLimit::new("NIBP", Some(90.0), None, AlarmPriority::High);
+        limit.component = Some("systolic".to_string());
+        let mut engine = engine(limit);
+
+        let mut nibp = track("NIBP", 0.0);
+        nibp.raw_value = None;
+        nibp.track_type = TrackType::String;
+        nibp.components = Some(vec![
+            ValueComponent::new("systolic", 80.0),
+            ValueComponent::new("diastolic", 50.0),
+        ]);
+
+        let events = engine.evaluate(&frame(nibp, 0));
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].parameter, "NIBP.systolic");
+        assert_eq!(events[0].condition, AlarmCondition::Low);
+    }
+
+    #[test]
+    fn test_unreliable_values_ignored() {
+        let mut limit = hr_limit();
+        limit.delay_secs = 0.0;
+        let mut engine = engine(limit).with_min_quality(0.5);
+
+        let mut artifact = track("HR", 250.0);
+        artifact.quality = Some(SignalQuality {
+            score: 0.3,
+            artifacts: vec![ArtifactFlag::Jump],
+        });
+        assert!(engine.evaluate(&frame(artifact, 0)).is_empty());
+
+        let events = engine.evaluate(&frame(track("HR", 130.0), 1));
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].action, AlarmAction::Raised);
+    }
+
+    #[test]
+    fn test_converted_temperature_within_builtin_limits() {
+        let conversions = UnitConversion::parse_list("°C=°F").unwrap();
+        let converter = crate::pipeline::UnitConverter::new(conversions.clone());
+        let mut engine = AlarmEngine::new(AlarmLimitSet::builtin()).with_unit_conversions(conversions);
+        let fahrenheit = |celsius: f64, secs: i64| {
+            let mut temperature = track("BT", celsius);
+            temperature.unit = "°C".to_string();
+            let mut data = frame(temperature, secs);
+            converter.apply(&mut data);
+            data
+        };
+
+        // 37 °C becomes 98.6 °F, within the built-in 35-38.5 °C limits
+        for secs in [0, 10, 20] {
+            let data = fahrenheit(37.0, secs);
+            assert!((data.all_tracks[0].raw_value.unwrap() - 98.6).abs() < 1e-9);
+            assert!(engine.evaluate(&data).is_empty());
+        }
+
+        // 39 °C is above the limit, reported against 101.3 °F
+        engine.evaluate(&fahrenheit(39.0, 30));
+        let events = engine.evaluate(&fahrenheit(39.0, 40));
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].condition, AlarmCondition::High);
+        assert!((events[0].limit.unwrap() - 101.3).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,380 @@
+// /src/alarm/limits.rs
+// Module: alarm.limits
+// Purpose: Per-parameter alarm limits with file loading and room/device overrides
+
+use crate::domain::AlarmPriority;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// ID SRS: SRS-MOD-ALARMLIMITS-001
+/// Title: AlarmLimit
+///
+/// Description: VRConnect shall define configurable alarm limits per parameter
+/// (low/high thresholds, priority, activation delay, hysteresis and escalation
+/// time), optionally targeting a numeric component of a composite value.
+///
+/// Version: V1.0
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AlarmLimit {
+    pub parameter: String,
+    #[serde(default)]
+    pub component: Option<String>,
+    #[serde(default)]
+    pub low: Option<f64>,
+    #[serde(default)]
+    pub high: Option<f64>,
+    #[serde(default = "default_priority")]
+    pub priority: AlarmPriority,
+    #[serde(default)]
+    pub delay_secs: f64,
+    #[serde(default)]
+    pub hysteresis: f64,
+    #[serde(default)]
+    pub escalation_secs: Option<f64>,
+}
+
+/// Limits applying only to a given device and/or room (patient bed)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AlarmLimitOverride {
+    #[serde(default)]
+    pub device_id: Option<String>,
+    #[serde(default)]
+    pub room_name: Option<String>,
+    pub limits: Vec<AlarmLimit>,
+}
+
+/// ID SRS: SRS-MOD-ALARMLIMITS-002
+/// Title: AlarmLimitSet
+///
+/// Description: VRConnect shall hold default alarm limits and per device/room
+/// overrides, resolving the most specific limit for each monitored parameter.
+///
+/// Version: V1.0
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AlarmLimitSet {
+    #[serde(default)]
+    pub defaults: Vec<AlarmLimit>,
+    #[serde(default)]
+    pub overrides: Vec<AlarmLimitOverride>,
+}
+
+fn default_priority() -> AlarmPriority {
+    AlarmPriority::Medium
+}
+
+impl AlarmLimit {
+    /// ID SRS: SRS-FN-ALARMLIMITS-001
+    /// Title: new
+    ///
+    /// Description: VRConnect shall construct an alarm limit for a parameter
+    /// with low/high thresholds and default timing settings.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `parameter` - Track name the limit applies to
+    /// * `low` - Optional low threshold
+    /// * `high` - Optional high threshold
+    /// * `priority` - Initial alarm priority
+    ///
+    /// # Returns
+    /// New AlarmLimit instance
+    pub fn new(parameter: &str, low: Option<f64>, high: Option<f64>, priority: AlarmPriority) -> Self {
+        Self {
+            parameter: parameter.to_string(),
+            component: None,
+            low,
+            high,
+            priority,
+            delay_secs: 0.0,
+            hysteresis: 0.0,
+            escalation_secs: None,
+        }
+    }
+
+    /// ID SRS: SRS-FN-ALARMLIMITS-002
+    /// Title: key
+    ///
+    /// Description: VRConnect shall build the parameter key identifying this
+    /// limit, including the component name for composite values.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Parameter key (e.g. "HR" or "NIBP.systolic")
+    pub fn key(&self) -> String {
+        match &self.component {
+            Some(component) => format!("{}.{}", self.parameter, component),
+            None => self.parameter.clone(),
+        }
+    }
+
+    /// ID SRS: SRS-FN-ALARMLIMITS-003
+    /// Title: matches_track
+    ///
+    /// Description: VRConnect shall match a track name against the limit
+    /// parameter case-insensitively, ignoring any "Device/" name prefix.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `track_name` - Processed track name
+    ///
+    /// # Returns
+    /// True if the limit applies to the track
+    pub fn matches_track(&self, track_name: &str) -> bool {
+        let short_name = track_name.rsplit('/').next().unwrap_or(track_name);
+        self.parameter.eq_ignore_ascii_case(track_name)
+            || self.parameter.eq_ignore_ascii_case(short_name)
+    }
+
+    /// ID SRS: SRS-FN-ALARMLIMITS-004
+    /// Title: validate
+    ///
+    /// Description: VRConnect shall validate limit consistency: at least one
+    /// threshold, low below high, and non-negative delay, hysteresis and escalation.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Result indicating validation success or error message
+    pub fn validate(&self) -> Result<(), String> {
+        if self.low.is_none() && self.high.is_none() {
+            return Err(format!("Alarm limit '{}' has neither low nor high threshold", self.key()));
+        }
+
+        if let (Some(low), Some(high)) = (self.low, self.high) {
+            if low >= high {
+                return Err(format!(
+                    "Alarm limit '{}': low ({}) must be below high ({})",
+                    self.key(),
+                    low,
+                    high
+                ));
+            }
+        }
+
+        if self.delay_secs < 0.0 || self.hysteresis < 0.0 || self.escalation_secs.unwrap_or(0.0) < 0.0 {
+            return Err(format!(
+                "Alarm limit '{}': delay, hysteresis and escalation must be non-negative",
+                self.key()
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl AlarmLimitSet {
+    /// ID SRS: SRS-FN-ALARMLIMITS-005
+    /// Title: builtin
+    ///
+    /// Description: VRConnect shall provide conservative adult default limits
+    /// used when no limits file is configured.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Built-in AlarmLimitSet
+    pub fn builtin() -> Self {
+        let mut nibp_systolic =
+            AlarmLimit::new("NIBP", Some(90.0), Some(180.0), AlarmPriority::Medium);
+        nibp_systolic.component = Some("systolic".to_string());
+
+        let defaults = vec![
+            AlarmLimit::new("HR", Some(50.0), Some(120.0), AlarmPriority::Medium),
+            AlarmLimit::new("PLETH_SPO2", Some(90.0), None, AlarmPriority::High),
+            AlarmLimit::new("SPO2", Some(90.0), None, AlarmPriority::High),
+            AlarmLimit::new("NIBP_SBP", Some(90.0), Some(180.0), AlarmPriority::Medium),
+            nibp_systolic,
+            AlarmLimit::new("RR", Some(8.0), Some(30.0), AlarmPriority::Medium),
+            AlarmLimit::new("BT", Some(35.0), Some(38.5), AlarmPriority::Low),
+        ]
+        .into_iter()
+        .map(|mut limit| {
+            limit.delay_secs = 5.0;
+            limit.hysteresis = 2.0;
+            limit.escalation_secs = Some(60.0);
+            limit
+        })
+        .collect();
+
+        Self {
+            defaults,
+            overrides: Vec::new(),
+        }
+    }
+
+    /// ID SRS: SRS-FN-ALARMLIMITS-006
+    /// Title: load_from_file
+    ///
+    /// Description: VRConnect shall load alarm limits from a JSON file containing
+    /// default limits and per device/room overrides, validating every entry.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `path` - Path to JSON limits file
+    ///
+    /// # Returns
+    /// Loaded AlarmLimitSet or error message
+    pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path.as_ref()).map_err(|e| format!("Failed to read file: {}", e))?;
+        let limits: AlarmLimitSet =
+            serde_json::from_str(&content).map_err(|e| format!("Failed to parse alarm limits: {}", e))?;
+        limits.validate()?;
+
+        log::info!(
+            "Loaded alarm limits from {}: {} defaults, {} overrides",
+            path.as_ref().display(),
+            limits.defaults.len(),
+            limits.overrides.len()
+        );
+
+        Ok(limits)
+    }
+
+    /// ID SRS: SRS-FN-ALARMLIMITS-007
+    /// Title: validate
+    ///
+    /// Description: VRConnect shall validate every default and override limit.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Result indicating validation success or error message
+    pub fn validate(&self) -> Result<(), String> {
+        self.defaults
+            .iter()
+            .chain(self.overrides.iter().flat_map(|o| o.limits.iter()))
+            .try_for_each(|limit| limit.validate())
+    }
+
+    /// ID SRS: SRS-FN-ALARMLIMITS-008
+    /// Title: resolve
+    ///
+    /// Description: VRConnect shall resolve the limits applying to a track of a
+    /// device and room, the most specific override (device and room, then room,
+    /// then device) replacing the default for the same parameter key.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `device_id` - VitalRecorder device identifier
+    /// * `room_name` - Room name
+    /// * `track_name` - Track name
+    ///
+    /// # Returns
+    /// Applicable limits, one per parameter key
+    pub fn resolve(&self, device_id: &str, room_name: &str, track_name: &str) -> Vec<&AlarmLimit> {
+        let mut resolved: Vec<(u8, &AlarmLimit)> = Vec::new();
+
+        let candidates = self
+            .defaults
+            .iter()
+            .map(|limit| (0u8, limit))
+            .chain(self.overrides.iter().filter_map(|o| {
+                let device_match = o.device_id.as_deref().map(|d| d == device_id);
+                let room_match = o.room_name.as_deref().map(|r| r == room_name);
+                let specificity = match (device_match, room_match) {
+                    (Some(false), _) | (_, Some(false)) => return None,
+                    (Some(true), Some(true)) => 3,
+                    (None, Some(true)) => 2,
+                    (Some(true), None) => 1,
+                    (None, None) => 0,
+                };
+                Some(o.limits.iter().map(move |limit| (specificity, limit)))
+            }).flatten());
+
+        for (specificity, limit) in candidates {
+            if !limit.matches_track(track_name) {
+                continue;
+            }
+
+            let key = limit.key();
+            match resolved.iter_mut().find(|(_, existing)| existing.key() == key) {
+                Some(entry) if specificity >= entry.0 => *entry = (specificity, limit),
+                Some(_) => {}
+                None => resolved.push((specificity, limit)),
+            }
+        }
+
+        resolved.into_iter().map(|(_, limit)| limit).collect()
+    }
+}
+
+impl Default for AlarmLimitSet {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    #[test]
+    fn test_matches_track_with_device_prefix() {
+        let limit = AlarmLimit::new("HR", Some(50.0), Some(120.0), AlarmPriority::Medium);
+        assert!(limit.matches_track("HR"));
+        assert!(limit.matches_track("Solar8000/hr"));
+        assert!(!limit.matches_track("PLETH_HR"));
+    }
+
+    #[test]
+    fn test_validate_rejects_inverted_limits() {
+        let limit = AlarmLimit::new("HR", Some(120.0), Some(50.0), AlarmPriority::Medium);
+        assert!(limit.validate().is_err());
+
+        let empty = AlarmLimit::new("HR", None, None, AlarmPriority::Medium);
+        assert!(empty.validate().is_err());
+    }
+
+    #[test]
+    fn test_resolve_prefers_most_specific_override() {
+        let mut limits = AlarmLimitSet {
+            defaults: vec![AlarmLimit::new("HR", Some(50.0), Some(120.0), AlarmPriority::Medium)],
+            overrides: Vec::new(),
+        };
+        limits.overrides.push(AlarmLimitOverride {
+            device_id: None,
+            room_name: Some("OR-3".to_string()),
+            limits: vec![AlarmLimit::new("HR", Some(40.0), Some(140.0), AlarmPriority::High)],
+        });
+        limits.overrides.push(AlarmLimitOverride {
+            device_id: Some("VR1".to_string()),
+            room_name: None,
+            limits: vec![AlarmLimit::new("HR", Some(45.0), Some(130.0), AlarmPriority::Low)],
+        });
+
+        assert_eq!(limits.resolve("VR1", "OR-3", "HR")[0].low, Some(40.0));
+        assert_eq!(limits.resolve("VR1", "OR-1", "HR")[0].low, Some(45.0));
+        assert_eq!(limits.resolve("VR2", "OR-1", "HR")[0].low, Some(50.0));
+        assert!(limits.resolve("VR2", "OR-1", "SPO2").is_empty());
+    }
+
+    #[test]
+    fn test_load_from_file() {
+        let mut file = NamedTempFile::new().unwrap();
+        write!(
+            file,
+            r#"{{
+                "defaults": [{{"parameter": "HR", "low": 50, "high": 120, "delay_secs": 3}}],
+                "overrides": [{{"room_name": "OR-3", "limits": [{{"parameter": "SPO2", "low": 88, "priority": "high"}}]}}]
+            }}"#
+        )
+        .unwrap();
+
+        let limits = AlarmLimitSet::load_from_file(file.path()).unwrap();
+        assert_eq!(limits.defaults[0].priority, AlarmPriority::Medium);
+        assert_eq!(limits.defaults[0].delay_secs, 3.0);
+        assert_eq!(limits.overrides[0].limits[0].priority, AlarmPriority::High);
+    }
+
+    #[test]
+    fn test_builtin_limits_are_valid() {
+        assert!(AlarmLimitSet::builtin().validate().is_ok());
+    }
+}
@@ -0,0 +1,9 @@
+// /src/alarm/mod.rs
+// Module: alarm
+// Purpose: Clinical alarm subsystem with configurable limits
+
+pub mod engine;
+pub mod limits;
+
+pub use engine::{AlarmEngine, AlarmHandle};
+pub use limits::AlarmLimitSet;
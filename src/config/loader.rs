@@ -32,6 +32,10 @@ pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Config, String> {
             .unwrap_or_else(|_| "3000".to_string())
             .parse()
             .unwrap_or(3000),
+        input_queue_capacity: std::env::var("INPUT_QUEUE_CAPACITY")
+            .unwrap_or_else(|_| "1024".to_string())
+            .parse()
+            .unwrap_or(1024),
         output_console_enabled: std::env::var("OUTPUT_CONSOLE_ENABLED")
             .unwrap_or_else(|_| "true".to_string())
             .parse()
@@ -44,6 +48,12 @@ pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Config, String> {
             .unwrap_or_else(|_| "true".to_string())
             .parse()
             .unwrap_or(true),
+        output_console_waveform_decimation: std::env::var("OUTPUT_CONSOLE_WAVEFORM_DECIMATION")
+            .unwrap_or_default(),
+        output_console_devices: std::env::var("OUTPUT_CONSOLE_DEVICES")
+            .unwrap_or_default(),
+        output_console_mode: std::env::var("OUTPUT_CONSOLE_MODE")
+            .unwrap_or_else(|_| "delta".to_string()),
         output_ble_enabled: std::env::var("OUTPUT_BLE_ENABLED")
             .unwrap_or_else(|_| "false".to_string())
             .parse()
@@ -52,6 +62,181 @@ pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Config, String> {
             .unwrap_or_else(|_| "VitalConnect".to_string()),
         output_ble_service_uuid: std::env::var("OUTPUT_BLE_SERVICE_UUID")
             .unwrap_or_else(|_| "12345678-1234-5678-1234-567812345678".to_string()),
+        output_ble_waveform_decimation: std::env::var("OUTPUT_BLE_WAVEFORM_DECIMATION")
+            .unwrap_or_default(),
+        output_ble_devices: std::env::var("OUTPUT_BLE_DEVICES")
+            .unwrap_or_default(),
+        output_ble_mode: std::env::var("OUTPUT_BLE_MODE")
+            .unwrap_or_else(|_| "snapshot".to_string()),
+        output_queue_capacity: std::env::var("OUTPUT_QUEUE_CAPACITY")
+            .unwrap_or_else(|_| "256".to_string())
+            .parse()
+            .unwrap_or(256),
+        output_console_overflow: std::env::var("OUTPUT_CONSOLE_OVERFLOW")
+            .unwrap_or_else(|_| "drop_oldest".to_string()),
+        output_ble_overflow: std::env::var("OUTPUT_BLE_OVERFLOW")
+            .unwrap_or_else(|_| "coalesce".to_string()),
+        alarm_enabled: std::env::var("ALARM_ENABLED")
+            .unwrap_or_else(|_| "true".to_string())
+            .parse()
+            .unwrap_or(true),
+        alarm_limits_path: std::env::var("ALARM_LIMITS_PATH")
+            .ok()
+            .filter(|path| !path.is_empty()),
+        watchdog_enabled: std::env::var("WATCHDOG_ENABLED")
+            .unwrap_or_else(|_| "true".to_string())
+            .parse()
+            .unwrap_or(true),
+        watchdog_device_timeout_secs: std::env::var("WATCHDOG_DEVICE_TIMEOUT_SECS")
+            .unwrap_or_else(|_| "10".to_string())
+            .parse()
+            .unwrap_or(10),
+        watchdog_track_timeout_secs: std::env::var("WATCHDOG_TRACK_TIMEOUT_SECS")
+            .unwrap_or_else(|_| "30".to_string())
+            .parse()
+            .unwrap_or(30),
+        sequencing_enabled: std::env::var("SEQUENCING_ENABLED")
+            .unwrap_or_else(|_| "true".to_string())
+            .parse()
+            .unwrap_or(true),
+        sequencing_jitter_ms: std::env::var("SEQUENCING_JITTER_MS")
+            .unwrap_or_else(|_| "0".to_string())
+            .parse()
+            .unwrap_or(0),
+        sequencing_max_skew_secs: std::env::var("SEQUENCING_MAX_SKEW_SECS")
+            .unwrap_or_else(|_| "60".to_string())
+            .parse()
+            .unwrap_or(60),
+        clock_sync_enabled: std::env::var("CLOCK_SYNC_ENABLED")
+            .unwrap_or_else(|_| "true".to_string())
+            .parse()
+            .unwrap_or(true),
+        clock_drift_threshold_ms: std::env::var("CLOCK_DRIFT_THRESHOLD_MS")
+            .unwrap_or_else(|_| "2000".to_string())
+            .parse()
+            .unwrap_or(2000),
+        clock_correction_enabled: std::env::var("CLOCK_CORRECTION_ENABLED")
+            .unwrap_or_else(|_| "false".to_string())
+            .parse()
+            .unwrap_or(false),
+        signal_quality_enabled: std::env::var("SIGNAL_QUALITY_ENABLED")
+            .unwrap_or_else(|_| "true".to_string())
+            .parse()
+            .unwrap_or(true),
+        signal_quality_min_score: std::env::var("SIGNAL_QUALITY_MIN_SCORE")
+            .unwrap_or_else(|_| "0.5".to_string())
+            .parse()
+            .unwrap_or(0.5),
+        waveform_extended_stats: std::env::var("WAVEFORM_EXTENDED_STATS")
+            .unwrap_or_else(|_| "false".to_string())
+            .parse()
+            .unwrap_or(false),
+        waveform_stats_percentiles: std::env::var("WAVEFORM_STATS_PERCENTILES")
+            .unwrap_or_else(|_| "5,25,50,75,95".to_string()),
+        waveform_filters: std::env::var("WAVEFORM_FILTERS").unwrap_or_default(),
+        early_warning_scores: std::env::var("EARLY_WARNING_SCORES")
+            .unwrap_or_else(|_| "news2".to_string()),
+        early_warning_max_input_age_secs: std::env::var("EARLY_WARNING_MAX_INPUT_AGE_SECS")
+            .unwrap_or_else(|_| "300".to_string())
+            .parse()
+            .unwrap_or(300),
+        qrs_detection_enabled: std::env::var("QRS_DETECTION_ENABLED")
+            .unwrap_or_else(|_| "true".to_string())
+            .parse()
+            .unwrap_or(true),
+        qrs_window_secs: std::env::var("QRS_WINDOW_SECS")
+            .unwrap_or_else(|_| "10".to_string())
+            .parse()
+            .unwrap_or(10),
+        trend_enabled: std::env::var("TREND_ENABLED")
+            .unwrap_or_else(|_| "true".to_string())
+            .parse()
+            .unwrap_or(true),
+        trend_window_secs: std::env::var("TREND_WINDOW_SECS")
+            .unwrap_or_else(|_| "300".to_string())
+            .parse()
+            .unwrap_or(300),
+        trend_stable_percent: std::env::var("TREND_STABLE_PERCENT")
+            .unwrap_or_else(|_| "5".to_string())
+            .parse()
+            .unwrap_or(5.0),
+        pipeline_stages: std::env::var("PIPELINE_STAGES").unwrap_or_else(|_| {
+            "sequencing,clock,quality,filter,statistics,heart_rate,early_warning,trend,units,deidentify".to_string()
+        }),
+        unit_conversions: std::env::var("UNIT_CONVERSIONS").unwrap_or_default(),
+        deidentify_enabled: std::env::var("DEIDENTIFY_ENABLED")
+            .unwrap_or_else(|_| "false".to_string())
+            .parse()
+            .unwrap_or(false),
+        deidentify_salt: std::env::var("DEIDENTIFY_SALT").unwrap_or_default(),
+        history_enabled: std::env::var("HISTORY_ENABLED")
+            .unwrap_or_else(|_| "true".to_string())
+            .parse()
+            .unwrap_or(true),
+        history_retention_secs: std::env::var("HISTORY_RETENTION_SECS")
+            .unwrap_or_else(|_| "3600".to_string())
+            .parse()
+            .unwrap_or(3600),
+        history_max_values_per_track: std::env::var("HISTORY_MAX_VALUES_PER_TRACK")
+            .unwrap_or_else(|_| "3600".to_string())
+            .parse()
+            .unwrap_or(3600),
+        history_max_waveform_points_per_track: std::env::var("HISTORY_MAX_WAVEFORM_POINTS_PER_TRACK")
+            .unwrap_or_else(|_| "30000".to_string())
+            .parse()
+            .unwrap_or(30000),
+        history_max_tracks: std::env::var("HISTORY_MAX_TRACKS")
+            .unwrap_or_else(|_| "512".to_string())
+            .parse()
+            .unwrap_or(512),
+        snapshot_max_age_secs: std::env::var("SNAPSHOT_MAX_AGE_SECS")
+            .unwrap_or_else(|_| "300".to_string())
+            .parse()
+            .unwrap_or(300),
+        shutdown_timeout_secs: std::env::var("SHUTDOWN_TIMEOUT_SECS")
+            .unwrap_or_else(|_| "5".to_string())
+            .parse()
+            .unwrap_or(5),
+        supervisor_backoff_initial_ms: std::env::var("SUPERVISOR_BACKOFF_INITIAL_MS")
+            .unwrap_or_else(|_| "500".to_string())
+            .parse()
+            .unwrap_or(500),
+        supervisor_backoff_max_ms: std::env::var("SUPERVISOR_BACKOFF_MAX_MS")
+            .unwrap_or_else(|_| "30000".to_string())
+            .parse()
+            .unwrap_or(30000),
+        supervisor_max_restarts: std::env::var("SUPERVISOR_MAX_RESTARTS")
+            .unwrap_or_else(|_| "5".to_string())
+            .parse()
+            .unwrap_or(5),
+        supervisor_critical: std::env::var("SUPERVISOR_CRITICAL")
+            .unwrap_or_else(|_| "socketio".to_string()),
+        http_host: std::env::var("HTTP_HOST")
+            .unwrap_or_else(|_| "127.0.0.1".to_string()),
+        http_port: std::env::var("HTTP_PORT")
+            .unwrap_or_else(|_| "9100".to_string())
+            .parse()
+            .unwrap_or(9100),
+        metrics_enabled: std::env::var("METRICS_ENABLED")
+            .unwrap_or_else(|_| "false".to_string())
+            .parse()
+            .unwrap_or(false),
+        health_enabled: std::env::var("HEALTH_ENABLED")
+            .unwrap_or_else(|_| "false".to_string())
+            .parse()
+            .unwrap_or(false),
+        health_stale_secs: std::env::var("HEALTH_STALE_SECS")
+            .unwrap_or_else(|_| "30".to_string())
+            .parse()
+            .unwrap_or(30),
+        api_enabled: std::env::var("API_ENABLED")
+            .unwrap_or_else(|_| "false".to_string())
+            .parse()
+            .unwrap_or(false),
+        api_max_events: std::env::var("API_MAX_EVENTS")
+            .unwrap_or_else(|_| "1000".to_string())
+            .parse()
+            .unwrap_or(1000),
         debug_enabled: std::env::var("DEBUG_ENABLED")
             .unwrap_or_else(|_| "false".to_string())
             .parse()
@@ -4,6 +4,11 @@
 
 pub mod loader;
 
+use crate::derived::EarlyWarningScore;
+use crate::output::{OutputMode, OutputRoute, OverflowPolicy};
+use crate::alarm::AlarmLimitSet;
+use crate::pipeline::{stage, Deidentifier, UnitConversion};
+use crate::signal::{Decimation, FilterRule, WaveformStatistics};
 use clap::Parser;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
@@ -36,6 +41,10 @@ pub struct Config {
     #[arg(long, short = 'p', default_value = "3000")]
     pub socketio_port: u16,
 
+    /// Frames buffered between Socket.IO input and processing (excess frames are dropped)
+    #[arg(long, default_value = "1024")]
+    pub input_queue_capacity: usize,
+
     // Console Output Configuration
     /// Enable console output
     #[arg(long, default_value = "true")]
@@ -49,6 +58,18 @@ pub struct Config {
     #[arg(long, default_value = "true")]
     pub output_console_colorized: bool,
 
+    /// Console waveform decimation (lttb:N, minmax:N, resample:HZ; empty for full resolution)
+    #[arg(long, default_value = "")]
+    pub output_console_waveform_decimation: String,
+
+    /// Devices/rooms shown on the console (DEVICE or DEVICE/ROOM, comma-separated; empty for all)
+    #[arg(long, default_value = "")]
+    pub output_console_devices: String,
+
+    /// Console delivery mode (delta: updates as received, snapshot: latest value of every track)
+    #[arg(long, default_value = "delta")]
+    pub output_console_mode: String,
+
     // BLE Output Configuration
     /// Enable BLE output
     #[arg(long, default_value = "false")]
@@ -62,6 +83,227 @@ pub struct Config {
     #[arg(long, default_value = "12345678-1234-5678-1234-567812345678")]
     pub output_ble_service_uuid: String,
 
+    /// BLE waveform decimation (lttb:N, minmax:N, resample:HZ; empty to exclude waveforms)
+    #[arg(long, default_value = "")]
+    pub output_ble_waveform_decimation: String,
+
+    /// Devices/rooms transmitted over BLE (DEVICE or DEVICE/ROOM, comma-separated; empty for all)
+    #[arg(long, default_value = "")]
+    pub output_ble_devices: String,
+
+    /// BLE delivery mode (delta: updates as received, snapshot: latest value of every track)
+    #[arg(long, default_value = "snapshot")]
+    pub output_ble_mode: String,
+
+    // Output Queue Configuration
+    /// Messages buffered per output
+    #[arg(long, default_value = "256")]
+    pub output_queue_capacity: usize,
+
+    /// Console queue overflow policy (drop_oldest, drop_newest, coalesce, block)
+    #[arg(long, default_value = "drop_oldest")]
+    pub output_console_overflow: String,
+
+    /// BLE queue overflow policy (drop_oldest, drop_newest, coalesce, block)
+    #[arg(long, default_value = "coalesce")]
+    pub output_ble_overflow: String,
+
+    // Alarm Configuration
+    /// Enable clinical alarm evaluation
+    #[arg(long, default_value = "true")]
+    pub alarm_enabled: bool,
+
+    /// Alarm limits file (JSON, built-in adult defaults if not set)
+    #[arg(long)]
+    pub alarm_limits_path: Option<String>,
+
+    // Watchdog Configuration
+    /// Enable stale-data and sensor-off detection
+    #[arg(long, default_value = "true")]
+    pub watchdog_enabled: bool,
+
+    /// Seconds without any frame before a device is reported stale
+    #[arg(long, default_value = "10")]
+    pub watchdog_device_timeout_secs: u64,
+
+    /// Seconds without update before a track is reported as sensor off
+    #[arg(long, default_value = "30")]
+    pub watchdog_track_timeout_secs: u64,
+
+    // Sequencing Configuration
+    /// Enable per-track deduplication and reordering of records
+    #[arg(long, default_value = "true")]
+    pub sequencing_enabled: bool,
+
+    /// Time records are held for reordering in milliseconds (0 = no hold)
+    #[arg(long, default_value = "0")]
+    pub sequencing_jitter_ms: u64,
+
+    /// Distance from the receive time in seconds above which a record timestamp is flagged
+    #[arg(long, default_value = "60")]
+    pub sequencing_max_skew_secs: u64,
+
+    // Clock Configuration
+    /// Enable device clock offset estimation and drift warnings
+    #[arg(long, default_value = "true")]
+    pub clock_sync_enabled: bool,
+
+    /// Device clock offset in milliseconds above which drift is reported
+    #[arg(long, default_value = "2000")]
+    pub clock_drift_threshold_ms: u64,
+
+    /// Rewrite record timestamps to the local clock (device time kept)
+    #[arg(long, default_value = "false")]
+    pub clock_correction_enabled: bool,
+
+    // Signal Processing Configuration
+    /// Enable signal quality assessment and artifact flagging
+    #[arg(long, default_value = "true")]
+    pub signal_quality_enabled: bool,
+
+    /// Minimum quality score (0-1) for a value to be evaluated by alarms
+    #[arg(long, default_value = "0.5")]
+    pub signal_quality_min_score: f64,
+
+    /// Compute extended waveform statistics (std-dev, RMS, percentiles, frequency)
+    #[arg(long, default_value = "false")]
+    pub waveform_extended_stats: bool,
+
+    /// Percentiles reported by extended waveform statistics (comma-separated)
+    #[arg(long, default_value = "5,25,50,75,95")]
+    pub waveform_stats_percentiles: String,
+
+    /// Waveform filter chains, e.g. "ECG=notch:50,highpass:0.5,lowpass:40;PLETH=lowpass:10" (empty to disable)
+    #[arg(long, default_value = "")]
+    pub waveform_filters: String,
+
+    // Derived Metrics Configuration
+    /// Early warning scores to publish (comma-separated: news2, mews; empty to disable)
+    #[arg(long, default_value = "news2")]
+    pub early_warning_scores: String,
+
+    /// Seconds after which a score input is considered missing
+    #[arg(long, default_value = "300")]
+    pub early_warning_max_input_age_secs: u64,
+
+    /// Enable heart rate and beat detection from ECG waveforms
+    #[arg(long, default_value = "true")]
+    pub qrs_detection_enabled: bool,
+
+    /// ECG analysis window for QRS detection in seconds
+    #[arg(long, default_value = "10")]
+    pub qrs_window_secs: u64,
+
+    // Trend Configuration
+    /// Enable rolling trends on numeric tracks
+    #[arg(long, default_value = "true")]
+    pub trend_enabled: bool,
+
+    /// Rolling trend window in seconds
+    #[arg(long, default_value = "300")]
+    pub trend_window_secs: u64,
+
+    /// Change over the window (percent of the average) still considered stable
+    #[arg(long, default_value = "5")]
+    pub trend_stable_percent: f64,
+
+    // Pipeline Configuration
+    /// Order of the built-in processing stages (comma-separated; a stage left out is not run)
+    #[arg(
+        long,
+        default_value = "sequencing,clock,quality,filter,statistics,heart_rate,early_warning,trend,units,deidentify"
+    )]
+    pub pipeline_stages: String,
+
+    /// Unit conversions, e.g. "°C=°F,mmHg=kPa" (empty to disable)
+    #[arg(long, default_value = "")]
+    pub unit_conversions: String,
+
+    /// Replace device identifiers and room names by salted pseudonyms
+    #[arg(long, default_value = "false")]
+    pub deidentify_enabled: bool,
+
+    /// Secret salt of the pseudonyms (required when de-identification is enabled)
+    #[arg(long, default_value = "")]
+    pub deidentify_salt: String,
+
+    // History Configuration
+    /// Enable the in-memory time-series history
+    #[arg(long, default_value = "true")]
+    pub history_enabled: bool,
+
+    /// History retention in seconds
+    #[arg(long, default_value = "3600")]
+    pub history_retention_secs: u64,
+
+    /// Maximum numeric values kept per track
+    #[arg(long, default_value = "3600")]
+    pub history_max_values_per_track: usize,
+
+    /// Maximum waveform points kept per track
+    #[arg(long, default_value = "30000")]
+    pub history_max_waveform_points_per_track: usize,
+
+    /// Maximum number of tracks kept (least recently received dropped first)
+    #[arg(long, default_value = "512")]
+    pub history_max_tracks: usize,
+
+    // Snapshot Configuration
+    /// Age after which a track no longer received leaves the device snapshot (seconds)
+    #[arg(long, default_value = "300")]
+    pub snapshot_max_age_secs: u64,
+
+    // Shutdown Configuration
+    /// Time allowed to drain in-flight frames through the outputs on shutdown (seconds)
+    #[arg(long, default_value = "5")]
+    pub shutdown_timeout_secs: u64,
+
+    // Supervisor Configuration
+    /// Delay before the first restart of a failed component (milliseconds, doubled per failure)
+    #[arg(long, default_value = "500")]
+    pub supervisor_backoff_initial_ms: u64,
+
+    /// Maximum delay between restarts (milliseconds)
+    #[arg(long, default_value = "30000")]
+    pub supervisor_backoff_max_ms: u64,
+
+    /// Consecutive restarts of a critical component before the application stops
+    #[arg(long, default_value = "5")]
+    pub supervisor_max_restarts: u32,
+
+    /// Critical components (socketio, console, ble, http; comma-separated)
+    #[arg(long, default_value = "socketio")]
+    pub supervisor_critical: String,
+
+    // HTTP Configuration
+    /// HTTP server host (observability endpoints)
+    #[arg(long, default_value = "127.0.0.1")]
+    pub http_host: String,
+
+    /// HTTP server port
+    #[arg(long, default_value = "9100")]
+    pub http_port: u16,
+
+    /// Serve processing metrics on /metrics (Prometheus text format)
+    #[arg(long, default_value = "false")]
+    pub metrics_enabled: bool,
+
+    /// Serve health and readiness on /healthz and /readyz
+    #[arg(long, default_value = "false")]
+    pub health_enabled: bool,
+
+    /// Time without frames after which the data is stale (not ready, seconds)
+    #[arg(long, default_value = "30")]
+    pub health_stale_secs: u64,
+
+    /// Serve the read-only REST API under /api (devices, latest values, history, events)
+    #[arg(long, default_value = "false")]
+    pub api_enabled: bool,
+
+    /// Recent alarm events kept for the REST API
+    #[arg(long, default_value = "1000")]
+    pub api_max_events: usize,
+
     // Debug Configuration
     /// Enable debug mode
     #[arg(long, default_value = "false")]
@@ -150,6 +392,140 @@ impl Config {
             }
         }
 
+        // Validate watchdog timeouts
+        if self.watchdog_enabled
+            && (self.watchdog_device_timeout_secs == 0 || self.watchdog_track_timeout_secs == 0)
+        {
+            return Err("Watchdog timeouts must be greater than 0".to_string());
+        }
+
+        // Validate per-output waveform decimation
+        Decimation::parse(&self.output_console_waveform_decimation)?;
+        Decimation::parse(&self.output_ble_waveform_decimation)?;
+
+        // Validate per-output device routes
+        OutputRoute::parse(&self.output_console_devices)?;
+        OutputRoute::parse(&self.output_ble_devices)?;
+
+        // Validate per-output delivery modes
+        OutputMode::parse(&self.output_console_mode)?;
+        OutputMode::parse(&self.output_ble_mode)?;
+
+        // Validate queues
+        if self.input_queue_capacity == 0 || self.output_queue_capacity == 0 {
+            return Err("Queue capacities must be greater than 0".to_string());
+        }
+        OverflowPolicy::parse(&self.output_console_overflow)?;
+        OverflowPolicy::parse(&self.output_ble_overflow)?;
+
+        // Validate snapshot track age
+        if self.snapshot_max_age_secs == 0 {
+            return Err("Snapshot maximum age must be greater than 0".to_string());
+        }
+
+        // Validate shutdown timeout
+        if self.shutdown_timeout_secs == 0 {
+            return Err("Shutdown timeout must be greater than 0".to_string());
+        }
+
+        // Validate supervisor backoff
+        if self.supervisor_backoff_initial_ms == 0
+            || self.supervisor_backoff_max_ms < self.supervisor_backoff_initial_ms
+        {
+            return Err("Supervisor backoff must be greater than 0 and maximum not below initial".to_string());
+        }
+
+        // Validate signal quality threshold
+        if !(0.0..=1.0).contains(&self.signal_quality_min_score) {
+            return Err("Signal quality minimum score must be between 0 and 1".to_string());
+        }
+
+        // Validate extended statistics percentiles
+        WaveformStatistics::parse_percentiles(&self.waveform_stats_percentiles)?;
+
+        // Validate waveform filter rules
+        FilterRule::parse_list(&self.waveform_filters)?;
+
+        // Validate early warning scores
+        EarlyWarningScore::parse_list(&self.early_warning_scores)?;
+
+        // Validate pipeline stage order and unit conversions
+        stage::parse_order(&self.pipeline_stages)?;
+        UnitConversion::parse_list(&self.unit_conversions)?;
+
+        // Validate HTTP port (served next to the Socket.IO server)
+        let http_enabled = self.metrics_enabled || self.health_enabled || self.api_enabled;
+        if http_enabled && (self.http_port == 0 || self.http_port == self.socketio_port) {
+            return Err("HTTP port must be non-zero and differ from the Socket.IO port".to_string());
+        }
+
+        // Validate health staleness delay
+        if self.health_enabled && self.health_stale_secs == 0 {
+            return Err("Health stale delay must be greater than 0".to_string());
+        }
+
+        // Validate de-identification salt (unsalted pseudonyms can be reversed)
+        if self.deidentify_enabled && self.deidentify_salt.trim().is_empty() {
+            return Err("De-identification requires a salt".to_string());
+        }
+
+        // Validate alarm limits file (no fallback to limits not configured)
+        let alarm_limits = match (self.alarm_enabled, &self.alarm_limits_path) {
+            (true, Some(path)) => Some(
+                AlarmLimitSet::load_from_file(path)
+                    .map_err(|e| format!("Invalid alarm limits file {}: {}", path, e))?,
+            ),
+            _ => None,
+        };
+
+        // Validate device and room scopes (routing and limits see the pseudonyms)
+        if self.deidentify_enabled {
+            let deidentifier = Deidentifier::new(&self.deidentify_salt);
+            for spec in [&self.output_console_devices, &self.output_ble_devices] {
+                for (device_id, room_name) in OutputRoute::parse(spec)?.scopes() {
+                    deidentifier.check_scope("Output route", device_id, room_name)?;
+                }
+            }
+
+            for limit_override in alarm_limits.iter().flat_map(|limits| &limits.overrides) {
+                deidentifier.check_scope(
+                    "Alarm limit override",
+                    limit_override.device_id.as_deref(),
+                    limit_override.room_name.as_deref(),
+                )?;
+            }
+        }
+
+        // Validate QRS detection window (learning phase needs a few beats)
+        if self.qrs_detection_enabled && self.qrs_window_secs < 3 {
+            return Err("QRS window must be at least 3 seconds".to_string());
+        }
+
+        // Validate trend settings
+        if self.trend_enabled && (self.trend_window_secs == 0 || self.trend_stable_percent < 0.0) {
+            return Err("Trend window must be greater than 0 and stable percent non-negative".to_string());
+        }
+
+        // Validate sequencing settings
+        if self.sequencing_enabled && self.sequencing_max_skew_secs == 0 {
+            return Err("Sequencing max skew must be greater than 0".to_string());
+        }
+
+        // Validate clock settings
+        if self.clock_sync_enabled && self.clock_drift_threshold_ms == 0 {
+            return Err("Clock drift threshold must be greater than 0".to_string());
+        }
+
+        // Validate history bounds
+        if self.history_enabled
+            && (self.history_retention_secs == 0
+                || self.history_max_values_per_track == 0
+                || self.history_max_waveform_points_per_track == 0
+                || self.history_max_tracks == 0)
+        {
+            return Err("History retention and bounds must be greater than 0".to_string());
+        }
+
         // Validate log level
         let valid_levels = ["SUCCESS", "INFO", "WARNING", "ERROR", "DEBUG"];
         if !valid_levels.contains(&self.log_level.to_uppercase().as_str()) {
@@ -158,21 +534,6 @@ impl Config {
 
         Ok(())
     }
-
-    /// ID SRS: SRS-FN-CONFIG-004
-    /// Title: socket_url
-    ///
-    /// Description: VRConnect shall construct the complete Socket.IO URL
-    /// from host and port configuration parameters.
-    ///
-    /// Version: V1.0
-    ///
-    /// # Returns
-    /// Complete Socket.IO URL string
-    #[allow(dead_code)]
-    pub fn socket_url(&self) -> String {
-        format!("http://{}:{}", self.socketio_host, self.socketio_port)
-    }
 }
 
 #[cfg(test)]
@@ -208,4 +569,17 @@ mod tests {
         // TODO: Implement URL construction test
         assert!(true);
     }
+
+    #[test]
+    fn test_invalid_alarm_limits_file() {
+        let mut config = <Config as Parser>::parse_from(["vrconnect"]);
+        config.alarm_enabled = true;
+        config.alarm_limits_path = Some("/nonexistent/limits.json".to_string());
+
+        let error = config.validate().unwrap_err();
+        assert!(error.contains("Invalid alarm limits file"));
+
+        config.alarm_enabled = false;
+        assert!(config.validate().is_ok());
+    }
 }
@@ -0,0 +1,182 @@
+// /src/core/builder.rs
+// Module: core.builder
+// Purpose: Construction of a VitalProcessor with user-defined stages, outputs and inputs
+
+use crate::config::Config;
+use crate::core::VitalProcessor;
+use crate::input::InputSource;
+use crate::output::OutputSink;
+use crate::pipeline::{PipelineStage, RawStage, StagePosition};
+use std::sync::Arc;
+
+/// ID SRS: SRS-MOD-BUILDER-001
+/// Title: VitalProcessorBuilder
+///
+/// Description: VRConnect shall let an embedding application extend the
+/// processor built from a configuration with its own pipeline stages,
+/// outputs and inputs, and choose whether the processor handles termination
+/// signals itself.
+///
+/// Version: V1.0
+pub struct VitalProcessorBuilder {
+    pub(crate) config: Config,
+    pub(crate) stages: Vec<(StagePosition, Box<dyn PipelineStage>)>,
+    pub(crate) raw_stages: Vec<(StagePosition, Arc<dyn RawStage>)>,
+    pub(crate) sinks: Vec<Arc<dyn OutputSink>>,
+    pub(crate) inputs: Vec<Arc<dyn InputSource>>,
+    pub(crate) handle_signals: bool,
+    pub(crate) socketio_enabled: bool,
+}
+
+impl VitalProcessorBuilder {
+    /// ID SRS: SRS-FN-BUILDER-001
+    /// Title: new
+    ///
+    /// Description: VRConnect shall construct a builder from a configuration.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `config` - Application configuration
+    ///
+    /// # Returns
+    /// New VitalProcessorBuilder instance
+    pub fn new(config: Config) -> Self {
+        Self {
+            config,
+            stages: Vec::new(),
+            raw_stages: Vec::new(),
+            sinks: Vec::new(),
+            inputs: Vec::new(),
+            handle_signals: true,
+            socketio_enabled: true,
+        }
+    }
+
+    /// ID SRS: SRS-FN-BUILDER-002
+    /// Title: with_stage
+    ///
+    /// Description: VRConnect shall insert a user-defined stage into the
+    /// processing pipeline, relative to the built-in stages enabled in the
+    /// configuration.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `position` - Insertion position (an inactive referenced stage fails `run`)
+    /// * `stage` - Stage to insert
+    ///
+    /// # Returns
+    /// Builder with the stage
+    pub fn with_stage(mut self, position: StagePosition, stage: Box<dyn PipelineStage>) -> Self {
+        self.stages.push((position, stage));
+        self
+    }
+
+    /// ID SRS: SRS-FN-BUILDER-003
+    /// Title: with_raw_stage
+    ///
+    /// Description: VRConnect shall insert a user-defined stage into the
+    /// pre-parse pipeline of the received frames (decompress, clean).
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `position` - Insertion position (an unknown referenced stage fails `run`)
+    /// * `stage` - Raw stage to insert
+    ///
+    /// # Returns
+    /// Builder with the raw stage
+    pub fn with_raw_stage(mut self, position: StagePosition, stage: Arc<dyn RawStage>) -> Self {
+        self.raw_stages.push((position, stage));
+        self
+    }
+
+    /// ID SRS: SRS-FN-BUILDER-004
+    /// Title: with_sink
+    ///
+    /// Description: VRConnect shall deliver data and alarm events to a
+    /// user-defined output in addition to the configured ones, behind its own
+    /// bounded queue (drop oldest) and under supervision.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `sink` - Output to add
+    ///
+    /// # Returns
+    /// Builder with the output
+    pub fn with_sink(mut self, sink: Arc<dyn OutputSink>) -> Self {
+        self.sinks.push(sink);
+        self
+    }
+
+    /// ID SRS: SRS-FN-BUILDER-005
+    /// Title: with_input
+    ///
+    /// Description: VRConnect shall receive data from a user-defined input in
+    /// addition to the Socket.IO server, under supervision.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `input` - Input to add
+    ///
+    /// # Returns
+    /// Builder with the input
+    pub fn with_input(mut self, input: Arc<dyn InputSource>) -> Self {
+        self.inputs.push(input);
+        self
+    }
+
+    /// ID SRS: SRS-FN-BUILDER-006
+    /// Title: with_signal_handling
+    ///
+    /// Description: VRConnect shall let an embedding application disable the
+    /// handling of termination signals by the processor, the application
+    /// then stopping it through the shutdown token.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `enabled` - Stop the processor on SIGINT/SIGTERM (default true)
+    ///
+    /// # Returns
+    /// Builder with the signal handling setting
+    pub fn with_signal_handling(mut self, enabled: bool) -> Self {
+        self.handle_signals = enabled;
+        self
+    }
+
+    /// ID SRS: SRS-FN-BUILDER-007
+    /// Title: with_socketio
+    ///
+    /// Description: VRConnect shall let an embedding application run the
+    /// processor without the Socket.IO server, data then coming only from
+    /// user-defined inputs.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `enabled` - Listen on the configured Socket.IO port (default true)
+    ///
+    /// # Returns
+    /// Builder with the Socket.IO setting
+    pub fn with_socketio(mut self, enabled: bool) -> Self {
+        self.socketio_enabled = enabled;
+        self
+    }
+
+    /// ID SRS: SRS-FN-BUILDER-008
+    /// Title: build
+    ///
+    /// Description: VRConnect shall construct the VitalProcessor.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// New VitalProcessor instance
+    pub fn build(self) -> VitalProcessor {
+        VitalProcessor::from_builder(self)
+    }
+}
@@ -0,0 +1,278 @@
+// /src/core/clock.rs
+// Module: core.clock
+// Purpose: Device clock offset estimation, drift warning and timestamp correction
+
+use crate::core::watchdog::ALL_ROOMS;
+use crate::domain::{AlarmAction, AlarmCondition, AlarmEvent, AlarmPriority, ProcessedData, TimestampFlag};
+use chrono::{DateTime, Duration, Utc};
+use std::collections::{HashMap, VecDeque};
+
+/// Parameter name used for device-level clock drift conditions
+const CLOCK_DRIFT_PARAMETER: &str = "CLOCK_DRIFT";
+
+/// Number of per-frame offset estimates the device offset is the median of
+const OFFSET_WINDOW: usize = 30;
+
+/// Clock state of one device
+#[derive(Default)]
+struct DeviceClock {
+    /// Per-frame offsets (receive time - newest device timestamp) in milliseconds
+    estimates: VecDeque<i64>,
+    offset_ms: i64,
+    drifted: bool,
+}
+
+/// ID SRS: SRS-MOD-CLOCKSYNC-001
+/// Title: ClockSync
+///
+/// Description: VRConnect shall estimate the clock offset of every device as
+/// the median, over the last frames, of the difference between receive time
+/// and newest record timestamp; raise a "clock drift" technical condition
+/// when the offset exceeds a threshold; and optionally rewrite record
+/// timestamps to the local clock, keeping the original device time.
+///
+/// Version: V1.0
+pub struct ClockSync {
+    threshold: Duration,
+    correct: bool,
+    devices: HashMap<String, DeviceClock>,
+}
+
+impl ClockSync {
+    /// ID SRS: SRS-FN-CLOCKSYNC-001
+    /// Title: new
+    ///
+    /// Description: VRConnect shall construct a ClockSync with a drift
+    /// threshold and correction mode.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `threshold_ms` - Offset above which the device clock is reported as drifting
+    /// * `correct` - Rewrite record timestamps to the local clock
+    ///
+    /// # Returns
+    /// New ClockSync instance
+    pub fn new(threshold_ms: u64, correct: bool) -> Self {
+        Self {
+            threshold: Duration::milliseconds(threshold_ms as i64),
+            correct,
+            devices: HashMap::new(),
+        }
+    }
+
+    /// ID SRS: SRS-FN-CLOCKSYNC-002
+    /// Title: apply
+    ///
+    /// Description: VRConnect shall update the offset estimate of the device
+    /// from the records carrying a device timestamp, report drift transitions
+    /// and, in correction mode, shift those records by the offset (the device
+    /// time is kept in `device_timestamp`).
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `data` - Processed vital data (timestamp = receive time), modified in place
+    ///
+    /// # Returns
+    /// Raised or cleared clock drift events
+    pub fn apply(&mut self, data: &mut ProcessedData) -> Vec<AlarmEvent> {
+        let received = data.timestamp;
+        let mut events = Vec::new();
+
+        let newest = data
+            .all_tracks
+            .iter()
+            .filter(|track| track.timestamp_flag != Some(TimestampFlag::Missing))
+            .map(|track| track.timestamp)
+            .max();
+        let Some(newest) = newest else {
+            return events;
+        };
+
+        let clock = self.devices.entry(data.device_id.clone()).or_default();
+        clock.estimates.push_back((received - newest).num_milliseconds());
+        if clock.estimates.len() > OFFSET_WINDOW {
+            clock.estimates.pop_front();
+        }
+
+        let mut sorted: Vec<i64> = clock.estimates.iter().copied().collect();
+        sorted.sort_unstable();
+        clock.offset_ms = sorted[sorted.len() / 2];
+
+        let drifting = clock.offset_ms.abs() > self.threshold.num_milliseconds();
+        if drifting != clock.drifted {
+            clock.drifted = drifting;
+            if drifting {
+                log::warn!(
+                    "⚠️  Clock of device {} is {} ms off the local clock",
+                    data.device_id,
+                    clock.offset_ms
+                );
+            } else {
+                log::info!("Clock of device {} back within threshold", data.device_id);
+            }
+            events.push(Self::make_event(
+                &data.device_id,
+                if drifting { AlarmAction::Raised } else { AlarmAction::Cleared },
+                clock.offset_ms,
+                self.threshold,
+                received,
+            ));
+        }
+
+        if self.correct {
+            let offset = Duration::milliseconds(clock.offset_ms);
+            for room in data.rooms.iter_mut() {
+                for track in room.tracks.iter_mut() {
+                    if track.timestamp_flag != Some(TimestampFlag::Missing) {
+                        track.device_timestamp = Some(track.timestamp);
+                        track.timestamp += offset;
+                    }
+                }
+            }
+            data.refresh_all_tracks();
+        }
+
+        events
+    }
+
+    /// ID SRS: SRS-FN-CLOCKSYNC-003
+    /// Title: offset
+    ///
+    /// Description: VRConnect shall return the current clock offset estimate
+    /// of a device (local time minus device time).
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `device_id` - VitalRecorder device identifier
+    ///
+    /// # Returns
+    /// Offset, or None if the device has no estimate yet
+    pub fn offset(&self, device_id: &str) -> Option<Duration> {
+        self.devices
+            .get(device_id)
+            .map(|clock| Duration::milliseconds(clock.offset_ms))
+    }
+
+    /// ID SRS: SRS-FN-CLOCKSYNC-004
+    /// Title: make_event
+    ///
+    /// Description: VRConnect shall build a device-level clock drift event
+    /// carrying the offset and threshold in seconds.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `device_id` - VitalRecorder device identifier
+    /// * `action` - Raised or cleared
+    /// * `offset_ms` - Current offset estimate in milliseconds
+    /// * `threshold` - Drift threshold
+    /// * `now` - Event time
+    ///
+    /// # Returns
+    /// Clock drift event
+    fn make_event(
+        device_id: &str,
+        action: AlarmAction,
+        offset_ms: i64,
+        threshold: Duration,
+        now: DateTime<Utc>,
+    ) -> AlarmEvent {
+        AlarmEvent {
+            alarm_id: AlarmEvent::alarm_id_for(device_id, ALL_ROOMS, CLOCK_DRIFT_PARAMETER),
+            device_id: device_id.to_string(),
+            room_name: ALL_ROOMS.to_string(),
+            parameter: CLOCK_DRIFT_PARAMETER.to_string(),
+            condition: AlarmCondition::ClockDrift,
+            action,
+            priority: AlarmPriority::Low,
+            value: Some(offset_ms as f64 / 1000.0),
+            limit: Some(threshold.num_milliseconds() as f64 / 1000.0),
+            silenced: false,
+            message: format!(
+                "{} {} {:?} (offset {:.1}s)",
+                CLOCK_DRIFT_PARAMETER,
+                AlarmCondition::ClockDrift.label(),
+                action,
+                offset_ms as f64 / 1000.0
+            ),
+            timestamp: now,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{ProcessedRoom, ProcessedTrack};
+    use chrono::TimeZone;
+
+    fn at(millis: i64) -> DateTime<Utc> {
+        Utc.timestamp_millis_opt(1_700_000_000_000 + millis).unwrap()
+    }
+
+    fn frame(device_ms: i64, received_ms: i64) -> ProcessedData {
+        let mut missing = ProcessedTrack::synthetic("BT", Some(36.6), "°C", 0, "OR-1", at(received_ms));
+        missing.timestamp_flag = Some(TimestampFlag::Missing);
+
+        let mut data = ProcessedData::new(
+            "VR1".to_string(),
+            vec![ProcessedRoom {
+                room_index: 0,
+                room_name: "OR-1".to_string(),
+                tracks: vec![
+                    ProcessedTrack::synthetic("HR", Some(72.0), "bpm", 0, "OR-1", at(device_ms - 500)),
+                    ProcessedTrack::synthetic("HR", Some(73.0), "bpm", 0, "OR-1", at(device_ms)),
+                    missing,
+                ],
+            }],
+        );
+        data.timestamp = at(received_ms);
+        data
+    }
+
+    #[test]
+    fn test_offset_median_and_drift_events() {
+        let mut clock = ClockSync::new(2000, false);
+
+        // Device clock 5 s behind, with one delayed frame
+        for (second, delay) in [(0, 20), (1, 30), (2, 900), (3, 25)] {
+            let events = clock.apply(&mut frame(second * 1000, second * 1000 + 5000 + delay));
+            assert_eq!(events.len(), usize::from(second == 0));
+        }
+        assert_eq!(clock.offset("VR1"), Some(Duration::milliseconds(5030)));
+
+        // Clock resynchronised: the median follows after a few frames
+        let mut cleared = Vec::new();
+        for second in 4..10 {
+            cleared.extend(clock.apply(&mut frame(second * 1000 + 5000, second * 1000 + 5020)));
+        }
+        assert_eq!(cleared.len(), 1);
+        assert_eq!(cleared[0].condition, AlarmCondition::ClockDrift);
+        assert_eq!(cleared[0].action, AlarmAction::Cleared);
+    }
+
+    #[test]
+    fn test_timestamp_correction() {
+        let mut clock = ClockSync::new(2000, true);
+        let mut data = frame(0, 5000);
+        let events = clock.apply(&mut data);
+
+        assert_eq!(events[0].action, AlarmAction::Raised);
+        let corrected: Vec<(DateTime<Utc>, Option<DateTime<Utc>>)> = data
+            .all_tracks
+            .iter()
+            .map(|track| (track.timestamp, track.device_timestamp))
+            .collect();
+        assert_eq!(
+            corrected,
+            vec![
+                (at(4500), Some(at(-500))),
+                (at(5000), Some(at(0))),
+                (at(5000), None),
+            ]
+        );
+    }
+}
@@ -0,0 +1,230 @@
+// /src/core/device.rs
+// Module: core.device
+// Purpose: Per-device state (activity, rooms, latest data) kept by the processor
+
+use crate::core::Snapshot;
+use crate::domain::ProcessedData;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap};
+
+/// ID SRS: SRS-MOD-DEVICE-001
+/// Title: DeviceState
+///
+/// Description: VRConnect shall keep, per VitalRecorder device, its first and
+/// last receive times, the number of processed frames, the rooms seen, the
+/// latest processed data and the snapshot merged across partial updates.
+///
+/// Version: V1.0
+#[derive(Debug, Clone)]
+pub struct DeviceState {
+    pub device_id: String,
+    pub first_seen: DateTime<Utc>,
+    pub last_seen: DateTime<Utc>,
+    pub frames: u64,
+    pub rooms: BTreeSet<String>,
+    pub latest: ProcessedData,
+    pub snapshot: Snapshot,
+}
+
+/// ID SRS: SRS-MOD-DEVICE-002
+/// Title: DeviceSummary
+///
+/// Description: VRConnect shall describe a known device for listings.
+///
+/// Version: V1.0
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DeviceSummary {
+    pub device_id: String,
+    #[serde(with = "chrono::serde::ts_milliseconds")]
+    pub first_seen: DateTime<Utc>,
+    #[serde(with = "chrono::serde::ts_milliseconds")]
+    pub last_seen: DateTime<Utc>,
+    pub frames: u64,
+    pub rooms: Vec<String>,
+}
+
+/// ID SRS: SRS-MOD-DEVICE-003
+/// Title: DeviceRegistry
+///
+/// Description: VRConnect shall maintain separate state for every device
+/// sending data, so that several VitalRecorder instances can feed one
+/// processor and be queried or routed independently.
+///
+/// Version: V1.0
+pub struct DeviceRegistry {
+    snapshot_max_age_secs: u64,
+    devices: HashMap<String, DeviceState>,
+}
+
+impl DeviceRegistry {
+    /// ID SRS: SRS-FN-DEVICE-001
+    /// Title: new
+    ///
+    /// Description: VRConnect shall construct an empty DeviceRegistry.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `snapshot_max_age_secs` - Age after which a track leaves a device snapshot
+    ///
+    /// # Returns
+    /// New DeviceRegistry instance
+    pub fn new(snapshot_max_age_secs: u64) -> Self {
+        Self {
+            snapshot_max_age_secs,
+            devices: HashMap::new(),
+        }
+    }
+
+    /// ID SRS: SRS-FN-DEVICE-002
+    /// Title: record
+    ///
+    /// Description: VRConnect shall update the state of the device of the
+    /// processed data (registering the device on its first frame).
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `data` - Processed vital data (timestamp = receive time)
+    pub fn record(&mut self, data: &ProcessedData) {
+        let rooms = data.rooms.iter().map(|room| room.room_name.clone());
+
+        match self.devices.get_mut(&data.device_id) {
+            Some(state) => {
+                state.last_seen = data.timestamp;
+                state.frames += 1;
+                state.rooms.extend(rooms);
+                state.latest = data.clone();
+                state.snapshot.merge(data);
+            }
+            None => {
+                log::info!("New device: {}", data.device_id);
+                let mut snapshot = Snapshot::new(&data.device_id, self.snapshot_max_age_secs);
+                snapshot.merge(data);
+                self.devices.insert(
+                    data.device_id.clone(),
+                    DeviceState {
+                        device_id: data.device_id.clone(),
+                        first_seen: data.timestamp,
+                        last_seen: data.timestamp,
+                        frames: 1,
+                        rooms: rooms.collect(),
+                        latest: data.clone(),
+                        snapshot,
+                    },
+                );
+            }
+        }
+    }
+
+    /// ID SRS: SRS-FN-DEVICE-003
+    /// Title: device
+    ///
+    /// Description: VRConnect shall return the state of a device.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `device_id` - VitalRecorder device identifier
+    ///
+    /// # Returns
+    /// Device state, or None if the device never sent data
+    pub fn device(&self, device_id: &str) -> Option<&DeviceState> {
+        self.devices.get(device_id)
+    }
+
+    /// ID SRS: SRS-FN-DEVICE-004
+    /// Title: devices
+    ///
+    /// Description: VRConnect shall list the known devices sorted by
+    /// identifier.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Device summaries
+    pub fn devices(&self) -> Vec<DeviceSummary> {
+        let mut summaries: Vec<DeviceSummary> = self
+            .devices
+            .values()
+            .map(|state| DeviceSummary {
+                device_id: state.device_id.clone(),
+                first_seen: state.first_seen,
+                last_seen: state.last_seen,
+                frames: state.frames,
+                rooms: state.rooms.iter().cloned().collect(),
+            })
+            .collect();
+
+        summaries.sort_by(|a, b| a.device_id.cmp(&b.device_id));
+        summaries
+    }
+
+    /// ID SRS: SRS-FN-DEVICE-005
+    /// Title: snapshot
+    ///
+    /// Description: VRConnect shall render the snapshot of a device: latest
+    /// record of every track with its age.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `device_id` - VitalRecorder device identifier
+    /// * `now` - Reference time for track ages
+    ///
+    /// # Returns
+    /// Full snapshot, or None if the device never sent data
+    pub fn snapshot(&self, device_id: &str, now: DateTime<Utc>) -> Option<ProcessedData> {
+        self.devices
+            .get(device_id)
+            .map(|state| state.snapshot.to_processed_data(now))
+    }
+
+    /// ID SRS: SRS-FN-DEVICE-006
+    /// Title: last_seen
+    ///
+    /// Description: VRConnect shall return the receive time of the last frame
+    /// from any device.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Time of the last frame, or None if no device sent data
+    pub fn last_seen(&self) -> Option<DateTime<Utc>> {
+        self.devices.values().map(|state| state.last_seen).max()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{ProcessedRoom, ProcessedTrack};
+
+    fn frame(device_id: &str, room_name: &str) -> ProcessedData {
+        ProcessedData::new(
+            device_id.to_string(),
+            vec![ProcessedRoom {
+                room_index: 0,
+                room_name: room_name.to_string(),
+                tracks: vec![ProcessedTrack::synthetic("HR", Some(70.0), "bpm", 0, room_name, Utc::now())],
+            }],
+        )
+    }
+
+    #[test]
+    fn test_separate_device_state() {
+        let mut registry = DeviceRegistry::new(300);
+        registry.record(&frame("VR2", "OR-3"));
+        registry.record(&frame("VR1", "OR-1"));
+        registry.record(&frame("VR1", "OR-2"));
+
+        let devices = registry.devices();
+        assert_eq!(devices.len(), 2);
+        assert_eq!((devices[0].device_id.as_str(), devices[0].frames), ("VR1", 2));
+        assert_eq!(devices[0].rooms, vec!["OR-1".to_string(), "OR-2".to_string()]);
+        assert_eq!(registry.device("VR2").unwrap().latest.rooms[0].room_name, "OR-3");
+        assert!(registry.device("VR3").is_none());
+        assert_eq!(registry.snapshot("VR1", Utc::now()).unwrap().all_tracks.len(), 2);
+    }
+}
@@ -0,0 +1,162 @@
+// /src/core/events.rs
+// Module: core.events
+// Purpose: Bounded log of recent alarm events and state of the active alarms
+
+use crate::domain::{AlarmAction, AlarmEvent};
+use std::collections::{BTreeMap, VecDeque};
+
+/// ID SRS: SRS-MOD-EVENTS-001
+/// Title: EventLog
+///
+/// Description: VRConnect shall keep the most recent alarm events (oldest
+/// dropped beyond the capacity) and the latest event of every alarm not yet
+/// cleared, for remote interfaces.
+///
+/// Version: V1.0
+pub struct EventLog {
+    capacity: usize,
+    events: VecDeque<AlarmEvent>,
+    active: BTreeMap<String, AlarmEvent>,
+}
+
+impl EventLog {
+    /// ID SRS: SRS-FN-EVENTS-001
+    /// Title: new
+    ///
+    /// Description: VRConnect shall construct an empty EventLog.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `capacity` - Number of recent events kept
+    ///
+    /// # Returns
+    /// New EventLog instance
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            events: VecDeque::new(),
+            active: BTreeMap::new(),
+        }
+    }
+
+    /// ID SRS: SRS-FN-EVENTS-002
+    /// Title: record
+    ///
+    /// Description: VRConnect shall append an alarm event to the log and
+    /// update the state of its alarm (a clear ends the alarm; any other
+    /// action becomes its latest state).
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `event` - Alarm event
+    pub fn record(&mut self, event: &AlarmEvent) {
+        if self.capacity > 0 {
+            if self.events.len() >= self.capacity {
+                self.events.pop_front();
+            }
+            self.events.push_back(event.clone());
+        }
+
+        match event.action {
+            AlarmAction::Cleared => {
+                self.active.remove(&event.alarm_id);
+            }
+            _ => {
+                self.active.insert(event.alarm_id.clone(), event.clone());
+            }
+        }
+    }
+
+    /// ID SRS: SRS-FN-EVENTS-003
+    /// Title: recent
+    ///
+    /// Description: VRConnect shall list the most recent events, newest
+    /// first, optionally of a single device.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `limit` - Maximum number of events
+    /// * `device_id` - Device filter, or None for all devices
+    ///
+    /// # Returns
+    /// Events, newest first
+    pub fn recent(&self, limit: usize, device_id: Option<&str>) -> Vec<AlarmEvent> {
+        self.events
+            .iter()
+            .rev()
+            .filter(|event| device_id.is_none_or(|device_id| event.device_id == device_id))
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+
+    /// ID SRS: SRS-FN-EVENTS-004
+    /// Title: active
+    ///
+    /// Description: VRConnect shall list the alarms not yet cleared with
+    /// their latest event (raise, escalation, acknowledgment or silencing),
+    /// highest priority first, optionally of a single device.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `device_id` - Device filter, or None for all devices
+    ///
+    /// # Returns
+    /// Latest event of every active alarm
+    pub fn active(&self, device_id: Option<&str>) -> Vec<AlarmEvent> {
+        let mut active: Vec<AlarmEvent> = self
+            .active
+            .values()
+            .filter(|event| device_id.is_none_or(|device_id| event.device_id == device_id))
+            .cloned()
+            .collect();
+
+        active.sort_by(|a, b| b.priority.cmp(&a.priority).then_with(|| a.alarm_id.cmp(&b.alarm_id)));
+        active
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{AlarmCondition, AlarmPriority};
+    use chrono::Utc;
+
+    fn event(parameter: &str, action: AlarmAction, priority: AlarmPriority) -> AlarmEvent {
+        AlarmEvent {
+            alarm_id: AlarmEvent::alarm_id_for("VR-1", "OR-1", parameter),
+            device_id: "VR-1".to_string(),
+            room_name: "OR-1".to_string(),
+            parameter: parameter.to_string(),
+            condition: AlarmCondition::High,
+            action,
+            priority,
+            value: None,
+            limit: None,
+            silenced: false,
+            message: String::new(),
+            timestamp: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_recent_and_active_alarms() {
+        let mut log = EventLog::new(2);
+        log.record(&event("HR", AlarmAction::Raised, AlarmPriority::Medium));
+        log.record(&event("SPO2", AlarmAction::Raised, AlarmPriority::High));
+        log.record(&event("HR", AlarmAction::Cleared, AlarmPriority::Medium));
+
+        let recent = log.recent(10, None);
+        assert_eq!(recent.len(), 2);
+        assert_eq!(recent[0].action, AlarmAction::Cleared);
+        assert!(log.recent(10, Some("VR-2")).is_empty());
+
+        let active = log.active(None);
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].parameter, "SPO2");
+    }
+}
@@ -0,0 +1,642 @@
+// /src/core/history.rs
+// Module: core.history
+// Purpose: Bounded in-memory time-series history per track with a query API
+
+use crate::domain::{ProcessedData, TrackType};
+use crate::signal::decimation::{lttb_indices, min_max_indices};
+use crate::signal::Decimation;
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+
+/// ID SRS: SRS-MOD-HISTORY-001
+/// Title: HistoryKey
+///
+/// Description: VRConnect shall identify a recorded track by device, room and
+/// track name.
+///
+/// Version: V1.0
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+pub struct HistoryKey {
+    pub device_id: String,
+    pub room_name: String,
+    pub track_name: String,
+}
+
+/// ID SRS: SRS-MOD-HISTORY-002
+/// Title: HistoryValue
+///
+/// Description: VRConnect shall define one recorded numeric value.
+///
+/// Version: V1.0
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HistoryValue {
+    #[serde(with = "chrono::serde::ts_milliseconds")]
+    pub timestamp: DateTime<Utc>,
+    pub value: f64,
+}
+
+/// ID SRS: SRS-MOD-HISTORY-003
+/// Title: HistorySegment
+///
+/// Description: VRConnect shall define one recorded waveform segment.
+///
+/// Version: V1.0
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HistorySegment {
+    #[serde(with = "chrono::serde::ts_milliseconds")]
+    pub timestamp: DateTime<Utc>,
+    pub sample_rate: Option<f64>,
+    pub points: Vec<f64>,
+}
+
+/// ID SRS: SRS-MOD-HISTORY-004
+/// Title: HistoryTrackSummary
+///
+/// Description: VRConnect shall describe a recorded track: unit, type, amount
+/// of retained data and covered time range.
+///
+/// Version: V1.0
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HistoryTrackSummary {
+    #[serde(flatten)]
+    pub key: HistoryKey,
+    pub unit: String,
+    pub track_type: TrackType,
+    pub values: usize,
+    pub waveform_points: usize,
+    #[serde(with = "chrono::serde::ts_milliseconds_option")]
+    pub first: Option<DateTime<Utc>>,
+    #[serde(with = "chrono::serde::ts_milliseconds_option")]
+    pub last: Option<DateTime<Utc>>,
+}
+
+/// ID SRS: SRS-MOD-HISTORY-005
+/// Title: HistoryLimits
+///
+/// Description: VRConnect shall bound the memory used by the history: a
+/// retention period, a maximum number of values and of waveform points per
+/// track, and a maximum number of tracks.
+///
+/// Version: V1.0
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HistoryLimits {
+    pub retention_secs: u64,
+    pub max_values_per_track: usize,
+    pub max_waveform_points_per_track: usize,
+    pub max_tracks: usize,
+}
+
+/// ID SRS: SRS-MOD-HISTORY-006
+/// Title: HistoryQuery
+///
+/// Description: VRConnect shall define a history query: optional inclusive
+/// time range and optional decimation of the result.
+///
+/// Version: V1.0
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct HistoryQuery {
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub decimation: Option<Decimation>,
+}
+
+impl HistoryQuery {
+    /// ID SRS: SRS-FN-HISTORY-001
+    /// Title: contains
+    ///
+    /// Description: VRConnect shall tell whether a timestamp lies within the
+    /// query time range.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `timestamp` - Timestamp to test
+    ///
+    /// # Returns
+    /// true if the timestamp is within the range
+    pub fn contains(&self, timestamp: DateTime<Utc>) -> bool {
+        self.from.is_none_or(|from| timestamp >= from) && self.to.is_none_or(|to| timestamp <= to)
+    }
+}
+
+/// Recorded data of one track
+struct TrackHistory {
+    unit: String,
+    track_type: TrackType,
+    values: VecDeque<HistoryValue>,
+    segments: VecDeque<HistorySegment>,
+    waveform_points: usize,
+    last_received: DateTime<Utc>,
+}
+
+/// ID SRS: SRS-MOD-HISTORY-007
+/// Title: History
+///
+/// Description: VRConnect shall keep a bounded ring buffer of numeric values
+/// and waveform segments per device, room and track, and answer time range
+/// queries with optional decimation for outputs, debugging tools and remote
+/// interfaces.
+///
+/// Version: V1.0
+pub struct History {
+    limits: HistoryLimits,
+    tracks: HashMap<HistoryKey, TrackHistory>,
+}
+
+impl History {
+    /// ID SRS: SRS-FN-HISTORY-002
+    /// Title: new
+    ///
+    /// Description: VRConnect shall construct an empty History with memory
+    /// bounds.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `limits` - Memory bounds
+    ///
+    /// # Returns
+    /// New History instance
+    pub fn new(limits: HistoryLimits) -> Self {
+        Self {
+            limits,
+            tracks: HashMap::new(),
+        }
+    }
+
+    /// ID SRS: SRS-FN-HISTORY-003
+    /// Title: record
+    ///
+    /// Description: VRConnect shall record the numeric values and waveform
+    /// segments of the processed data in timestamp order (replacing an entry
+    /// with the same timestamp), then enforce the retention period and the
+    /// per-track and track count bounds. When the track count is exceeded,
+    /// the least recently received track is dropped.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `data` - Processed vital data
+    pub fn record(&mut self, data: &ProcessedData) {
+        for track in &data.all_tracks {
+            let waveform = match (&track.track_type, &track.waveform_points, track.raw_value) {
+                (TrackType::Waveform, Some(points), _) if !points.is_empty() => Some(points),
+                (TrackType::Waveform, _, _) | (_, _, None) => continue,
+                _ => None,
+            };
+
+            let key = HistoryKey {
+                device_id: data.device_id.clone(),
+                room_name: track.room_name.clone(),
+                track_name: track.name.clone(),
+            };
+            let history = self.tracks.entry(key).or_insert_with(|| TrackHistory {
+                unit: track.unit.clone(),
+                track_type: track.track_type.clone(),
+                values: VecDeque::new(),
+                segments: VecDeque::new(),
+                waveform_points: 0,
+                last_received: data.timestamp,
+            });
+            history.last_received = data.timestamp;
+
+            match waveform {
+                Some(points) => {
+                    let segment = HistorySegment {
+                        timestamp: track.timestamp,
+                        sample_rate: track.sample_rate,
+                        points: points.clone(),
+                    };
+                    history.waveform_points += segment.points.len();
+                    if let Some(replaced) = Self::insert(&mut history.segments, segment, |s| s.timestamp) {
+                        history.waveform_points -= replaced.points.len();
+                    }
+                }
+                None => {
+                    let value = HistoryValue {
+                        timestamp: track.timestamp,
+                        value: track.raw_value.unwrap_or_default(),
+                    };
+                    Self::insert(&mut history.values, value, |v| v.timestamp);
+                }
+            }
+
+            Self::enforce_track_limits(history, &self.limits);
+        }
+
+        self.enforce_retention(data.timestamp);
+    }
+
+    /// ID SRS: SRS-FN-HISTORY-004
+    /// Title: tracks
+    ///
+    /// Description: VRConnect shall list the recorded tracks, optionally of a
+    /// single device, sorted by device, room and track name.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `device_id` - Device filter, or None for all devices
+    ///
+    /// # Returns
+    /// Track summaries
+    pub fn tracks(&self, device_id: Option<&str>) -> Vec<HistoryTrackSummary> {
+        let mut summaries: Vec<HistoryTrackSummary> = self
+            .tracks
+            .iter()
+            .filter(|(key, _)| device_id.is_none_or(|device_id| key.device_id == device_id))
+            .map(|(key, history)| {
+                let timestamps = history
+                    .values
+                    .iter()
+                    .map(|v| v.timestamp)
+                    .chain(history.segments.iter().map(|s| s.timestamp));
+                HistoryTrackSummary {
+                    key: key.clone(),
+                    unit: history.unit.clone(),
+                    track_type: history.track_type.clone(),
+                    values: history.values.len(),
+                    waveform_points: history.waveform_points,
+                    first: timestamps.clone().min(),
+                    last: timestamps.max(),
+                }
+            })
+            .collect();
+
+        summaries.sort_by(|a, b| a.key.cmp(&b.key));
+        summaries
+    }
+
+    /// ID SRS: SRS-FN-HISTORY-005
+    /// Title: query_values
+    ///
+    /// Description: VRConnect shall return the recorded numeric values of a
+    /// track within the query range. LTTB and min/max decimation select
+    /// values by position; resampling averages the values per interval of
+    /// the target rate.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `key` - Track identifier
+    /// * `query` - Time range and decimation
+    ///
+    /// # Returns
+    /// Time-ordered values, or None if the track is unknown
+    pub fn query_values(&self, key: &HistoryKey, query: &HistoryQuery) -> Option<Vec<HistoryValue>> {
+        let history = self.tracks.get(key)?;
+        let values: Vec<HistoryValue> = history
+            .values
+            .iter()
+            .filter(|v| query.contains(v.timestamp))
+            .cloned()
+            .collect();
+
+        let raw: Vec<f64> = values.iter().map(|v| v.value).collect();
+        let decimated = match query.decimation {
+            None => values,
+            Some(Decimation::Lttb(threshold)) => lttb_indices(&raw, threshold)
+                .into_iter()
+                .map(|i| values[i].clone())
+                .collect(),
+            Some(Decimation::MinMax(max_points)) => min_max_indices(&raw, max_points)
+                .into_iter()
+                .map(|i| values[i].clone())
+                .collect(),
+            Some(Decimation::Resample(rate)) => Self::bucket_average(&values, rate),
+        };
+
+        Some(decimated)
+    }
+
+    /// ID SRS: SRS-FN-HISTORY-006
+    /// Title: query_waveform
+    ///
+    /// Description: VRConnect shall return the recorded waveform segments of a
+    /// track starting within the query range, each decimated if requested.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `key` - Track identifier
+    /// * `query` - Time range and decimation
+    ///
+    /// # Returns
+    /// Time-ordered segments, or None if the track is unknown
+    pub fn query_waveform(&self, key: &HistoryKey, query: &HistoryQuery) -> Option<Vec<HistorySegment>> {
+        let history = self.tracks.get(key)?;
+
+        Some(
+            history
+                .segments
+                .iter()
+                .filter(|s| query.contains(s.timestamp))
+                .map(|segment| match query.decimation {
+                    Some(decimation) => {
+                        let (points, sample_rate) = decimation.apply_points(&segment.points, segment.sample_rate);
+                        HistorySegment {
+                            timestamp: segment.timestamp,
+                            sample_rate,
+                            points,
+                        }
+                    }
+                    None => segment.clone(),
+                })
+                .collect(),
+        )
+    }
+
+    /// ID SRS: SRS-FN-HISTORY-007
+    /// Title: insert
+    ///
+    /// Description: VRConnect shall insert an entry in timestamp order,
+    /// replacing an entry with the same timestamp.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `entries` - Time-ordered entries
+    /// * `entry` - Entry to insert
+    /// * `timestamp` - Timestamp accessor
+    ///
+    /// # Returns
+    /// Replaced entry, if any
+    fn insert<T>(entries: &mut VecDeque<T>, entry: T, timestamp: impl Fn(&T) -> DateTime<Utc>) -> Option<T> {
+        let at = timestamp(&entry);
+        match entries.back().map(&timestamp) {
+            Some(last) if last < at => {
+                entries.push_back(entry);
+                None
+            }
+            None => {
+                entries.push_back(entry);
+                None
+            }
+            _ => match entries.binary_search_by(|e| timestamp(e).cmp(&at)) {
+                Ok(index) => Some(std::mem::replace(&mut entries[index], entry)),
+                Err(index) => {
+                    entries.insert(index, entry);
+                    None
+                }
+            },
+        }
+    }
+
+    /// ID SRS: SRS-FN-HISTORY-008
+    /// Title: enforce_track_limits
+    ///
+    /// Description: VRConnect shall drop the oldest values and segments of a
+    /// track beyond the per-track bounds (the latest segment is always kept).
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `history` - Recorded data of the track
+    /// * `limits` - Memory bounds
+    fn enforce_track_limits(history: &mut TrackHistory, limits: &HistoryLimits) {
+        while history.values.len() > limits.max_values_per_track {
+            history.values.pop_front();
+        }
+
+        while history.waveform_points > limits.max_waveform_points_per_track && history.segments.len() > 1 {
+            if let Some(segment) = history.segments.pop_front() {
+                history.waveform_points -= segment.points.len();
+            }
+        }
+    }
+
+    /// ID SRS: SRS-FN-HISTORY-009
+    /// Title: enforce_retention
+    ///
+    /// Description: VRConnect shall drop entries older than the retention
+    /// period (relative to the newest entry of their track), tracks not
+    /// received within the retention period, and the least recently received
+    /// tracks beyond the track count bound.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `now` - Current receive time
+    fn enforce_retention(&mut self, now: DateTime<Utc>) {
+        let retention = Duration::seconds(self.limits.retention_secs as i64);
+
+        self.tracks.retain(|_, history| {
+            if let Some(newest) = history.values.back().map(|v| v.timestamp) {
+                while history.values.front().is_some_and(|v| newest - v.timestamp > retention) {
+                    history.values.pop_front();
+                }
+            }
+            if let Some(newest) = history.segments.back().map(|s| s.timestamp) {
+                while history.segments.front().is_some_and(|s| newest - s.timestamp > retention) {
+                    if let Some(segment) = history.segments.pop_front() {
+                        history.waveform_points -= segment.points.len();
+                    }
+                }
+            }
+            now - history.last_received <= retention
+        });
+
+        if self.tracks.len() > self.limits.max_tracks {
+            let mut by_age: Vec<(DateTime<Utc>, HistoryKey)> = self
+                .tracks
+                .iter()
+                .map(|(key, history)| (history.last_received, key.clone()))
+                .collect();
+            by_age.sort();
+
+            let excess = self.tracks.len() - self.limits.max_tracks;
+            for (_, key) in by_age.into_iter().take(excess) {
+                log::debug!(
+                    "History full, dropping {}/{}/{}",
+                    key.device_id,
+                    key.room_name,
+                    key.track_name
+                );
+                self.tracks.remove(&key);
+            }
+        }
+    }
+
+    /// ID SRS: SRS-FN-HISTORY-010
+    /// Title: bucket_average
+    ///
+    /// Description: VRConnect shall average irregularly timed values per
+    /// interval of 1/rate seconds, each average stamped at the start of its
+    /// interval.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `values` - Time-ordered values
+    /// * `rate` - Target rate in Hz
+    ///
+    /// # Returns
+    /// One averaged value per non-empty interval
+    fn bucket_average(values: &[HistoryValue], rate: f64) -> Vec<HistoryValue> {
+        let Some(origin) = values.first().map(|v| v.timestamp) else {
+            return Vec::new();
+        };
+        let interval_ms = (1000.0 / rate).max(1.0);
+
+        let mut averaged: Vec<(i64, f64, usize)> = Vec::new();
+        for value in values {
+            let bucket = ((value.timestamp - origin).num_milliseconds() as f64 / interval_ms).floor() as i64;
+            match averaged.last_mut() {
+                Some((last, sum, count)) if *last == bucket => {
+                    *sum += value.value;
+                    *count += 1;
+                }
+                _ => averaged.push((bucket, value.value, 1)),
+            }
+        }
+
+        averaged
+            .into_iter()
+            .map(|(bucket, sum, count)| HistoryValue {
+                timestamp: origin + Duration::milliseconds((bucket as f64 * interval_ms) as i64),
+                value: sum / count as f64,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{ProcessedRoom, ProcessedTrack};
+    use chrono::TimeZone;
+
+    fn limits() -> HistoryLimits {
+        HistoryLimits {
+            retention_secs: 60,
+            max_values_per_track: 100,
+            max_waveform_points_per_track: 1000,
+            max_tracks: 10,
+        }
+    }
+
+    fn at(secs: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(1_700_000_000 + secs, 0).unwrap()
+    }
+
+    fn frame(device_id: &str, tracks: Vec<ProcessedTrack>, secs: i64) -> ProcessedData {
+        let mut data = ProcessedData::new(
+            device_id.to_string(),
+            vec![ProcessedRoom {
+                room_index: 0,
+                room_name: "OR-1".to_string(),
+                tracks,
+            }],
+        );
+        data.timestamp = at(secs);
+        data
+    }
+
+    fn waveform(points: usize, secs: i64) -> ProcessedTrack {
+        let mut track = ProcessedTrack::synthetic("ECG_II", None, "mV", 0, "OR-1", at(secs));
+        track.track_type = TrackType::Waveform;
+        track.waveform_points = Some((0..points).map(|i| (i % 10) as f64).collect());
+        track.sample_rate = Some(100.0);
+        track
+    }
+
+    fn key(track_name: &str) -> HistoryKey {
+        HistoryKey {
+            device_id: "VR1".to_string(),
+            room_name: "OR-1".to_string(),
+            track_name: track_name.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_record_and_query_range() {
+        let mut history = History::new(limits());
+        for secs in [0, 2, 1, 3, 3] {
+            let track = ProcessedTrack::synthetic("HR", Some(70.0 + secs as f64), "bpm", 0, "OR-1", at(secs));
+            history.record(&frame("VR1", vec![track], secs));
+        }
+
+        let all = history.query_values(&key("HR"), &HistoryQuery::default()).unwrap();
+        let timestamps: Vec<DateTime<Utc>> = all.iter().map(|v| v.timestamp).collect();
+        assert_eq!(timestamps, vec![at(0), at(1), at(2), at(3)]);
+
+        let query = HistoryQuery {
+            from: Some(at(1)),
+            to: Some(at(2)),
+            decimation: None,
+        };
+        let values: Vec<f64> = history.query_values(&key("HR"), &query).unwrap().iter().map(|v| v.value).collect();
+        assert_eq!(values, vec![71.0, 72.0]);
+        assert!(history.query_values(&key("SPO2"), &query).is_none());
+    }
+
+    #[test]
+    fn test_bounds_and_retention() {
+        let mut history = History::new(HistoryLimits {
+            max_values_per_track: 5,
+            max_tracks: 2,
+            ..limits()
+        });
+
+        for secs in 0..10 {
+            let track = ProcessedTrack::synthetic("HR", Some(secs as f64), "bpm", 0, "OR-1", at(secs));
+            history.record(&frame("VR1", vec![track], secs));
+        }
+        assert_eq!(history.tracks(None)[0].values, 5);
+
+        // A third track evicts the least recently received one
+        history.record(&frame("VR2", vec![ProcessedTrack::synthetic("HR", Some(1.0), "bpm", 0, "OR-1", at(10))], 10));
+        history.record(&frame("VR3", vec![ProcessedTrack::synthetic("HR", Some(1.0), "bpm", 0, "OR-1", at(11))], 11));
+        let devices: Vec<String> = history.tracks(None).into_iter().map(|t| t.key.device_id).collect();
+        assert_eq!(devices, vec!["VR2".to_string(), "VR3".to_string()]);
+
+        // Tracks silent for longer than the retention period are dropped
+        history.record(&frame("VR3", vec![ProcessedTrack::synthetic("HR", Some(1.0), "bpm", 0, "OR-1", at(75))], 75));
+        assert_eq!(history.tracks(Some("VR2")).len(), 0);
+        assert_eq!(history.tracks(Some("VR3"))[0].values, 1);
+    }
+
+    #[test]
+    fn test_waveform_bounds_and_decimation() {
+        let mut history = History::new(limits());
+        for secs in 0..5 {
+            history.record(&frame("VR1", vec![waveform(300, secs)], secs));
+        }
+
+        let summary = &history.tracks(Some("VR1"))[0];
+        assert_eq!((summary.waveform_points, summary.first), (900, Some(at(2))));
+
+        let query = HistoryQuery {
+            decimation: Some(Decimation::Lttb(30)),
+            ..HistoryQuery::default()
+        };
+        let segments = history.query_waveform(&key("ECG_II"), &query).unwrap();
+        assert_eq!(segments.len(), 3);
+        assert!(segments.iter().all(|s| s.points.len() == 30 && s.sample_rate == Some(10.0)));
+    }
+
+    #[test]
+    fn test_numeric_decimation() {
+        let mut history = History::new(limits());
+        for secs in 0..50 {
+            let value = if secs == 17 { 200.0 } else { 70.0 };
+            let track = ProcessedTrack::synthetic("HR", Some(value), "bpm", 0, "OR-1", at(secs));
+            history.record(&frame("VR1", vec![track], secs));
+        }
+
+        let query = |decimation| HistoryQuery {
+            decimation: Some(decimation),
+            ..HistoryQuery::default()
+        };
+
+        let lttb = history.query_values(&key("HR"), &query(Decimation::Lttb(10))).unwrap();
+        assert_eq!(lttb.len(), 10);
+        assert!(lttb.iter().any(|v| v.value == 200.0 && v.timestamp == at(17)));
+
+        let averaged = history.query_values(&key("HR"), &query(Decimation::Resample(0.1))).unwrap();
+        assert_eq!(averaged.len(), 5);
+        assert_eq!(averaged[1].timestamp, at(10));
+        assert!((averaged[1].value - 83.0).abs() < 1e-9);
+    }
+}
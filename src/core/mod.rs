@@ -2,6 +2,27 @@
 // Module: core
 // Purpose: Core processing logic and orchestration
 
+pub mod builder;
+pub mod clock;
+pub mod device;
+pub mod events;
+pub mod history;
 pub mod processor;
+pub mod sequencer;
+pub mod shutdown;
+pub mod supervisor;
+pub mod snapshot;
+pub mod trend;
+pub mod watchdog;
 
+pub use builder::VitalProcessorBuilder;
+pub use clock::ClockSync;
+pub use device::DeviceRegistry;
+pub use events::EventLog;
+pub use history::{History, HistoryLimits};
 pub use processor::VitalProcessor;
+pub use sequencer::Sequencer;
+pub use snapshot::Snapshot;
+pub use supervisor::{Backoff, Supervisor};
+pub use trend::TrendTracker;
+pub use watchdog::Watchdog;
@@ -2,27 +2,52 @@
 // Module: core.processor
 // Purpose: Main processor orchestrating data flow from input to outputs
 
+use crate::alarm::{AlarmEngine, AlarmHandle, AlarmLimitSet};
 use crate::config::Config;
-use crate::domain::ProcessedData;
-use crate::error::Result;
-use crate::input::SocketIOServer;
-use crate::output::{BleOutput, ConsoleOutput};
+use crate::core::shutdown::wait_for_signal;
+use crate::core::{
+    Backoff, DeviceRegistry, EventLog, History, HistoryLimits, Supervisor, VitalProcessorBuilder, Watchdog,
+};
+use crate::domain::{AlarmEvent, ProcessedData};
+use crate::error::{Result, VitalError};
+use crate::http::{ApiEndpoint, HealthEndpoint, HttpServer, MetricsEndpoint};
+use crate::input::{InputSource, SocketIOServer, SocketIOStatus};
+use crate::output::{build_sinks, OutputMode, OutputSink, OverflowPolicy, QueuedSink};
+use crate::pipeline::{build_pipeline, PipelineStage, RawPipeline, RawStage, StagePosition, UnitConversion};
 use std::fs::OpenOptions;
 use std::io::Write;
 use std::sync::Arc;
-use tokio::sync::{mpsc, RwLock};
+use tokio::sync::{mpsc, Mutex, RwLock};
+use tokio_util::sync::CancellationToken;
 
 /// ID SRS: SRS-MOD-PROCESSOR-001
 /// Title: VitalProcessor
 ///
 /// Description: VRConnect shall orchestrate the complete data processing pipeline
-/// from Socket.IO input through transformation to multiple outputs with optional
-/// debug logging.
+/// from Socket.IO input through transformation and alarm evaluation to multiple
+/// outputs with optional debug logging.
 ///
 /// Version: V1.0
 pub struct VitalProcessor {
     config: Config,
     debug_file: Arc<RwLock<Option<std::fs::File>>>,
+    alarm_engine: Option<Arc<Mutex<AlarmEngine>>>,
+    event_tx: mpsc::UnboundedSender<AlarmEvent>,
+    event_rx: Mutex<Option<mpsc::UnboundedReceiver<AlarmEvent>>>,
+    history: Option<Arc<RwLock<History>>>,
+    devices: Arc<RwLock<DeviceRegistry>>,
+    watchdog: Option<Arc<RwLock<Watchdog>>>,
+    events: Arc<RwLock<EventLog>>,
+    shutdown: CancellationToken,
+    supervisor: Supervisor,
+    stages: Mutex<Vec<(StagePosition, Box<dyn PipelineStage>)>>,
+    raw_stages: Vec<(StagePosition, Arc<dyn RawStage>)>,
+    extra_sinks: Vec<Arc<dyn OutputSink>>,
+    extra_inputs: Vec<Arc<dyn InputSource>>,
+    handle_signals: bool,
+    socketio_enabled: bool,
+    socketio_status: Arc<SocketIOStatus>,
+    limits_error: Option<String>,
 }
 
 impl VitalProcessor {
@@ -30,7 +55,8 @@ impl VitalProcessor {
     /// Title: new
     ///
     /// Description: VRConnect shall construct a VitalProcessor instance with
-    /// configuration and initialize debug file if debug mode enabled.
+    /// configuration, initialize debug file if debug mode enabled, and load
+    /// alarm limits if alarms are enabled.
     ///
     /// Version: V1.0
     ///
@@ -40,6 +66,38 @@ impl VitalProcessor {
     /// # Returns
     /// New VitalProcessor instance
     pub fn new(config: Config) -> Self {
+        VitalProcessorBuilder::new(config).build()
+    }
+
+    /// ID SRS: SRS-FN-PROCESSOR-011
+    /// Title: builder
+    ///
+    /// Description: VRConnect shall provide a builder to extend the processor
+    /// with user-defined stages, outputs and inputs.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `config` - Application configuration
+    ///
+    /// # Returns
+    /// New VitalProcessorBuilder instance
+    pub fn builder(config: Config) -> VitalProcessorBuilder {
+        VitalProcessorBuilder::new(config)
+    }
+
+    /// Construct the processor from a builder (see `new`)
+    pub(crate) fn from_builder(builder: VitalProcessorBuilder) -> Self {
+        let VitalProcessorBuilder {
+            config,
+            stages,
+            raw_stages,
+            sinks: extra_sinks,
+            inputs: extra_inputs,
+            handle_signals,
+            socketio_enabled,
+        } = builder;
+
         let debug_file = if config.debug_enabled {
             // Create debug file
             if let Ok(file) = OpenOptions::new()
@@ -56,17 +114,181 @@ impl VitalProcessor {
             Arc::new(RwLock::new(None))
         };
 
+        // An invalid limits file stops the processor at startup (see `run`)
+        let limits = match (&config.alarm_limits_path, config.alarm_enabled) {
+            (Some(path), true) => AlarmLimitSet::load_from_file(path)
+                .map_err(|e| format!("Invalid alarm limits file {}: {}", path, e)),
+            _ => Ok(AlarmLimitSet::builtin()),
+        };
+        let (limits, limits_error) = match limits {
+            Ok(limits) => (Some(limits), None),
+            Err(e) => (None, Some(e)),
+        };
+
+        let alarm_engine = if let (true, Some(limits)) = (config.alarm_enabled, limits) {
+            let min_quality = if config.signal_quality_enabled {
+                config.signal_quality_min_score
+            } else {
+                0.0
+            };
+            let unit_conversions = UnitConversion::parse_list(&config.unit_conversions).unwrap_or_default();
+            Some(Arc::new(Mutex::new(
+                AlarmEngine::new(limits)
+                    .with_min_quality(min_quality)
+                    .with_unit_conversions(unit_conversions),
+            )))
+        } else {
+            None
+        };
+
+        let (event_tx, event_rx) = mpsc::unbounded_channel::<AlarmEvent>();
+
+        let history = config.history_enabled.then(|| {
+            Arc::new(RwLock::new(History::new(HistoryLimits {
+                retention_secs: config.history_retention_secs,
+                max_values_per_track: config.history_max_values_per_track,
+                max_waveform_points_per_track: config.history_max_waveform_points_per_track,
+                max_tracks: config.history_max_tracks,
+            })))
+        });
+
+        let devices = Arc::new(RwLock::new(DeviceRegistry::new(config.snapshot_max_age_secs)));
+        let watchdog = config.watchdog_enabled.then(|| {
+            Arc::new(RwLock::new(Watchdog::new(
+                config.watchdog_device_timeout_secs,
+                config.watchdog_track_timeout_secs,
+            )))
+        });
+        let events = Arc::new(RwLock::new(EventLog::new(config.api_max_events)));
+
+        let shutdown = CancellationToken::new();
+        let supervisor = Supervisor::new(
+            Backoff {
+                initial: std::time::Duration::from_millis(config.supervisor_backoff_initial_ms),
+                max: std::time::Duration::from_millis(config.supervisor_backoff_max_ms),
+            },
+            config.supervisor_max_restarts,
+            Supervisor::parse_critical(&config.supervisor_critical),
+            shutdown.clone(),
+        );
+
         Self {
             config,
             debug_file,
+            alarm_engine,
+            event_tx,
+            event_rx: Mutex::new(Some(event_rx)),
+            history,
+            devices,
+            watchdog,
+            events,
+            shutdown,
+            supervisor,
+            stages: Mutex::new(stages),
+            raw_stages,
+            extra_sinks,
+            extra_inputs,
+            handle_signals,
+            socketio_enabled,
+            socketio_status: Arc::new(SocketIOStatus::default()),
+            limits_error,
         }
     }
 
+    /// ID SRS: SRS-FN-PROCESSOR-004
+    /// Title: alarm_handle
+    ///
+    /// Description: VRConnect shall provide a handle to acknowledge and silence
+    /// alarms while the processor is running.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// AlarmHandle, or None if alarms are disabled
+    pub fn alarm_handle(&self) -> Option<AlarmHandle> {
+        self.alarm_engine
+            .as_ref()
+            .map(|engine| AlarmHandle::new(engine.clone(), self.event_tx.clone()))
+    }
+
+    /// ID SRS: SRS-FN-PROCESSOR-006
+    /// Title: history
+    ///
+    /// Description: VRConnect shall provide shared read access to the
+    /// time-series history while the processor is running.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Shared history, or None if the history is disabled
+    pub fn history(&self) -> Option<Arc<RwLock<History>>> {
+        self.history.clone()
+    }
+
+    /// ID SRS: SRS-FN-PROCESSOR-007
+    /// Title: devices
+    ///
+    /// Description: VRConnect shall provide shared read access to the state
+    /// of every device seen by the processor.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Shared device registry
+    pub fn devices(&self) -> Arc<RwLock<DeviceRegistry>> {
+        self.devices.clone()
+    }
+
+    /// ID SRS: SRS-FN-PROCESSOR-013
+    /// Title: events
+    ///
+    /// Description: VRConnect shall provide shared read access to the recent
+    /// alarm events and the active alarms.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Shared event log
+    pub fn events(&self) -> Arc<RwLock<EventLog>> {
+        self.events.clone()
+    }
+
+    /// ID SRS: SRS-FN-PROCESSOR-008
+    /// Title: shutdown_token
+    ///
+    /// Description: VRConnect shall provide a token to request the
+    /// coordinated shutdown of a running processor (as a termination signal).
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Shutdown token shared by all processor tasks
+    pub fn shutdown_token(&self) -> CancellationToken {
+        self.shutdown.clone()
+    }
+
+    /// ID SRS: SRS-FN-PROCESSOR-010
+    /// Title: supervisor
+    ///
+    /// Description: VRConnect shall provide the supervisor of the input and
+    /// output components, for state and restart reports.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Component supervisor
+    pub fn supervisor(&self) -> Supervisor {
+        self.supervisor.clone()
+    }
+
     /// ID SRS: SRS-FN-PROCESSOR-002
     /// Title: run
     ///
     /// Description: VRConnect shall execute the main processing loop, starting
-    /// input server, creating outputs, and processing data until shutdown signal.
+    /// input server, creating outputs, applying the pipeline stages, evaluating
+    /// alarms, watching for stale data, and processing data and alarm events
+    /// until a termination signal or shutdown request, then drain in-flight
+    /// frames through the outputs within the shutdown timeout.
     ///
     /// Version: V1.0
     ///
@@ -75,56 +297,92 @@ impl VitalProcessor {
     pub async fn run(&self) -> Result<()> {
         log::info!("Starting VitalProcessor...");
 
-        // Create data channel
-        let (tx, mut rx) = mpsc::unbounded_channel::<ProcessedData>();
+        if let Some(e) = &self.limits_error {
+            return Err(VitalError::Config(e.clone()));
+        }
 
-        // Create console output
-        let console_output = if self.config.output_console_enabled {
-            Some(Arc::new(ConsoleOutput::new(
-                self.config.output_console_verbose,
-                self.config.output_console_colorized,
-            )))
-        } else {
-            None
-        };
+        // Create bounded data channel (the input drops frames rather than wait)
+        let (tx, mut rx) = mpsc::channel::<ProcessedData>(self.config.input_queue_capacity);
 
-        // Create BLE output
-        let ble_output = if self.config.output_ble_enabled {
-            log::warn!("⚠️  BLE Output: Waveform tracks excluded (MTU limit)");
-            Some(Arc::new(
-                BleOutput::new(
-                    self.config.output_ble_device_name.clone(),
-                    self.config.output_ble_service_uuid.clone(),
-                )
-                .await?,
-            ))
-        } else {
-            None
-        };
+        // Built-in stages in the configured order, then user-defined stages
+        let mut pipeline = build_pipeline(&self.config).map_err(VitalError::Config)?;
+        for (position, stage) in self.stages.lock().await.drain(..) {
+            pipeline.insert(position, stage).map_err(VitalError::Config)?;
+        }
+        log::info!("Pipeline stages: {}", pipeline.names().join(" → "));
 
-        // Start BLE server if enabled
-        let ble_task = if let Some(ref ble) = ble_output {
-            let ble_clone = ble.clone();
-            Some(tokio::spawn(async move {
-                if let Err(e) = ble_clone.start().await {
-                    log::error!("BLE server error: {}", e);
+        // Pre-parse stages of the received frames
+        let mut raw_pipeline = RawPipeline::new();
+        for (position, stage) in &self.raw_stages {
+            raw_pipeline
+                .insert(position.clone(), stage.clone())
+                .map_err(VitalError::Config)?;
+        }
+
+        // Create the enabled and user-defined outputs, started and restarted by the supervisor
+        let mut sinks = build_sinks(&self.config).await?;
+        for sink in &self.extra_sinks {
+            let queued = QueuedSink::new(sink.clone(), self.config.output_queue_capacity, OverflowPolicy::DropOldest);
+            sinks.push(Arc::new(queued));
+        }
+        for sink in &sinks {
+            let name = sink.name().to_string();
+            let sink = sink.clone();
+            let shutdown = self.shutdown.clone();
+            self.supervisor.spawn(&name, move || {
+                let sink = sink.clone();
+                let shutdown = shutdown.clone();
+                async move {
+                    sink.start().await?;
+                    log::info!("✓ {} output started ({:?})", sink.name(), sink.health());
+                    tokio::select! {
+                        result = sink.monitor() => result,
+                        _ = shutdown.cancelled() => Ok(()),
+                    }
                 }
-            }))
-        } else {
-            None
-        };
+            });
+        }
 
-        // Start Socket.IO input server
-        let socketio_server = SocketIOServer::new(
-            self.config.socketio_host.clone(),
-            self.config.socketio_port,
-            self.config.debug_enabled,
-            self.debug_file.clone(),
-        );
+        // Serve the enabled HTTP endpoints (restarted by the supervisor, e.g. on bind failure)
+        if let Some(server) = self.http_server(&sinks) {
+            let server = Arc::new(server);
+            let shutdown = self.shutdown.clone();
+            self.supervisor.spawn("http", move || server.clone().run(shutdown.clone()));
+        }
 
-        let input_task = tokio::spawn(async move {
-            if let Err(e) = socketio_server.start(tx).await {
-                log::error!("Socket.IO server error: {}", e);
+        // Start Socket.IO input server and user-defined inputs (restarted by
+        // the supervisor, e.g. on bind failure)
+        let socketio_server = self.socketio_enabled.then(|| {
+            Arc::new(
+                SocketIOServer::new(
+                    self.config.socketio_host.clone(),
+                    self.config.socketio_port,
+                    self.config.debug_enabled,
+                    self.debug_file.clone(),
+                )
+                .with_raw_pipeline(raw_pipeline)
+                .with_status(self.socketio_status.clone()),
+            ) as Arc<dyn InputSource>
+        });
+
+        let mut input_handles = Vec::new();
+        for input in socketio_server.into_iter().chain(self.extra_inputs.iter().cloned()) {
+            let name = input.name().to_string();
+            let tx = tx.clone();
+            let shutdown = self.shutdown.clone();
+            input_handles.push(self.supervisor.spawn(&name, move || {
+                let input = input.clone();
+                let tx = tx.clone();
+                let shutdown = shutdown.clone();
+                async move { input.run(tx, shutdown).await }
+            }));
+        }
+        drop(tx);
+
+        let input_aborts: Vec<_> = input_handles.iter().map(|handle| handle.abort_handle()).collect();
+        let mut input_task = tokio::spawn(async move {
+            for handle in input_handles {
+                let _ = handle.await;
             }
         });
 
@@ -133,83 +391,298 @@ impl VitalProcessor {
         // Processing loop
         let debug_file = self.debug_file.clone();
         let debug_enabled = self.config.debug_enabled;
-        let ble_output_clone = ble_output.clone();
-        let console_output_clone = console_output.clone();
-        
-        let processing_task = tokio::spawn(async move {
-            while let Some(data) = rx.recv().await {
-                log::debug!("Processing data for device: {}", data.device_id);
-
-                // Debug log processed data with ALL waveform points
-                if debug_enabled {
-                    Self::write_debug_data(&debug_file, &data).await;
-                }
+        let output_sinks = sinks.clone();
+        let alarm_engine = self.alarm_engine.clone();
+        let history = self.history.clone();
+        let devices = self.devices.clone();
+        let event_log = self.events.clone();
+        let mut event_rx = self
+            .event_rx
+            .lock()
+            .await
+            .take()
+            .ok_or_else(|| VitalError::Processing("VitalProcessor already running".to_string()))?;
 
-                // Output to console
-                if let Some(ref console) = console_output_clone {
-                    console.output(&data).await;
-                }
+        let watchdog = self.watchdog.clone();
+        let mut watchdog_interval = tokio::time::interval(tokio::time::Duration::from_secs(1));
+
+        let mut processing_task = tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    data = rx.recv() => {
+                        let Some(mut data) = data else { break };
+                        log::debug!("Processing data for device: {}", data.device_id);
+                        let mut events = Vec::new();
+
+                        // Pipeline stages (sequencing, clock, filtering, derived metrics, ...)
+                        events.extend(pipeline.process(&mut data));
+
+                        // Record values and waveform segments in the history
+                        if let Some(ref history) = history {
+                            history.write().await.record(&data);
+                        }
+
+                        // Per-device state (activity, rooms, latest data, snapshot)
+                        devices.write().await.record(&data);
+
+                        // Record activity for the watchdog (clears recovered conditions)
+                        if let Some(ref watchdog) = watchdog {
+                            events.extend(watchdog.write().await.record(&data));
+                        }
 
-                // Output to BLE (non-waveform only)
-                if let Some(ref ble) = ble_output_clone {
-                    if let Err(e) = ble.output(&data).await {
-                        log::error!("BLE output error: {}", e);
+                        // Debug log processed data with ALL waveform points
+                        if debug_enabled {
+                            Self::write_debug_data(&debug_file, &data).await;
+                        }
+
+                        // Records may all be held or dropped by sequencing
+                        if !data.all_tracks.is_empty() {
+                            // Full device snapshot for outputs in snapshot mode
+                            let snapshot_needed = output_sinks
+                                .iter()
+                                .any(|sink| sink.mode() == OutputMode::Snapshot);
+                            let mut snapshot = if snapshot_needed {
+                                devices.read().await.snapshot(&data.device_id, chrono::Utc::now())
+                            } else {
+                                None
+                            };
+
+                            // Flag stale and sensor-off tracks before outputs show them
+                            if let Some(ref watchdog) = watchdog {
+                                let watchdog = watchdog.read().await;
+                                watchdog.mark(&mut data);
+                                if let Some(ref mut snapshot) = snapshot {
+                                    watchdog.mark(snapshot);
+                                }
+                            }
+                            let view = |mode: OutputMode| match (mode, &snapshot) {
+                                (OutputMode::Snapshot, Some(snapshot)) => snapshot,
+                                _ => &data,
+                            };
+
+                            for sink in &output_sinks {
+                                if let Err(e) = sink.handle_data(view(sink.mode())).await {
+                                    log::error!("{} output error: {}", sink.name(), e);
+                                }
+                            }
+                        }
+
+                        // Evaluate alarms
+                        if let Some(ref engine) = alarm_engine {
+                            events.extend(engine.lock().await.evaluate(&data));
+                        }
+
+                        for event in &events {
+                            Self::output_alarm(
+                                event,
+                                &output_sinks,
+                                &event_log,
+                                debug_enabled,
+                                &debug_file,
+                            )
+                            .await;
+                        }
+                    }
+                    _ = watchdog_interval.tick(), if watchdog.is_some() => {
+                        if let Some(ref watchdog) = watchdog {
+                            let raised = watchdog.write().await.check(chrono::Utc::now());
+                            for event in &raised {
+                                Self::output_alarm(
+                                    event,
+                                    &output_sinks,
+                                    &event_log,
+                                    debug_enabled,
+                                    &debug_file,
+                                )
+                                .await;
+                            }
+                        }
+                    }
+                    Some(event) = event_rx.recv() => {
+                        Self::output_alarm(
+                            &event,
+                            &output_sinks,
+                            &event_log,
+                            debug_enabled,
+                            &debug_file,
+                        )
+                        .await;
                     }
                 }
             }
         });
 
-        // Wait for shutdown signal or task completion
-        if let Some(ble_task) = ble_task {
-            // With BLE enabled
-            tokio::select! {
-                _ = tokio::signal::ctrl_c() => {
-                    log::info!("Shutdown signal received");
-                }
-                result = input_task => {
-                    match result {
-                        Ok(_) => log::info!("Socket.IO server stopped"),
-                        Err(e) => log::error!("Socket.IO task panicked: {}", e),
-                    }
-                }
-                result = processing_task => {
-                    match result {
-                        Ok(_) => log::info!("Processing task stopped"),
-                        Err(e) => log::error!("Processing task panicked: {}", e),
-                    }
-                }
-                result = ble_task => {
-                    match result {
-                        Ok(_) => log::info!("BLE server stopped"),
-                        Err(e) => log::error!("BLE task panicked: {}", e),
-                    }
+        // Wait for a termination signal, a shutdown request or task completion
+        let mut input_done = false;
+        let mut processing_done = false;
+        let handle_signals = self.handle_signals;
+        let signal = async move {
+            if handle_signals {
+                wait_for_signal().await
+            } else {
+                std::future::pending().await
+            }
+        };
+        tokio::select! {
+            signal = signal => {
+                log::info!("{} received, shutting down", signal);
+            }
+            _ = self.shutdown.cancelled() => {
+                log::info!("Shutdown requested");
+            }
+            result = &mut input_task => {
+                input_done = true;
+                match result {
+                    Ok(_) => log::info!("Inputs stopped"),
+                    Err(e) => log::error!("Input task panicked: {}", e),
                 }
             }
-        } else {
-            // Without BLE
-            tokio::select! {
-                _ = tokio::signal::ctrl_c() => {
-                    log::info!("Shutdown signal received");
+            result = &mut processing_task => {
+                processing_done = true;
+                match result {
+                    Ok(_) => log::info!("Processing task stopped"),
+                    Err(e) => log::error!("Processing task panicked: {}", e),
                 }
-                result = input_task => {
-                    match result {
-                        Ok(_) => log::info!("Socket.IO server stopped"),
-                        Err(e) => log::error!("Socket.IO task panicked: {}", e),
-                    }
+            }
+        }
+        self.shutdown.cancel();
+
+        // Close the input, then drain received frames through processing and outputs
+        let timeout_secs = self.config.shutdown_timeout_secs;
+        let drained = tokio::time::timeout(tokio::time::Duration::from_secs(timeout_secs), async {
+            if !input_done {
+                let _ = (&mut input_task).await;
+            }
+            if !processing_done {
+                let _ = (&mut processing_task).await;
+            }
+            for sink in &sinks {
+                if let Some(stats) = sink.queue_stats().filter(|stats| stats.dropped > 0) {
+                    log::warn!("{} output dropped {} messages", sink.name(), stats.dropped);
                 }
-                result = processing_task => {
-                    match result {
-                        Ok(_) => log::info!("Processing task stopped"),
-                        Err(e) => log::error!("Processing task panicked: {}", e),
-                    }
+                if let Err(e) = sink.shutdown().await {
+                    log::error!("{} output shutdown error: {}", sink.name(), e);
                 }
             }
+        })
+        .await;
+
+        if drained.is_err() {
+            log::warn!("⚠️  Shutdown timeout ({}s) reached, abandoning in-flight data", timeout_secs);
+            input_aborts.iter().for_each(|input| input.abort());
+            input_task.abort();
+            processing_task.abort();
+        }
+
+        Self::flush_debug_file(&self.debug_file).await;
+
+        if let Some(failure) = self.supervisor.failure() {
+            return Err(VitalError::Processing(format!("Critical component failed: {}", failure)));
         }
 
         log::info!("✓ VitalProcessor stopped gracefully");
         Ok(())
     }
 
+    /// ID SRS: SRS-FN-PROCESSOR-012
+    /// Title: http_server
+    ///
+    /// Description: VRConnect shall create the HTTP server of the enabled
+    /// endpoints (metrics, health, REST API).
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `sinks` - Outputs of the processor (for the health report)
+    ///
+    /// # Returns
+    /// HTTP server, or None if no endpoint is enabled
+    fn http_server(&self, sinks: &[Arc<dyn OutputSink>]) -> Option<HttpServer> {
+        if !self.config.metrics_enabled && !self.config.health_enabled && !self.config.api_enabled {
+            return None;
+        }
+
+        let mut server = HttpServer::new(self.config.http_host.clone(), self.config.http_port);
+        if self.config.metrics_enabled {
+            server = server.with_handler(Arc::new(MetricsEndpoint));
+        }
+        if self.config.health_enabled {
+            server = server.with_handler(Arc::new(HealthEndpoint::new(
+                self.supervisor.clone(),
+                sinks.to_vec(),
+                self.devices.clone(),
+                self.socketio_enabled.then(|| self.socketio_status.clone()),
+                self.config.health_stale_secs,
+            )));
+        }
+        if self.config.api_enabled {
+            server = server.with_handler(Arc::new(ApiEndpoint::new(
+                self.devices.clone(),
+                self.history.clone(),
+                self.watchdog.clone(),
+                self.events.clone(),
+            )));
+        }
+        Some(server)
+    }
+
+    /// ID SRS: SRS-FN-PROCESSOR-005
+    /// Title: output_alarm
+    ///
+    /// Description: VRConnect shall dispatch an alarm event to every enabled
+    /// output and to the debug file, and record it in the event log.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `event` - Alarm event
+    /// * `sinks` - Enabled outputs
+    /// * `event_log` - Recent alarm events
+    /// * `debug_enabled` - Debug mode flag
+    /// * `debug_file` - Debug file handle
+    async fn output_alarm(
+        event: &AlarmEvent,
+        sinks: &[Arc<dyn OutputSink>],
+        event_log: &Arc<RwLock<EventLog>>,
+        debug_enabled: bool,
+        debug_file: &Arc<RwLock<Option<std::fs::File>>>,
+    ) {
+        event_log.write().await.record(event);
+
+        if debug_enabled {
+            if let Some(ref mut file) = *debug_file.write().await {
+                let _ = writeln!(
+                    file,
+                    "\n=== ALARM EVENT ===\n{}\n",
+                    serde_json::to_string(event).unwrap_or_default()
+                );
+            }
+        }
+
+        for sink in sinks {
+            if let Err(e) = sink.handle_event(event).await {
+                log::error!("{} alarm output error: {}", sink.name(), e);
+            }
+        }
+    }
+
+    /// ID SRS: SRS-FN-PROCESSOR-009
+    /// Title: flush_debug_file
+    ///
+    /// Description: VRConnect shall flush the debug file to disk on shutdown.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `debug_file` - Debug file handle
+    async fn flush_debug_file(debug_file: &Arc<RwLock<Option<std::fs::File>>>) {
+        if let Some(ref mut file) = *debug_file.write().await {
+            if let Err(e) = file.flush().and_then(|_| file.sync_all()) {
+                log::error!("Failed to flush debug file: {}", e);
+            }
+        }
+    }
+
     /// ID SRS: SRS-FN-PROCESSOR-003
     /// Title: write_debug_data
     ///
@@ -255,6 +728,14 @@ impl VitalProcessor {
                         let _ = writeln!(file, "    Raw Value: {}", raw_val);
                     }
 
+                    // Composite value components
+                    if let Some(components) = &track.components {
+                        let _ = writeln!(file, "    Components:");
+                        for component in components {
+                            let _ = writeln!(file, "      {}: {}", component.name, component.value);
+                        }
+                    }
+
                     // Waveform statistics
                     if let Some(stats) = &track.waveform_stats {
                         let _ = writeln!(file, "    Waveform Stats:");
@@ -0,0 +1,243 @@
+// /src/core/sequencer.rs
+// Module: core.sequencer
+// Purpose: Per-track deduplication, reordering and timestamp plausibility of records
+
+use crate::domain::{ProcessedData, ProcessedRoom, ProcessedTrack, TimestampFlag};
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+
+/// Idle time after which the state of a track without pending records is dropped
+const STALE_TRACK_SECS: i64 = 600;
+
+/// Sequencing state of one track
+#[derive(Default)]
+struct TrackSequence {
+    /// Held records (receive time, record), ordered by record timestamp
+    pending: Vec<(DateTime<Utc>, ProcessedTrack)>,
+    last_released: Option<DateTime<Utc>>,
+    last_received: Option<DateTime<Utc>>,
+}
+
+/// ID SRS: SRS-MOD-SEQUENCER-001
+/// Title: Sequencer
+///
+/// Description: VRConnect shall sequence the records of every track before
+/// processing: records whose (track id, timestamp) was already released, or
+/// older than the last released record, are dropped as duplicates or late
+/// retransmissions; records are held for a jitter window and released in
+/// timestamp order; records stamped far from the receive time are flagged.
+///
+/// Version: V1.0
+pub struct Sequencer {
+    jitter: Duration,
+    max_skew: Duration,
+    tracks: HashMap<(String, String, String), TrackSequence>,
+}
+
+impl Sequencer {
+    /// ID SRS: SRS-FN-SEQUENCER-001
+    /// Title: new
+    ///
+    /// Description: VRConnect shall construct a Sequencer with a jitter window
+    /// and a maximum distance between record and receive time.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `jitter_ms` - Time records are held for reordering (0 = release immediately)
+    /// * `max_skew_secs` - Distance from the receive time above which a record is flagged
+    ///
+    /// # Returns
+    /// New Sequencer instance
+    pub fn new(jitter_ms: u64, max_skew_secs: u64) -> Self {
+        Self {
+            jitter: Duration::milliseconds(jitter_ms as i64),
+            max_skew: Duration::seconds(max_skew_secs as i64),
+            tracks: HashMap::new(),
+        }
+    }
+
+    /// ID SRS: SRS-FN-SEQUENCER-002
+    /// Title: apply
+    ///
+    /// Description: VRConnect shall replace the records of the processed data
+    /// with the records of the same device due for release: received at least
+    /// the jitter window before the current record set (immediately without
+    /// jitter window), in timestamp order per track.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `data` - Processed vital data, modified in place
+    pub fn apply(&mut self, data: &mut ProcessedData) {
+        let received = data.timestamp;
+
+        for room in data.rooms.iter_mut() {
+            for mut track in room.tracks.drain(..) {
+                if track.timestamp_flag.is_none() && (track.timestamp - received).abs() > self.max_skew {
+                    log::debug!(
+                        "Timestamp of {}/{}/{} is {}s away from receive time",
+                        data.device_id,
+                        track.room_name,
+                        track.name,
+                        (track.timestamp - received).num_seconds()
+                    );
+                    track.timestamp_flag = Some(TimestampFlag::Skewed);
+                }
+
+                let key = (
+                    data.device_id.clone(),
+                    track.room_name.clone(),
+                    track.track_id.clone().unwrap_or_else(|| track.name.clone()),
+                );
+                let sequence = self.tracks.entry(key).or_default();
+                sequence.last_received = Some(received);
+
+                let duplicate = sequence.last_released.is_some_and(|last| track.timestamp <= last)
+                    || sequence.pending.iter().any(|(_, held)| held.timestamp == track.timestamp);
+                if duplicate {
+                    log::debug!(
+                        "Dropping duplicate or late record of {}/{}/{} at {}",
+                        data.device_id,
+                        track.room_name,
+                        track.name,
+                        track.timestamp
+                    );
+                    continue;
+                }
+
+                let position = sequence.pending.partition_point(|(_, held)| held.timestamp < track.timestamp);
+                sequence.pending.insert(position, (received, track));
+            }
+        }
+
+        let mut released: Vec<ProcessedTrack> = Vec::new();
+        for ((device_id, _, _), sequence) in self.tracks.iter_mut() {
+            if *device_id != data.device_id {
+                continue;
+            }
+
+            // Release the held records up to the last one due
+            let due = sequence
+                .pending
+                .iter()
+                .rposition(|(held_at, _)| *held_at + self.jitter <= received)
+                .map_or(0, |index| index + 1);
+            for (_, track) in sequence.pending.drain(..due) {
+                sequence.last_released = Some(track.timestamp);
+                released.push(track);
+            }
+        }
+
+        for track in released {
+            match data
+                .rooms
+                .iter_mut()
+                .find(|room| room.room_index == track.room_index && room.room_name == track.room_name)
+            {
+                Some(room) => room.tracks.push(track),
+                None => data.rooms.push(ProcessedRoom {
+                    room_index: track.room_index,
+                    room_name: track.room_name.clone(),
+                    tracks: vec![track],
+                }),
+            }
+        }
+
+        for room in data.rooms.iter_mut() {
+            room.tracks.sort_by_key(|track| (track.track_index, track.timestamp));
+        }
+        data.refresh_all_tracks();
+
+        self.tracks.retain(|_, sequence| {
+            !sequence.pending.is_empty()
+                || sequence
+                    .last_received
+                    .is_some_and(|last| received - last <= Duration::seconds(STALE_TRACK_SECS))
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(millis: i64) -> DateTime<Utc> {
+        Utc.timestamp_millis_opt(1_700_000_000_000 + millis).unwrap()
+    }
+
+    fn frame(records: &[(i64, f64)], received_ms: i64) -> ProcessedData {
+        let tracks = records
+            .iter()
+            .enumerate()
+            .map(|(index, (millis, value))| {
+                let mut track = ProcessedTrack::synthetic("HR", Some(*value), "bpm", 0, "OR-1", at(*millis));
+                track.track_id = Some("7".to_string());
+                track.track_index = 0;
+                track.record_index = index as i32;
+                track
+            })
+            .collect();
+
+        let mut data = ProcessedData::new(
+            "VR1".to_string(),
+            vec![ProcessedRoom {
+                room_index: 0,
+                room_name: "OR-1".to_string(),
+                tracks,
+            }],
+        );
+        data.timestamp = at(received_ms);
+        data
+    }
+
+    fn values(data: &ProcessedData) -> Vec<f64> {
+        data.all_tracks.iter().filter_map(|t| t.raw_value).collect()
+    }
+
+    #[test]
+    fn test_dedup_and_order_without_jitter() {
+        let mut sequencer = Sequencer::new(0, 60);
+
+        let mut data = frame(&[(2000, 72.0), (1000, 71.0), (2000, 72.0)], 2100);
+        sequencer.apply(&mut data);
+        assert_eq!(values(&data), vec![71.0, 72.0]);
+
+        // Retransmission and a record older than the last released one
+        let mut data = frame(&[(2000, 72.0), (1500, 70.0), (3000, 73.0)], 3100);
+        sequencer.apply(&mut data);
+        assert_eq!(values(&data), vec![73.0]);
+    }
+
+    #[test]
+    fn test_reorder_within_jitter() {
+        let mut sequencer = Sequencer::new(500, 60);
+
+        let mut data = frame(&[(2000, 72.0)], 2000);
+        sequencer.apply(&mut data);
+        assert!(data.all_tracks.is_empty());
+
+        // The late record arrives within the jitter window and is released first
+        let mut data = frame(&[(1000, 71.0)], 2300);
+        sequencer.apply(&mut data);
+        assert!(data.all_tracks.is_empty());
+
+        let mut data = frame(&[(3000, 73.0)], 2900);
+        sequencer.apply(&mut data);
+        assert_eq!(values(&data), vec![71.0, 72.0]);
+    }
+
+    #[test]
+    fn test_skew_flag() {
+        let mut sequencer = Sequencer::new(0, 60);
+
+        let mut data = frame(&[(0, 70.0), (1000, 71.0)], 120_000);
+        data.rooms[0].tracks[1].timestamp_flag = Some(TimestampFlag::Missing);
+        data.refresh_all_tracks();
+        sequencer.apply(&mut data);
+
+        let flags: Vec<Option<TimestampFlag>> = data.all_tracks.iter().map(|t| t.timestamp_flag).collect();
+        assert_eq!(flags, vec![Some(TimestampFlag::Skewed), Some(TimestampFlag::Missing)]);
+    }
+}
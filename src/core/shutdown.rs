@@ -0,0 +1,45 @@
+// /src/core/shutdown.rs
+// Module: core.shutdown
+// Purpose: Termination signals triggering the coordinated shutdown
+
+/// ID SRS: SRS-FN-SHUTDOWN-001
+/// Title: wait_for_signal
+///
+/// Description: VRConnect shall wait for an interactive interrupt (Ctrl+C,
+/// SIGINT) or a service manager termination request (SIGTERM).
+///
+/// Version: V1.0
+///
+/// # Returns
+/// Name of the received signal
+#[cfg(unix)]
+pub async fn wait_for_signal() -> &'static str {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    match signal(SignalKind::terminate()) {
+        Ok(mut terminate) => tokio::select! {
+            _ = tokio::signal::ctrl_c() => "SIGINT",
+            _ = terminate.recv() => "SIGTERM",
+        },
+        Err(e) => {
+            log::warn!("SIGTERM handler unavailable: {}", e);
+            let _ = tokio::signal::ctrl_c().await;
+            "SIGINT"
+        }
+    }
+}
+
+/// ID SRS: SRS-FN-SHUTDOWN-001
+/// Title: wait_for_signal
+///
+/// Description: VRConnect shall wait for an interactive interrupt (Ctrl+C).
+///
+/// Version: V1.0
+///
+/// # Returns
+/// Name of the received signal
+#[cfg(not(unix))]
+pub async fn wait_for_signal() -> &'static str {
+    let _ = tokio::signal::ctrl_c().await;
+    "Ctrl+C"
+}
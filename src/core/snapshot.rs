@@ -0,0 +1,219 @@
+// /src/core/snapshot.rs
+// Module: core.snapshot
+// Purpose: Latest value of every track of a device, merged across partial updates
+
+use crate::domain::{ProcessedData, ProcessedRoom, ProcessedTrack};
+use chrono::{DateTime, Duration, Utc};
+use std::collections::BTreeMap;
+
+/// Latest record of one track
+#[derive(Debug, Clone)]
+struct SnapshotEntry {
+    track: ProcessedTrack,
+    received: DateTime<Utc>,
+}
+
+/// ID SRS: SRS-MOD-SNAPSHOT-001
+/// Title: Snapshot
+///
+/// Description: VRConnect shall merge the partial updates of a device into
+/// the latest record of every track (per room and track name), so that
+/// consumers see a stable set of tracks with the age of each value. Tracks
+/// not received for longer than the maximum age are removed.
+///
+/// Version: V1.0
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    device_id: String,
+    max_age: Duration,
+    updated: DateTime<Utc>,
+    entries: BTreeMap<(String, String), SnapshotEntry>,
+}
+
+impl Snapshot {
+    /// ID SRS: SRS-FN-SNAPSHOT-001
+    /// Title: new
+    ///
+    /// Description: VRConnect shall construct an empty Snapshot of a device.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `device_id` - VitalRecorder device identifier
+    /// * `max_age_secs` - Age after which a track leaves the snapshot
+    ///
+    /// # Returns
+    /// New Snapshot instance
+    pub fn new(device_id: &str, max_age_secs: u64) -> Self {
+        Self {
+            device_id: device_id.to_string(),
+            max_age: Duration::seconds(max_age_secs as i64),
+            updated: DateTime::<Utc>::MIN_UTC,
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// ID SRS: SRS-FN-SNAPSHOT-002
+    /// Title: merge
+    ///
+    /// Description: VRConnect shall merge an update: each track replaces the
+    /// stored record of the same room and name unless it is older; tracks
+    /// older than the maximum age are removed.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `data` - Processed vital data (timestamp = receive time)
+    pub fn merge(&mut self, data: &ProcessedData) {
+        self.updated = data.timestamp;
+
+        for track in &data.all_tracks {
+            let key = (track.room_name.clone(), track.name.clone());
+            let newer = self
+                .entries
+                .get(&key)
+                .is_none_or(|entry| track.timestamp >= entry.track.timestamp);
+
+            if newer {
+                self.entries.insert(
+                    key,
+                    SnapshotEntry {
+                        track: track.clone(),
+                        received: data.timestamp,
+                    },
+                );
+            }
+        }
+
+        let (updated, max_age) = (self.updated, self.max_age);
+        self.entries.retain(|_, entry| updated - entry.received <= max_age);
+    }
+
+    /// ID SRS: SRS-FN-SNAPSHOT-003
+    /// Title: to_processed_data
+    ///
+    /// Description: VRConnect shall render the snapshot as processed data with
+    /// every known track, ordered by room and track index, each carrying the
+    /// time elapsed since it was received.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `now` - Reference time for track ages
+    ///
+    /// # Returns
+    /// Full snapshot (timestamp = last update receive time)
+    pub fn to_processed_data(&self, now: DateTime<Utc>) -> ProcessedData {
+        let mut rooms: Vec<ProcessedRoom> = Vec::new();
+
+        for entry in self.entries.values() {
+            let mut track = entry.track.clone();
+            track.age_ms = Some((now - entry.received).num_milliseconds().max(0) as u64);
+
+            match rooms.iter_mut().find(|room| room.room_name == track.room_name) {
+                Some(room) => room.tracks.push(track),
+                None => rooms.push(ProcessedRoom {
+                    room_index: track.room_index,
+                    room_name: track.room_name.clone(),
+                    tracks: vec![track],
+                }),
+            }
+        }
+
+        rooms.sort_by_key(|room| room.room_index);
+        for room in rooms.iter_mut() {
+            room.tracks.sort_by(|a, b| (a.track_index, &a.name).cmp(&(b.track_index, &b.name)));
+        }
+
+        let mut data = ProcessedData::new(self.device_id.clone(), rooms);
+        data.timestamp = self.updated;
+        data
+    }
+
+    /// ID SRS: SRS-FN-SNAPSHOT-004
+    /// Title: len
+    ///
+    /// Description: VRConnect shall return the number of tracks in the
+    /// snapshot.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Track count
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// ID SRS: SRS-FN-SNAPSHOT-005
+    /// Title: is_empty
+    ///
+    /// Description: VRConnect shall tell whether the snapshot has no track.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// true if empty
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(secs: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(1_700_000_000 + secs, 0).unwrap()
+    }
+
+    fn update(tracks: &[(&str, f64, i64)], received: i64) -> ProcessedData {
+        let tracks = tracks
+            .iter()
+            .enumerate()
+            .map(|(index, (name, value, secs))| {
+                let mut track = ProcessedTrack::synthetic(name, Some(*value), "", 0, "OR-1", at(*secs));
+                track.track_index = index as i32;
+                track
+            })
+            .collect();
+        let mut data = ProcessedData::new(
+            "VR1".to_string(),
+            vec![ProcessedRoom {
+                room_index: 0,
+                room_name: "OR-1".to_string(),
+                tracks,
+            }],
+        );
+        data.timestamp = at(received);
+        data
+    }
+
+    #[test]
+    fn test_merge_partial_updates() {
+        let mut snapshot = Snapshot::new("VR1", 60);
+        snapshot.merge(&update(&[("HR", 70.0, 0), ("SPO2", 98.0, 0)], 0));
+        snapshot.merge(&update(&[("HR", 72.0, 2)], 2));
+        // An older retransmission does not overwrite the latest value
+        snapshot.merge(&update(&[("SPO2", 90.0, -5)], 3));
+
+        let data = snapshot.to_processed_data(at(4));
+        let tracks: Vec<(&str, Option<f64>, Option<u64>)> = data
+            .all_tracks
+            .iter()
+            .map(|t| (t.name.as_str(), t.raw_value, t.age_ms))
+            .collect();
+        assert_eq!(tracks, vec![("HR", Some(72.0), Some(2000)), ("SPO2", Some(98.0), Some(4000))]);
+        assert_eq!(data.timestamp, at(3));
+    }
+
+    #[test]
+    fn test_max_age() {
+        let mut snapshot = Snapshot::new("VR1", 60);
+        snapshot.merge(&update(&[("HR", 70.0, 0), ("BT", 36.6, 0)], 0));
+        snapshot.merge(&update(&[("HR", 71.0, 61)], 61));
+
+        assert_eq!(snapshot.len(), 1);
+        assert_eq!(snapshot.to_processed_data(at(61)).all_tracks[0].name, "HR");
+    }
+}
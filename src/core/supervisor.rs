@@ -0,0 +1,388 @@
+// /src/core/supervisor.rs
+// Module: core.supervisor
+// Purpose: Restart of failed inputs and outputs with exponential backoff
+
+use crate::error::Result;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+use tokio_util::task::AbortOnDropHandle;
+
+/// ID SRS: SRS-MOD-SUPERVISOR-001
+/// Title: Backoff
+///
+/// Description: VRConnect shall wait before restarting a failed component,
+/// doubling the delay after each consecutive failure up to a maximum.
+///
+/// Version: V1.0
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Backoff {
+    pub initial: Duration,
+    pub max: Duration,
+}
+
+impl Backoff {
+    /// ID SRS: SRS-FN-SUPERVISOR-001
+    /// Title: delay
+    ///
+    /// Description: VRConnect shall compute the delay before a restart.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `failures` - Consecutive failures of the component (1 = first)
+    ///
+    /// # Returns
+    /// Delay before restarting
+    pub fn delay(&self, failures: u32) -> Duration {
+        let factor = 1u32 << failures.saturating_sub(1).min(16);
+        self.initial.saturating_mul(factor).min(self.max)
+    }
+}
+
+/// ID SRS: SRS-MOD-SUPERVISOR-002
+/// Title: ComponentState
+///
+/// Description: VRConnect shall report a supervised component as running,
+/// restarting after a failure (degraded), stopped on shutdown, or failed
+/// when a critical component exhausted its restarts.
+///
+/// Version: V1.0
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ComponentState {
+    Running,
+    Restarting,
+    Stopped,
+    Failed,
+}
+
+/// ID SRS: SRS-MOD-SUPERVISOR-003
+/// Title: ComponentReport
+///
+/// Description: VRConnect shall describe the state, restart count and last
+/// error of a supervised component.
+///
+/// Version: V1.0
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ComponentReport {
+    pub name: String,
+    pub state: ComponentState,
+    pub critical: bool,
+    pub restarts: u32,
+    pub last_error: Option<String>,
+}
+
+impl ComponentReport {
+    /// ID SRS: SRS-FN-SUPERVISOR-002
+    /// Title: is_degraded
+    ///
+    /// Description: VRConnect shall tell whether a component is not running
+    /// while the application is.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// true if restarting or failed
+    pub fn is_degraded(&self) -> bool {
+        matches!(self.state, ComponentState::Restarting | ComponentState::Failed)
+    }
+}
+
+/// Reports of all components and the critical failure, if any
+#[derive(Default)]
+struct SupervisorState {
+    components: BTreeMap<String, ComponentReport>,
+    failure: Option<String>,
+}
+
+/// ID SRS: SRS-MOD-SUPERVISOR-004
+/// Title: Supervisor
+///
+/// Description: VRConnect shall run inputs and outputs as supervised
+/// components: a component that fails (or stops before shutdown) is restarted
+/// with exponential backoff; a critical component failing more than the
+/// maximum number of consecutive restarts stops the application.
+///
+/// Version: V1.0
+#[derive(Clone)]
+pub struct Supervisor {
+    backoff: Backoff,
+    max_restarts: u32,
+    critical: Arc<BTreeSet<String>>,
+    shutdown: CancellationToken,
+    state: Arc<Mutex<SupervisorState>>,
+}
+
+impl Supervisor {
+    /// ID SRS: SRS-FN-SUPERVISOR-003
+    /// Title: new
+    ///
+    /// Description: VRConnect shall construct a Supervisor.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `backoff` - Restart delays
+    /// * `max_restarts` - Consecutive restarts of a critical component before stopping the application
+    /// * `critical` - Names of the critical components
+    /// * `shutdown` - Shutdown token (cancelled on critical failure)
+    ///
+    /// # Returns
+    /// New Supervisor instance
+    pub fn new(backoff: Backoff, max_restarts: u32, critical: BTreeSet<String>, shutdown: CancellationToken) -> Self {
+        Self {
+            backoff,
+            max_restarts,
+            critical: Arc::new(critical),
+            shutdown,
+            state: Arc::new(Mutex::new(SupervisorState::default())),
+        }
+    }
+
+    /// ID SRS: SRS-FN-SUPERVISOR-004
+    /// Title: parse_critical
+    ///
+    /// Description: VRConnect shall parse a comma-separated list of critical
+    /// component names.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `spec` - Component list such as "socketio,ble"
+    ///
+    /// # Returns
+    /// Component names
+    pub fn parse_critical(spec: &str) -> BTreeSet<String> {
+        spec.split(',')
+            .map(|name| name.trim().to_lowercase())
+            .filter(|name| !name.is_empty())
+            .collect()
+    }
+
+    /// ID SRS: SRS-FN-SUPERVISOR-005
+    /// Title: spawn
+    ///
+    /// Description: VRConnect shall run a component until shutdown, creating
+    /// a new run of the component after each failure. Each run is a separate
+    /// task, so that a panic is a failure too; a run ending without error
+    /// before shutdown is a failure; a run lasting longer than the maximum
+    /// backoff resets the consecutive failure count.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `name` - Component name
+    /// * `factory` - Creates a run of the component (returns on shutdown or failure)
+    ///
+    /// # Returns
+    /// Supervision task handle
+    pub fn spawn<F, Fut>(&self, name: &str, mut factory: F) -> JoinHandle<()>
+    where
+        F: FnMut() -> Fut + Send + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        let supervisor = self.clone();
+        let name = name.to_string();
+        let critical = self.critical.contains(&name);
+        self.lock().components.insert(
+            name.clone(),
+            ComponentReport {
+                name: name.clone(),
+                state: ComponentState::Running,
+                critical,
+                restarts: 0,
+                last_error: None,
+            },
+        );
+
+        tokio::spawn(async move {
+            let mut failures = 0;
+            loop {
+                supervisor.update(&name, |report| report.state = ComponentState::Running);
+                let started = Instant::now();
+                // Aborted with the supervision task
+                let result = AbortOnDropHandle::new(tokio::spawn(factory())).await;
+
+                if supervisor.shutdown.is_cancelled() {
+                    supervisor.update(&name, |report| report.state = ComponentState::Stopped);
+                    break;
+                }
+
+                let error = match result {
+                    Ok(Ok(())) => "stopped unexpectedly".to_string(),
+                    Ok(Err(e)) => e.to_string(),
+                    Err(e) => e.to_string(),
+                };
+                if started.elapsed() >= supervisor.backoff.max {
+                    failures = 0;
+                }
+                failures += 1;
+
+                if critical && failures > supervisor.max_restarts {
+                    log::error!("Critical component {} failed: {}", name, error);
+                    supervisor.update(&name, |report| {
+                        report.state = ComponentState::Failed;
+                        report.last_error = Some(error.clone());
+                    });
+                    supervisor.lock().failure.get_or_insert_with(|| format!("{}: {}", name, error));
+                    supervisor.shutdown.cancel();
+                    break;
+                }
+
+                let delay = supervisor.backoff.delay(failures);
+                log::warn!("⚠️  {} failed: {} (restart in {} ms)", name, error, delay.as_millis());
+                supervisor.update(&name, |report| {
+                    report.state = ComponentState::Restarting;
+                    report.restarts += 1;
+                    report.last_error = Some(error.clone());
+                });
+
+                tokio::select! {
+                    _ = tokio::time::sleep(delay) => {}
+                    _ = supervisor.shutdown.cancelled() => {
+                        supervisor.update(&name, |report| report.state = ComponentState::Stopped);
+                        break;
+                    }
+                }
+            }
+        })
+    }
+
+    /// ID SRS: SRS-FN-SUPERVISOR-006
+    /// Title: reports
+    ///
+    /// Description: VRConnect shall list the supervised components sorted by
+    /// name.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Component reports
+    pub fn reports(&self) -> Vec<ComponentReport> {
+        self.lock().components.values().cloned().collect()
+    }
+
+    /// ID SRS: SRS-FN-SUPERVISOR-007
+    /// Title: failure
+    ///
+    /// Description: VRConnect shall return the failure of the critical
+    /// component that stopped the application.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Component and error, or None
+    pub fn failure(&self) -> Option<String> {
+        self.lock().failure.clone()
+    }
+
+    /// Apply a change to the report of a component
+    fn update(&self, name: &str, change: impl FnOnce(&mut ComponentReport)) {
+        if let Some(report) = self.lock().components.get_mut(name) {
+            change(report);
+        }
+    }
+
+    /// Lock the supervisor state (a poisoned lock still holds consistent state)
+    fn lock(&self) -> std::sync::MutexGuard<'_, SupervisorState> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::VitalError;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn supervisor(critical: &str, shutdown: &CancellationToken) -> Supervisor {
+        let backoff = Backoff {
+            initial: Duration::from_millis(1),
+            max: Duration::from_millis(50),
+        };
+        Supervisor::new(backoff, 2, Supervisor::parse_critical(critical), shutdown.clone())
+    }
+
+    #[test]
+    fn test_backoff() {
+        let backoff = Backoff {
+            initial: Duration::from_millis(500),
+            max: Duration::from_secs(3),
+        };
+        let delays: Vec<u128> = (1..=5).map(|n| backoff.delay(n).as_millis()).collect();
+        assert_eq!(delays, vec![500, 1000, 2000, 3000, 3000]);
+    }
+
+    #[tokio::test]
+    async fn test_restart_until_shutdown() {
+        let shutdown = CancellationToken::new();
+        let supervisor = supervisor("", &shutdown);
+        let runs = Arc::new(AtomicU32::new(0));
+
+        let task = {
+            let runs = runs.clone();
+            let shutdown = shutdown.clone();
+            supervisor.spawn("ble", move || {
+                let runs = runs.clone();
+                let shutdown = shutdown.clone();
+                async move {
+                    // Fails three times, then runs until shutdown
+                    if runs.fetch_add(1, Ordering::SeqCst) < 3 {
+                        return Err(VitalError::Processing("adapter unavailable".to_string()));
+                    }
+                    shutdown.cancelled().await;
+                    Ok(())
+                }
+            })
+        };
+
+        while runs.load(Ordering::SeqCst) < 4 {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+        let report = supervisor.reports()[0].clone();
+        assert_eq!((report.state, report.restarts), (ComponentState::Running, 3));
+        assert_eq!(report.last_error.as_deref(), Some("Data processing error: adapter unavailable"));
+
+        shutdown.cancel();
+        task.await.unwrap();
+        assert_eq!(supervisor.reports()[0].state, ComponentState::Stopped);
+        assert!(supervisor.failure().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_critical_failure_stops_application() {
+        let shutdown = CancellationToken::new();
+        let supervisor = supervisor("socketio", &shutdown);
+
+        supervisor
+            .spawn("socketio", || async { Err(VitalError::SocketIo("bind failed".to_string())) })
+            .await
+            .unwrap();
+
+        assert!(shutdown.is_cancelled());
+        let report = &supervisor.reports()[0];
+        assert_eq!((report.state, report.restarts, report.critical), (ComponentState::Failed, 2, true));
+        assert!(supervisor.failure().unwrap().starts_with("socketio:"));
+    }
+
+    #[tokio::test]
+    async fn test_panic_is_a_failure() {
+        let shutdown = CancellationToken::new();
+        let supervisor = supervisor("http", &shutdown);
+
+        supervisor
+            .spawn("http", || async { panic!("handler bug") })
+            .await
+            .unwrap();
+
+        assert!(shutdown.is_cancelled());
+        let report = &supervisor.reports()[0];
+        assert_eq!((report.state, report.restarts), (ComponentState::Failed, 2));
+        assert!(report.last_error.as_deref().unwrap().contains("panicked"));
+    }
+}
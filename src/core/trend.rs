@@ -0,0 +1,207 @@
+// /src/core/trend.rs
+// Module: core.trend
+// Purpose: Rolling per-track time series and trend computation for numeric tracks
+
+use crate::domain::{ProcessedData, TrackType, Trend, TrendDirection};
+use chrono::{DateTime, Duration, Utc};
+use std::collections::{HashMap, VecDeque};
+
+/// Time-ordered values of one track
+type Series = VecDeque<(DateTime<Utc>, f64)>;
+
+/// ID SRS: SRS-MOD-TRENDTRACKER-001
+/// Title: TrendTracker
+///
+/// Description: VRConnect shall keep a rolling window of values per device,
+/// room and numeric track and attach the resulting trend to each new value.
+/// A trend is stable while the change predicted by the slope over the window
+/// stays within a percentage of the moving average.
+///
+/// Version: V1.0
+pub struct TrendTracker {
+    window: Duration,
+    stable_ratio: f64,
+    series: HashMap<(String, String, String), Series>,
+}
+
+impl TrendTracker {
+    /// ID SRS: SRS-FN-TRENDTRACKER-001
+    /// Title: new
+    ///
+    /// Description: VRConnect shall construct a TrendTracker with a window
+    /// length and a stability band.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `window_secs` - Rolling window length in seconds
+    /// * `stable_percent` - Change over the window (percent of the average) considered stable
+    ///
+    /// # Returns
+    /// New TrendTracker instance
+    pub fn new(window_secs: u64, stable_percent: f64) -> Self {
+        Self {
+            window: Duration::seconds(window_secs as i64),
+            stable_ratio: stable_percent / 100.0,
+            series: HashMap::new(),
+        }
+    }
+
+    /// ID SRS: SRS-FN-TRENDTRACKER-002
+    /// Title: apply
+    ///
+    /// Description: VRConnect shall record the value of every numeric track
+    /// (at its own timestamp, replacing a value with the same timestamp),
+    /// evict values older than the window and set the track trend.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `data` - Processed vital data, modified in place
+    pub fn apply(&mut self, data: &mut ProcessedData) {
+        for room in data.rooms.iter_mut() {
+            for track in room.tracks.iter_mut() {
+                let (TrackType::Number, Some(value)) = (&track.track_type, track.raw_value) else {
+                    continue;
+                };
+
+                let series = self
+                    .series
+                    .entry((data.device_id.clone(), track.room_name.clone(), track.name.clone()))
+                    .or_default();
+
+                match series.back_mut() {
+                    Some(last) if last.0 == track.timestamp => last.1 = value,
+                    Some(last) if last.0 > track.timestamp => continue,
+                    _ => series.push_back((track.timestamp, value)),
+                }
+
+                while series
+                    .front()
+                    .is_some_and(|(timestamp, _)| track.timestamp - *timestamp > self.window)
+                {
+                    series.pop_front();
+                }
+
+                track.trend = Self::compute(series, self.stable_ratio);
+            }
+        }
+
+        data.refresh_all_tracks();
+    }
+
+    /// ID SRS: SRS-FN-TRENDTRACKER-003
+    /// Title: compute
+    ///
+    /// Description: VRConnect shall compute moving average, min/max,
+    /// least-squares slope (per minute), rate of change since the previous
+    /// value and trend direction of a series.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `series` - Time-ordered values within the window
+    /// * `stable_ratio` - Stability band as a fraction of the average
+    ///
+    /// # Returns
+    /// Trend, or None for an empty series
+    fn compute(series: &Series, stable_ratio: f64) -> Option<Trend> {
+        let (origin, _) = *series.front()?;
+        let n = series.len() as f64;
+        let minutes = |timestamp: &DateTime<Utc>| (*timestamp - origin).num_milliseconds() as f64 / 60_000.0;
+
+        let mean_t = series.iter().map(|(t, _)| minutes(t)).sum::<f64>() / n;
+        let mean_v = series.iter().map(|(_, v)| v).sum::<f64>() / n;
+        let (covariance, variance) = series.iter().fold((0.0, 0.0), |(cov, var), (t, v)| {
+            let dt = minutes(t) - mean_t;
+            (cov + dt * (v - mean_v), var + dt * dt)
+        });
+        let slope = if variance > 0.0 { covariance / variance } else { 0.0 };
+
+        let span = series.back().map(|(t, _)| minutes(t)).unwrap_or(0.0);
+        let predicted_change = (slope * span).abs();
+        let direction = if predicted_change <= stable_ratio * mean_v.abs() || predicted_change == 0.0 {
+            TrendDirection::Stable
+        } else if slope > 0.0 {
+            TrendDirection::Rising
+        } else {
+            TrendDirection::Falling
+        };
+
+        let rate_of_change_per_min = (series.len() >= 2)
+            .then(|| {
+                let (t1, v1) = series[series.len() - 2];
+                let (t2, v2) = series[series.len() - 1];
+                let elapsed = (t2 - t1).num_milliseconds() as f64 / 60_000.0;
+                (elapsed > 0.0).then(|| (v2 - v1) / elapsed)
+            })
+            .flatten();
+
+        Some(Trend {
+            direction,
+            moving_average: mean_v,
+            slope_per_min: slope,
+            min: series.iter().map(|(_, v)| *v).fold(f64::INFINITY, f64::min),
+            max: series.iter().map(|(_, v)| *v).fold(f64::NEG_INFINITY, f64::max),
+            rate_of_change_per_min,
+            samples: series.len(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{ProcessedRoom, ProcessedTrack};
+    use chrono::TimeZone;
+
+    fn frame(value: f64, secs: i64) -> ProcessedData {
+        let timestamp = Utc.timestamp_opt(1_700_000_000 + secs, 0).unwrap();
+        ProcessedData::new(
+            "VR1".to_string(),
+            vec![ProcessedRoom {
+                room_index: 0,
+                room_name: "OR-1".to_string(),
+                tracks: vec![ProcessedTrack::synthetic("HR", Some(value), "bpm", 0, "OR-1", timestamp)],
+            }],
+        )
+    }
+
+    fn trend(tracker: &mut TrendTracker, value: f64, secs: i64) -> Trend {
+        let mut data = frame(value, secs);
+        tracker.apply(&mut data);
+        assert_eq!(data.rooms[0].tracks[0].trend, data.all_tracks[0].trend);
+        data.all_tracks[0].trend.clone().unwrap()
+    }
+
+    #[test]
+    fn test_rising_trend() {
+        let mut tracker = TrendTracker::new(300, 5.0);
+        let mut last = None;
+        for minute in 0..5 {
+            last = Some(trend(&mut tracker, 70.0 + 5.0 * minute as f64, minute * 60));
+        }
+
+        let last = last.unwrap();
+        assert_eq!(last.direction, TrendDirection::Rising);
+        assert!((last.slope_per_min - 5.0).abs() < 1e-9);
+        assert_eq!(last.rate_of_change_per_min, Some(5.0));
+        assert_eq!((last.min, last.max, last.samples), (70.0, 90.0, 5));
+        assert!((last.moving_average - 80.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_stable_and_window_eviction() {
+        let mut tracker = TrendTracker::new(60, 5.0);
+        trend(&mut tracker, 40.0, 0);
+        for secs in (120..=180).step_by(10) {
+            trend(&mut tracker, 98.0 + (secs % 20) as f64 / 10.0, secs);
+        }
+
+        let last = trend(&mut tracker, 98.5, 190);
+        assert_eq!(last.direction, TrendDirection::Stable);
+        // The old value (40) left the window
+        assert!(last.min >= 98.0);
+        assert_eq!(last.samples, 7);
+    }
+}
@@ -0,0 +1,361 @@
+// /src/core/watchdog.rs
+// Module: core.watchdog
+// Purpose: Stale-data and signal-loss detection per device and per track
+
+use crate::domain::{AlarmAction, AlarmCondition, AlarmEvent, AlarmPriority, ProcessedData, SignalStatus};
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+
+/// Room name used for device-level conditions covering all rooms
+pub(crate) const ALL_ROOMS: &str = "*";
+
+/// Parameter name used for device-level stale data conditions
+const DATA_STALE_PARAMETER: &str = "DATA_STALE";
+
+/// ID SRS: SRS-MOD-WATCHDOG-001
+/// Title: Watchdog
+///
+/// Description: VRConnect shall track the last update time of every device and
+/// track, raising "data stale" when a device stops sending and "sensor off"
+/// when a single track stops updating while others continue, marking the
+/// affected tracks in outputs, and clearing both on recovery.
+///
+/// Version: V1.0
+pub struct Watchdog {
+    device_timeout: Duration,
+    track_timeout: Duration,
+    devices: HashMap<String, DeviceActivity>,
+}
+
+/// Activity of one device and its tracks
+#[derive(Debug, Clone)]
+struct DeviceActivity {
+    last_update: DateTime<Utc>,
+    stale: bool,
+    tracks: HashMap<(String, String), TrackActivity>,
+}
+
+/// Activity of one track, keyed by (room name, track name)
+#[derive(Debug, Clone)]
+struct TrackActivity {
+    last_update: DateTime<Utc>,
+    off: bool,
+}
+
+impl Watchdog {
+    /// ID SRS: SRS-FN-WATCHDOG-001
+    /// Title: new
+    ///
+    /// Description: VRConnect shall construct a Watchdog with device and track
+    /// timeouts.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `device_timeout_secs` - Seconds without any frame before data is stale
+    /// * `track_timeout_secs` - Seconds without a track update before sensor off
+    ///
+    /// # Returns
+    /// New Watchdog instance
+    pub fn new(device_timeout_secs: u64, track_timeout_secs: u64) -> Self {
+        Self {
+            device_timeout: Duration::seconds(device_timeout_secs as i64),
+            track_timeout: Duration::seconds(track_timeout_secs as i64),
+            devices: HashMap::new(),
+        }
+    }
+
+    /// ID SRS: SRS-FN-WATCHDOG-002
+    /// Title: record
+    ///
+    /// Description: VRConnect shall record activity for the device and every
+    /// track of the processed data, clearing stale and sensor-off conditions
+    /// that recovered.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `data` - Processed vital data (timestamp = receive time)
+    ///
+    /// # Returns
+    /// Cleared events for recovered conditions
+    pub fn record(&mut self, data: &ProcessedData) -> Vec<AlarmEvent> {
+        let now = data.timestamp;
+        let mut events = Vec::new();
+
+        let device = self
+            .devices
+            .entry(data.device_id.clone())
+            .or_insert_with(|| DeviceActivity {
+                last_update: now,
+                stale: false,
+                tracks: HashMap::new(),
+            });
+
+        device.last_update = now;
+
+        if device.stale {
+            device.stale = false;
+            log::info!("Data flow recovered for device {}", data.device_id);
+            events.push(Self::make_event(
+                &data.device_id,
+                ALL_ROOMS,
+                DATA_STALE_PARAMETER,
+                AlarmCondition::DataStale,
+                AlarmAction::Cleared,
+                now,
+            ));
+        }
+
+        for track in &data.all_tracks {
+            let activity = device
+                .tracks
+                .entry((track.room_name.clone(), track.name.clone()))
+                .or_insert(TrackActivity {
+                    last_update: now,
+                    off: false,
+                });
+
+            activity.last_update = now;
+
+            if activity.off {
+                activity.off = false;
+                log::info!("Track {} recovered on {}/{}", track.name, data.device_id, track.room_name);
+                events.push(Self::make_event(
+                    &data.device_id,
+                    &track.room_name,
+                    &track.name,
+                    AlarmCondition::SensorOff,
+                    AlarmAction::Cleared,
+                    now,
+                ));
+            }
+        }
+
+        events
+    }
+
+    /// ID SRS: SRS-FN-WATCHDOG-003
+    /// Title: check
+    ///
+    /// Description: VRConnect shall raise "data stale" for devices silent longer
+    /// than the device timeout, and "sensor off" for tracks silent longer than
+    /// the track timeout while their device is still sending.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `now` - Current time
+    ///
+    /// # Returns
+    /// Raised events for newly detected conditions
+    pub fn check(&mut self, now: DateTime<Utc>) -> Vec<AlarmEvent> {
+        let mut events = Vec::new();
+
+        for (device_id, device) in self.devices.iter_mut() {
+            if device.stale {
+                continue;
+            }
+
+            if now - device.last_update > self.device_timeout {
+                device.stale = true;
+                log::warn!(
+                    "No data from device {} for {}s",
+                    device_id,
+                    (now - device.last_update).num_seconds()
+                );
+                events.push(Self::make_event(
+                    device_id,
+                    ALL_ROOMS,
+                    DATA_STALE_PARAMETER,
+                    AlarmCondition::DataStale,
+                    AlarmAction::Raised,
+                    now,
+                ));
+                continue;
+            }
+
+            for ((room_name, track_name), activity) in device.tracks.iter_mut() {
+                if !activity.off && now - activity.last_update > self.track_timeout {
+                    activity.off = true;
+                    log::warn!("Track {} stopped updating on {}/{}", track_name, device_id, room_name);
+                    events.push(Self::make_event(
+                        device_id,
+                        room_name,
+                        track_name,
+                        AlarmCondition::SensorOff,
+                        AlarmAction::Raised,
+                        now,
+                    ));
+                }
+            }
+        }
+
+        events
+    }
+
+    /// ID SRS: SRS-FN-WATCHDOG-005
+    /// Title: mark
+    ///
+    /// Description: VRConnect shall flag the tracks of processed data going
+    /// to outputs whose device is stale or whose track is sensor off, so that
+    /// their last value is not shown as current.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `data` - Processed vital data (frame or snapshot), modified in place
+    pub fn mark(&self, data: &mut ProcessedData) {
+        let Some(device) = self.devices.get(&data.device_id) else {
+            return;
+        };
+
+        for room in data.rooms.iter_mut() {
+            for track in room.tracks.iter_mut() {
+                let off = device
+                    .tracks
+                    .get(&(track.room_name.clone(), track.name.clone()))
+                    .is_some_and(|activity| activity.off);
+                if device.stale {
+                    track.signal_status = Some(SignalStatus::Stale);
+                } else if off {
+                    track.signal_status = Some(SignalStatus::SensorOff);
+                }
+            }
+        }
+        data.refresh_all_tracks();
+    }
+
+    /// ID SRS: SRS-FN-WATCHDOG-004
+    /// Title: make_event
+    ///
+    /// Description: VRConnect shall build a technical AlarmEvent for a stale
+    /// data or sensor-off condition.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `device_id` - VitalRecorder device identifier
+    /// * `room_name` - Room name ("*" for device-level conditions)
+    /// * `parameter` - Track name or DATA_STALE
+    /// * `condition` - DataStale or SensorOff
+    /// * `action` - Raised or Cleared
+    /// * `now` - Event time
+    ///
+    /// # Returns
+    /// New AlarmEvent
+    fn make_event(
+        device_id: &str,
+        room_name: &str,
+        parameter: &str,
+        condition: AlarmCondition,
+        action: AlarmAction,
+        now: DateTime<Utc>,
+    ) -> AlarmEvent {
+        let priority = match condition {
+            AlarmCondition::DataStale => AlarmPriority::Medium,
+            _ => AlarmPriority::Low,
+        };
+
+        AlarmEvent {
+            alarm_id: AlarmEvent::alarm_id_for(device_id, room_name, parameter),
+            device_id: device_id.to_string(),
+            room_name: room_name.to_string(),
+            parameter: parameter.to_string(),
+            condition,
+            action,
+            priority,
+            value: None,
+            limit: None,
+            silenced: false,
+            message: format!("{} {} {:?}", parameter, condition.label(), action),
+            timestamp: now,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{ProcessedRoom, ProcessedTrack};
+    use chrono::TimeZone;
+
+    fn track(name: &str) -> ProcessedTrack {
+        ProcessedTrack::synthetic(name, Some(1.0), "", 0, "OR-1", Utc::now())
+    }
+
+    fn at(secs: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(1_700_000_000 + secs, 0).unwrap()
+    }
+
+    fn frame(names: &[&str], secs: i64) -> ProcessedData {
+        let mut data = ProcessedData::new(
+            "VR1".to_string(),
+            vec![ProcessedRoom {
+                room_index: 0,
+                room_name: "OR-1".to_string(),
+                tracks: names.iter().map(|name| track(name)).collect(),
+            }],
+        );
+        data.timestamp = at(secs);
+        data
+    }
+
+    #[test]
+    fn test_device_stale_and_recovery() {
+        let mut watchdog = Watchdog::new(10, 30);
+        assert!(watchdog.record(&frame(&["HR"], 0)).is_empty());
+        assert!(watchdog.check(at(5)).is_empty());
+
+        let events = watchdog.check(at(11));
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].condition, AlarmCondition::DataStale);
+        assert_eq!(events[0].action, AlarmAction::Raised);
+        assert_eq!(events[0].alarm_id, "VR1/*/DATA_STALE");
+
+        // Raised only once
+        assert!(watchdog.check(at(12)).is_empty());
+
+        let events = watchdog.record(&frame(&["HR"], 13));
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].action, AlarmAction::Cleared);
+    }
+
+    #[test]
+    fn test_sensor_off_while_device_active() {
+        let mut watchdog = Watchdog::new(10, 30);
+        watchdog.record(&frame(&["HR", "SPO2"], 0));
+
+        for secs in (5..=30).step_by(5) {
+            watchdog.record(&frame(&["HR"], secs));
+        }
+
+        let events = watchdog.check(at(31));
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].condition, AlarmCondition::SensorOff);
+        assert_eq!(events[0].parameter, "SPO2");
+        assert_eq!(events[0].room_name, "OR-1");
+
+        let events = watchdog.record(&frame(&["HR", "SPO2"], 32));
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].condition, AlarmCondition::SensorOff);
+        assert_eq!(events[0].action, AlarmAction::Cleared);
+    }
+
+    #[test]
+    fn test_mark_sensor_off_tracks() {
+        let mut watchdog = Watchdog::new(10, 30);
+        watchdog.record(&frame(&["HR", "SPO2"], 0));
+        for secs in (5..=30).step_by(5) {
+            watchdog.record(&frame(&["HR"], secs));
+        }
+        watchdog.check(at(31));
+
+        // Snapshot still holding the last SPO2 value
+        let mut snapshot = frame(&["HR", "SPO2"], 31);
+        watchdog.mark(&mut snapshot);
+        let status: Vec<Option<SignalStatus>> = snapshot.all_tracks.iter().map(|t| t.signal_status).collect();
+        assert_eq!(status, vec![None, Some(SignalStatus::SensorOff)]);
+        assert_eq!(snapshot.rooms[0].tracks[1].signal_status, Some(SignalStatus::SensorOff));
+    }
+}
@@ -0,0 +1,551 @@
+// /src/derived/early_warning.rs
+// Module: derived.early_warning
+// Purpose: NEWS2 and MEWS early warning score computation as synthetic tracks
+
+use crate::domain::{ProcessedData, ProcessedTrack, TrackType, VitalSign};
+use chrono::{DateTime, Duration, Utc};
+use std::collections::{BTreeMap, HashMap};
+
+/// ID SRS: SRS-MOD-EARLYWARNING-001
+/// Title: EarlyWarningScore
+///
+/// Description: VRConnect shall support the NEWS2 (Royal College of Physicians,
+/// 2017) and MEWS (Subbe et al., 2001) aggregate deterioration scores.
+/// Supplemental oxygen and level of consciousness are not available from the
+/// monitor stream and are scored as "air" and "alert" (0 points).
+///
+/// Version: V1.0
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EarlyWarningScore {
+    News2,
+    Mews,
+}
+
+/// Result of one score evaluation with explicit missing inputs
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoreResult {
+    pub total: Option<u32>,
+    pub sub_scores: Vec<(VitalSign, Option<u32>)>,
+    pub missing: Vec<VitalSign>,
+}
+
+/// ID SRS: SRS-MOD-EARLYWARNING-002
+/// Title: EarlyWarningCalculator
+///
+/// Description: VRConnect shall keep the latest canonical vital signs per
+/// device and room across partial updates and publish configured early warning
+/// scores and their sub-scores as synthetic tracks.
+///
+/// Version: V1.0
+pub struct EarlyWarningCalculator {
+    scores: Vec<EarlyWarningScore>,
+    max_input_age: Duration,
+    latest: HashMap<(String, String), RoomInputs>,
+}
+
+/// Latest inputs of one room
+#[derive(Debug, Clone, Default)]
+struct RoomInputs {
+    room_index: i32,
+    values: BTreeMap<VitalSign, (f64, DateTime<Utc>)>,
+}
+
+impl EarlyWarningScore {
+    /// ID SRS: SRS-FN-EARLYWARNING-001
+    /// Title: parse_list
+    ///
+    /// Description: VRConnect shall parse a comma-separated list of score names
+    /// ("news2", "mews"); an empty list disables early warning scores.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `spec` - Comma-separated score names
+    ///
+    /// # Returns
+    /// Parsed scores or error message
+    pub fn parse_list(spec: &str) -> Result<Vec<Self>, String> {
+        spec.split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(|name| match name.to_lowercase().as_str() {
+                "news2" => Ok(EarlyWarningScore::News2),
+                "mews" => Ok(EarlyWarningScore::Mews),
+                other => Err(format!("Unknown early warning score: {}", other)),
+            })
+            .collect()
+    }
+
+    /// ID SRS: SRS-FN-EARLYWARNING-002
+    /// Title: name
+    ///
+    /// Description: VRConnect shall provide the score name used as synthetic
+    /// track name prefix.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Upper-case score name
+    pub fn name(&self) -> &'static str {
+        match self {
+            EarlyWarningScore::News2 => "NEWS2",
+            EarlyWarningScore::Mews => "MEWS",
+        }
+    }
+
+    /// ID SRS: SRS-FN-EARLYWARNING-003
+    /// Title: inputs
+    ///
+    /// Description: VRConnect shall list the vital signs required by a score.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Required vital signs
+    pub fn inputs(&self) -> &'static [VitalSign] {
+        match self {
+            EarlyWarningScore::News2 => &[
+                VitalSign::RespiratoryRate,
+                VitalSign::SpO2,
+                VitalSign::SystolicPressure,
+                VitalSign::HeartRate,
+                VitalSign::Temperature,
+            ],
+            EarlyWarningScore::Mews => &[
+                VitalSign::SystolicPressure,
+                VitalSign::HeartRate,
+                VitalSign::RespiratoryRate,
+                VitalSign::Temperature,
+            ],
+        }
+    }
+
+    /// ID SRS: SRS-FN-EARLYWARNING-004
+    /// Title: sub_score
+    ///
+    /// Description: VRConnect shall compute the points for one vital sign using
+    /// the published score tables (integer parameters rounded to the nearest
+    /// unit, temperature to 0.1 °C).
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `sign` - Vital sign
+    /// * `value` - Measured value
+    ///
+    /// # Returns
+    /// Points, or None if the sign is not part of the score
+    pub fn sub_score(&self, sign: VitalSign, value: f64) -> Option<u32> {
+        let v = match sign {
+            VitalSign::Temperature => (value * 10.0).round() / 10.0,
+            _ => value.round(),
+        };
+
+        let points = match (self, sign) {
+            (EarlyWarningScore::News2, VitalSign::RespiratoryRate) => match v {
+                v if v <= 8.0 => 3,
+                v if v <= 11.0 => 1,
+                v if v <= 20.0 => 0,
+                v if v <= 24.0 => 2,
+                _ => 3,
+            },
+            (EarlyWarningScore::News2, VitalSign::SpO2) => match v {
+                v if v <= 91.0 => 3,
+                v if v <= 93.0 => 2,
+                v if v <= 95.0 => 1,
+                _ => 0,
+            },
+            (EarlyWarningScore::News2, VitalSign::SystolicPressure) => match v {
+                v if v <= 90.0 => 3,
+                v if v <= 100.0 => 2,
+                v if v <= 110.0 => 1,
+                v if v <= 219.0 => 0,
+                _ => 3,
+            },
+            (EarlyWarningScore::News2, VitalSign::HeartRate) => match v {
+                v if v <= 40.0 => 3,
+                v if v <= 50.0 => 1,
+                v if v <= 90.0 => 0,
+                v if v <= 110.0 => 1,
+                v if v <= 130.0 => 2,
+                _ => 3,
+            },
+            (EarlyWarningScore::News2, VitalSign::Temperature) => match v {
+                v if v <= 35.0 => 3,
+                v if v <= 36.0 => 1,
+                v if v <= 38.0 => 0,
+                v if v <= 39.0 => 1,
+                _ => 2,
+            },
+            (EarlyWarningScore::Mews, VitalSign::SystolicPressure) => match v {
+                v if v <= 70.0 => 3,
+                v if v <= 80.0 => 2,
+                v if v <= 100.0 => 1,
+                v if v <= 199.0 => 0,
+                _ => 2,
+            },
+            (EarlyWarningScore::Mews, VitalSign::HeartRate) => match v {
+                v if v <= 40.0 => 2,
+                v if v <= 50.0 => 1,
+                v if v <= 100.0 => 0,
+                v if v <= 110.0 => 1,
+                v if v <= 129.0 => 2,
+                _ => 3,
+            },
+            (EarlyWarningScore::Mews, VitalSign::RespiratoryRate) => match v {
+                v if v <= 8.0 => 2,
+                v if v <= 14.0 => 0,
+                v if v <= 20.0 => 1,
+                v if v <= 29.0 => 2,
+                _ => 3,
+            },
+            (EarlyWarningScore::Mews, VitalSign::Temperature) => match v {
+                v if v < 35.0 => 2,
+                v if v < 38.5 => 0,
+                _ => 2,
+            },
+            (EarlyWarningScore::Mews, VitalSign::SpO2) => return None,
+        };
+
+        Some(points)
+    }
+
+    /// ID SRS: SRS-FN-EARLYWARNING-005
+    /// Title: evaluate
+    ///
+    /// Description: VRConnect shall evaluate the score from available inputs,
+    /// reporting each sub-score and listing missing inputs explicitly; the total
+    /// is only produced when every input is available.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `values` - Available vital sign values
+    ///
+    /// # Returns
+    /// Score result
+    pub fn evaluate(&self, values: &BTreeMap<VitalSign, f64>) -> ScoreResult {
+        let mut sub_scores = Vec::new();
+        let mut missing = Vec::new();
+
+        for &sign in self.inputs() {
+            match values.get(&sign) {
+                Some(value) => sub_scores.push((sign, self.sub_score(sign, *value))),
+                None => {
+                    missing.push(sign);
+                    sub_scores.push((sign, None));
+                }
+            }
+        }
+
+        let total = missing
+            .is_empty()
+            .then(|| sub_scores.iter().filter_map(|(_, points)| *points).sum());
+
+        ScoreResult {
+            total,
+            sub_scores,
+            missing,
+        }
+    }
+
+    /// ID SRS: SRS-FN-EARLYWARNING-006
+    /// Title: risk_level
+    ///
+    /// Description: VRConnect shall derive the NEWS2 clinical risk level (low,
+    /// low-medium when any single parameter scores 3, medium, high).
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `result` - Complete score result
+    ///
+    /// # Returns
+    /// Risk level, or None for MEWS or incomplete results
+    pub fn risk_level(&self, result: &ScoreResult) -> Option<&'static str> {
+        if *self != EarlyWarningScore::News2 {
+            return None;
+        }
+
+        let total = result.total?;
+        let any_three = result.sub_scores.iter().any(|(_, points)| *points == Some(3));
+
+        Some(match total {
+            t if t >= 7 => "high",
+            t if t >= 5 => "medium",
+            _ if any_three => "low-medium",
+            _ => "low",
+        })
+    }
+}
+
+impl EarlyWarningCalculator {
+    /// ID SRS: SRS-FN-EARLYWARNING-007
+    /// Title: new
+    ///
+    /// Description: VRConnect shall construct an EarlyWarningCalculator for the
+    /// configured scores, ignoring inputs older than the maximum input age.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `scores` - Scores to publish
+    /// * `max_input_age_secs` - Maximum age of an input before it counts as missing
+    ///
+    /// # Returns
+    /// New EarlyWarningCalculator instance
+    pub fn new(scores: Vec<EarlyWarningScore>, max_input_age_secs: u64) -> Self {
+        Self {
+            scores,
+            max_input_age: Duration::seconds(max_input_age_secs as i64),
+            latest: HashMap::new(),
+        }
+    }
+
+    /// ID SRS: SRS-FN-EARLYWARNING-008
+    /// Title: apply
+    ///
+    /// Description: VRConnect shall record canonical vital signs of the
+    /// processed data and append score tracks to every room whose inputs were
+    /// updated by this frame.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `data` - Processed vital data, extended in place
+    pub fn apply(&mut self, data: &mut ProcessedData) {
+        let now = data.timestamp;
+        let mut updated_rooms: Vec<(String, i32)> = Vec::new();
+
+        for track in &data.all_tracks {
+            let Some((sign, value)) = VitalSign::from_track(track) else {
+                continue;
+            };
+
+            let inputs = self
+                .latest
+                .entry((data.device_id.clone(), track.room_name.clone()))
+                .or_default();
+            inputs.room_index = track.room_index;
+            inputs.values.insert(sign, (value, now));
+
+            if !updated_rooms.iter().any(|(room, _)| *room == track.room_name) {
+                updated_rooms.push((track.room_name.clone(), track.room_index));
+            }
+        }
+
+        for (room_name, room_index) in updated_rooms {
+            let tracks = self.score_tracks(&data.device_id, &room_name, now);
+            data.add_tracks(room_index, &room_name, tracks);
+        }
+    }
+
+    /// ID SRS: SRS-FN-EARLYWARNING-009
+    /// Title: score_tracks
+    ///
+    /// Description: VRConnect shall build synthetic tracks for each configured
+    /// score: total (or "incomplete" with missing inputs), NEWS2 risk level, and
+    /// one track per sub-score ("missing" when the input is unavailable).
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `device_id` - VitalRecorder device identifier
+    /// * `room_name` - Room name
+    /// * `now` - Evaluation time
+    ///
+    /// # Returns
+    /// Synthetic score tracks
+    fn score_tracks(&self, device_id: &str, room_name: &str, now: DateTime<Utc>) -> Vec<ProcessedTrack> {
+        let Some(inputs) = self.latest.get(&(device_id.to_string(), room_name.to_string())) else {
+            return Vec::new();
+        };
+
+        let values: BTreeMap<VitalSign, f64> = inputs
+            .values
+            .iter()
+            .filter(|(_, (_, updated))| now - *updated <= self.max_input_age)
+            .map(|(sign, (value, _))| (*sign, *value))
+            .collect();
+
+        let mut tracks = Vec::new();
+        let room_index = inputs.room_index;
+
+        for score in &self.scores {
+            let result = score.evaluate(&values);
+
+            let mut total = ProcessedTrack::synthetic(
+                score.name(),
+                result.total.map(f64::from),
+                "",
+                room_index,
+                room_name,
+                now,
+            );
+            total.display_value = match result.total {
+                Some(points) => points.to_string(),
+                None => {
+                    let missing: Vec<&str> = result.missing.iter().map(|sign| sign.code()).collect();
+                    format!("incomplete (missing: {})", missing.join(", "))
+                }
+            };
+            tracks.push(total);
+
+            if let Some(risk) = score.risk_level(&result) {
+                let mut risk_track = ProcessedTrack::synthetic(
+                    &format!("{}_RISK", score.name()),
+                    None,
+                    "",
+                    room_index,
+                    room_name,
+                    now,
+                );
+                risk_track.track_type = TrackType::String;
+                risk_track.display_value = risk.to_string();
+                tracks.push(risk_track);
+            }
+
+            for (sign, points) in &result.sub_scores {
+                let mut sub_track = ProcessedTrack::synthetic(
+                    &format!("{}_{}", score.name(), sign.code()),
+                    points.map(f64::from),
+                    "",
+                    room_index,
+                    room_name,
+                    now,
+                );
+                sub_track.display_value = match points {
+                    Some(points) => points.to_string(),
+                    None => "missing".to_string(),
+                };
+                tracks.push(sub_track);
+            }
+        }
+
+        tracks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::ProcessedRoom;
+
+    fn values(entries: &[(VitalSign, f64)]) -> BTreeMap<VitalSign, f64> {
+        entries.iter().copied().collect()
+    }
+
+    fn normal_vitals() -> BTreeMap<VitalSign, f64> {
+        values(&[
+            (VitalSign::RespiratoryRate, 16.0),
+            (VitalSign::SpO2, 98.0),
+            (VitalSign::SystolicPressure, 120.0),
+            (VitalSign::HeartRate, 75.0),
+            (VitalSign::Temperature, 36.8),
+        ])
+    }
+
+    #[test]
+    fn test_parse_list() {
+        assert_eq!(
+            EarlyWarningScore::parse_list("news2, MEWS").unwrap(),
+            vec![EarlyWarningScore::News2, EarlyWarningScore::Mews]
+        );
+        assert!(EarlyWarningScore::parse_list("").unwrap().is_empty());
+        assert!(EarlyWarningScore::parse_list("qsofa").is_err());
+    }
+
+    #[test]
+    fn test_news2_normal_vitals() {
+        let result = EarlyWarningScore::News2.evaluate(&normal_vitals());
+        assert_eq!(result.total, Some(0));
+        assert!(result.missing.is_empty());
+        assert_eq!(EarlyWarningScore::News2.risk_level(&result), Some("low"));
+    }
+
+    #[test]
+    fn test_news2_deteriorating_patient() {
+        let vitals = values(&[
+            (VitalSign::RespiratoryRate, 25.0), // 3
+            (VitalSign::SpO2, 93.0),            // 2
+            (VitalSign::SystolicPressure, 105.0), // 1
+            (VitalSign::HeartRate, 115.0),      // 2
+            (VitalSign::Temperature, 38.4),     // 1
+        ]);
+
+        let result = EarlyWarningScore::News2.evaluate(&vitals);
+        assert_eq!(result.total, Some(9));
+        assert_eq!(EarlyWarningScore::News2.risk_level(&result), Some("high"));
+    }
+
+    #[test]
+    fn test_news2_single_parameter_three() {
+        let mut vitals = normal_vitals();
+        vitals.insert(VitalSign::HeartRate, 38.0);
+
+        let result = EarlyWarningScore::News2.evaluate(&vitals);
+        assert_eq!(result.total, Some(3));
+        assert_eq!(EarlyWarningScore::News2.risk_level(&result), Some("low-medium"));
+    }
+
+    #[test]
+    fn test_missing_inputs_are_explicit() {
+        let mut vitals = normal_vitals();
+        vitals.remove(&VitalSign::Temperature);
+
+        let result = EarlyWarningScore::News2.evaluate(&vitals);
+        assert_eq!(result.total, None);
+        assert_eq!(result.missing, vec![VitalSign::Temperature]);
+        assert!(result.sub_scores.contains(&(VitalSign::Temperature, None)));
+        assert_eq!(EarlyWarningScore::News2.risk_level(&result), None);
+    }
+
+    #[test]
+    fn test_mews_ignores_spo2() {
+        let mut vitals = normal_vitals();
+        vitals.remove(&VitalSign::SpO2);
+        vitals.insert(VitalSign::HeartRate, 135.0); // 3
+
+        let result = EarlyWarningScore::Mews.evaluate(&vitals);
+        assert_eq!(result.total, Some(4)); // RR 16 → 1, HR 135 → 3
+        assert!(result.sub_scores.iter().all(|(sign, _)| *sign != VitalSign::SpO2));
+    }
+
+    #[test]
+    fn test_apply_publishes_synthetic_tracks() {
+        let now = Utc::now();
+        let tracks = [
+            ("RR", 16.0),
+            ("PLETH_SPO2", 98.0),
+            ("NIBP_SBP", 120.0),
+            ("HR", 75.0),
+            ("BT", 36.8),
+        ]
+        .iter()
+        .map(|(name, value)| ProcessedTrack::synthetic(name, Some(*value), "", 0, "OR-1", now))
+        .collect();
+
+        let mut data = ProcessedData::new(
+            "VR1".to_string(),
+            vec![ProcessedRoom {
+                room_index: 0,
+                room_name: "OR-1".to_string(),
+                tracks,
+            }],
+        );
+
+        let mut calculator = EarlyWarningCalculator::new(vec![EarlyWarningScore::News2], 300);
+        calculator.apply(&mut data);
+
+        let news2 = data.all_tracks.iter().find(|t| t.name == "NEWS2").unwrap();
+        assert_eq!(news2.raw_value, Some(0.0));
+        assert_eq!(news2.display_value, "0");
+
+        let risk = data.all_tracks.iter().find(|t| t.name == "NEWS2_RISK").unwrap();
+        assert_eq!(risk.track_type, TrackType::String);
+        assert_eq!(risk.display_value, "low");
+
+        assert!(data.all_tracks.iter().any(|t| t.name == "NEWS2_TEMP"));
+        assert_eq!(data.rooms[0].tracks.len(), data.all_tracks.len());
+    }
+}
@@ -0,0 +1,406 @@
+// /src/derived/heart_rate.rs
+// Module: derived.heart_rate
+// Purpose: Heart rate, RR interval and beat markers derived from ECG waveforms
+
+use crate::domain::{ProcessedData, ProcessedTrack, TrackType};
+use crate::signal::QrsDetector;
+use chrono::{DateTime, Duration, Utc};
+use std::collections::{HashMap, VecDeque};
+
+/// Maximum gap between consecutive records before the ECG buffer is restarted (ms)
+const MAX_GAP_MS: i64 = 1000;
+
+/// Beats too close to the end of the buffer may be incomplete (s)
+const END_MARGIN_SECS: f64 = 0.25;
+
+/// Minimum distance between a new beat and the previous one (s)
+const MIN_BEAT_DISTANCE_SECS: f64 = 0.25;
+
+/// Longest RR interval accepted for the heart rate (s)
+const MAX_RR_SECS: f64 = 3.0;
+
+/// Number of recent RR intervals averaged (median) into the heart rate
+const RR_HISTORY: usize = 8;
+
+/// Idle time after which the buffer of a lead no longer received is dropped
+const STALE_LEAD_SECS: i64 = 600;
+
+/// ID SRS: SRS-MOD-HEARTRATE-001
+/// Title: HeartRateDetector
+///
+/// Description: VRConnect shall run QRS detection over a rolling window of
+/// each ECG waveform track (per device, room and lead) using the track sample
+/// rate, and publish heart rate, last RR interval and beat marker tracks.
+/// Beat markers are plain numeric tracks, so they reach BLE clients even
+/// though waveforms are excluded from BLE.
+///
+/// Version: V1.0
+pub struct HeartRateDetector {
+    window_secs: f64,
+    leads: HashMap<(String, String, String), EcgBuffer>,
+}
+
+/// Rolling ECG samples and detected beats of one lead
+#[derive(Debug, Clone)]
+struct EcgBuffer {
+    sample_rate: f64,
+    start: DateTime<Utc>,
+    samples: Vec<f64>,
+    beats: VecDeque<DateTime<Utc>>,
+    last_received: DateTime<Utc>,
+}
+
+impl EcgBuffer {
+    /// ID SRS: SRS-FN-HEARTRATE-001
+    /// Title: end
+    ///
+    /// Description: VRConnect shall compute the time just after the last
+    /// buffered sample.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Expected start time of the next record
+    fn end(&self) -> DateTime<Utc> {
+        self.start + seconds(self.samples.len() as f64 / self.sample_rate)
+    }
+}
+
+impl HeartRateDetector {
+    /// ID SRS: SRS-FN-HEARTRATE-002
+    /// Title: new
+    ///
+    /// Description: VRConnect shall construct a HeartRateDetector analysing the
+    /// last `window_secs` seconds of every ECG lead.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `window_secs` - Analysis window length in seconds
+    ///
+    /// # Returns
+    /// New HeartRateDetector instance
+    pub fn new(window_secs: u64) -> Self {
+        Self {
+            window_secs: window_secs as f64,
+            leads: HashMap::new(),
+        }
+    }
+
+    /// ID SRS: SRS-FN-HEARTRATE-003
+    /// Title: is_ecg
+    ///
+    /// Description: VRConnect shall recognise ECG leads as waveform tracks whose
+    /// name (ignoring any "Device/" prefix) starts with "ECG".
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `track` - Processed track
+    ///
+    /// # Returns
+    /// True if the track is an ECG waveform
+    pub fn is_ecg(track: &ProcessedTrack) -> bool {
+        let short_name = track.name.rsplit('/').next().unwrap_or(&track.name);
+        track.track_type == TrackType::Waveform && short_name.to_uppercase().starts_with("ECG")
+    }
+
+    /// ID SRS: SRS-FN-HEARTRATE-004
+    /// Title: apply
+    ///
+    /// Description: VRConnect shall append ECG samples of the processed data to
+    /// their lead buffers (the record timestamp being the time of the first
+    /// sample), detect new beats and append `<lead>_HR`, `<lead>_RR_INTERVAL`
+    /// and, when beats were found, `<lead>_BEAT` tracks to the lead's room.
+    /// Buffers of leads not received for 10 minutes are dropped.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `data` - Processed vital data, extended in place
+    pub fn apply(&mut self, data: &mut ProcessedData) {
+        let mut derived: Vec<(i32, String, Vec<ProcessedTrack>)> = Vec::new();
+
+        for track in data.all_tracks.iter().filter(|track| Self::is_ecg(track)) {
+            let (Some(points), Some(sample_rate)) = (&track.waveform_points, track.sample_rate) else {
+                continue;
+            };
+            if points.is_empty() || sample_rate <= 0.0 {
+                continue;
+            }
+
+            let key = (data.device_id.clone(), track.room_name.clone(), track.name.clone());
+            let buffer = self.leads.entry(key).or_insert_with(|| EcgBuffer {
+                sample_rate,
+                start: track.timestamp,
+                samples: Vec::new(),
+                beats: VecDeque::new(),
+                last_received: data.timestamp,
+            });
+
+            let gap = (track.timestamp - buffer.end()).num_milliseconds().abs();
+            if buffer.sample_rate != sample_rate || (!buffer.samples.is_empty() && gap > MAX_GAP_MS) {
+                log::debug!("Restarting ECG buffer for {} ({}ms gap)", track.name, gap);
+                *buffer = EcgBuffer {
+                    sample_rate,
+                    start: track.timestamp,
+                    samples: Vec::new(),
+                    beats: VecDeque::new(),
+                    last_received: data.timestamp,
+                };
+            }
+
+            buffer.last_received = data.timestamp;
+            buffer.samples.extend_from_slice(points);
+            Self::trim(buffer, self.window_secs);
+
+            let new_beats = Self::detect_new_beats(buffer);
+            derived.push((
+                track.room_index,
+                track.room_name.clone(),
+                Self::beat_tracks(track, buffer, &new_beats),
+            ));
+        }
+
+        for (room_index, room_name, tracks) in derived {
+            data.add_tracks(room_index, &room_name, tracks);
+        }
+
+        let received = data.timestamp;
+        self.leads
+            .retain(|_, buffer| received - buffer.last_received <= Duration::seconds(STALE_LEAD_SECS));
+    }
+
+    /// ID SRS: SRS-FN-HEARTRATE-005
+    /// Title: trim
+    ///
+    /// Description: VRConnect shall drop samples older than the analysis window,
+    /// advancing the buffer start time accordingly.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `buffer` - Lead buffer
+    /// * `window_secs` - Analysis window length in seconds
+    fn trim(buffer: &mut EcgBuffer, window_secs: f64) {
+        let capacity = (window_secs * buffer.sample_rate).ceil() as usize;
+        if buffer.samples.len() > capacity {
+            let excess = buffer.samples.len() - capacity;
+            buffer.samples.drain(..excess);
+            buffer.start += seconds(excess as f64 / buffer.sample_rate);
+        }
+    }
+
+    /// ID SRS: SRS-FN-HEARTRATE-006
+    /// Title: detect_new_beats
+    ///
+    /// Description: VRConnect shall run QRS detection on the buffer and keep
+    /// beats that are complete and later than the last known beat.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `buffer` - Lead buffer
+    ///
+    /// # Returns
+    /// Times of newly detected beats
+    fn detect_new_beats(buffer: &mut EcgBuffer) -> Vec<DateTime<Utc>> {
+        let detector = QrsDetector::new(buffer.sample_rate);
+        let complete = buffer.samples.len() as f64 - END_MARGIN_SECS * buffer.sample_rate;
+        let last_beat = buffer.beats.back().copied();
+        let mut new_beats = Vec::new();
+
+        for index in detector.detect(&buffer.samples) {
+            if index as f64 >= complete {
+                break;
+            }
+
+            let time = buffer.start + seconds(index as f64 / buffer.sample_rate);
+            if last_beat.is_none_or(|last| time - last > seconds(MIN_BEAT_DISTANCE_SECS)) {
+                new_beats.push(time);
+                buffer.beats.push_back(time);
+            }
+        }
+
+        while buffer.beats.len() > RR_HISTORY + 1 {
+            buffer.beats.pop_front();
+        }
+
+        new_beats
+    }
+
+    /// ID SRS: SRS-FN-HEARTRATE-007
+    /// Title: rr_intervals
+    ///
+    /// Description: VRConnect shall compute the recent RR intervals in seconds,
+    /// ignoring intervals longer than a plausible heart period.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `buffer` - Lead buffer
+    ///
+    /// # Returns
+    /// RR intervals, oldest first
+    fn rr_intervals(buffer: &EcgBuffer) -> Vec<f64> {
+        buffer
+            .beats
+            .iter()
+            .zip(buffer.beats.iter().skip(1))
+            .map(|(a, b)| (*b - *a).num_milliseconds() as f64 / 1000.0)
+            .filter(|rr| *rr <= MAX_RR_SECS)
+            .collect()
+    }
+
+    /// ID SRS: SRS-FN-HEARTRATE-008
+    /// Title: beat_tracks
+    ///
+    /// Description: VRConnect shall build the heart rate (60 / median RR),
+    /// last RR interval and beat marker tracks of one lead.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `lead` - Source ECG track
+    /// * `buffer` - Lead buffer
+    /// * `new_beats` - Beats detected in this frame
+    ///
+    /// # Returns
+    /// Synthetic tracks
+    fn beat_tracks(lead: &ProcessedTrack, buffer: &EcgBuffer, new_beats: &[DateTime<Utc>]) -> Vec<ProcessedTrack> {
+        let intervals = Self::rr_intervals(buffer);
+        let timestamp = buffer.beats.back().copied().unwrap_or(lead.timestamp);
+
+        let heart_rate = (!intervals.is_empty()).then(|| {
+            let mut sorted = intervals.clone();
+            sorted.sort_by(f64::total_cmp);
+            let mid = sorted.len() / 2;
+            let median = if sorted.len().is_multiple_of(2) {
+                (sorted[mid - 1] + sorted[mid]) / 2.0
+            } else {
+                sorted[mid]
+            };
+            60.0 / median
+        });
+
+        let mut tracks = vec![
+            ProcessedTrack::synthetic(
+                &format!("{}_HR", lead.name),
+                heart_rate.map(f64::round),
+                "bpm",
+                lead.room_index,
+                &lead.room_name,
+                timestamp,
+            ),
+            ProcessedTrack::synthetic(
+                &format!("{}_RR_INTERVAL", lead.name),
+                intervals.last().map(|rr| (rr * 1000.0).round()),
+                "ms",
+                lead.room_index,
+                &lead.room_name,
+                timestamp,
+            ),
+        ];
+
+        if let Some(last) = new_beats.last() {
+            let mut beat = ProcessedTrack::synthetic(
+                &format!("{}_BEAT", lead.name),
+                Some(new_beats.len() as f64),
+                "beats",
+                lead.room_index,
+                &lead.room_name,
+                *last,
+            );
+            beat.display_value = new_beats.len().to_string();
+            tracks.push(beat);
+        }
+
+        tracks
+    }
+}
+
+/// Convert fractional seconds to a chrono Duration (microsecond precision)
+fn seconds(secs: f64) -> Duration {
+    Duration::microseconds((secs * 1_000_000.0).round() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::ProcessedRoom;
+    use crate::signal::qrs::tests::synthetic_ecg;
+    use chrono::TimeZone;
+
+    fn ecg_frame(points: Vec<f64>, start: DateTime<Utc>) -> ProcessedData {
+        let mut track = ProcessedTrack::synthetic("Monitor/ECG_II", None, "mV", 0, "OR-1", start);
+        track.track_type = TrackType::Waveform;
+        track.waveform_points = Some(points);
+        track.sample_rate = Some(250.0);
+
+        ProcessedData::new(
+            "VR1".to_string(),
+            vec![ProcessedRoom {
+                room_index: 0,
+                room_name: "OR-1".to_string(),
+                tracks: vec![track],
+            }],
+        )
+    }
+
+    fn find<'a>(data: &'a ProcessedData, name: &str) -> Option<&'a ProcessedTrack> {
+        data.all_tracks.iter().find(|track| track.name == name)
+    }
+
+    #[test]
+    fn test_heart_rate_across_records() {
+        let mut detector = HeartRateDetector::new(10);
+        let ecg = synthetic_ecg(250.0, 12.0, 0.8, 0.4);
+        let start = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+        let mut beats = 0;
+
+        // One-second records, as sent by VitalRecorder
+        for (second, chunk) in ecg.chunks(250).enumerate() {
+            let mut data = ecg_frame(chunk.to_vec(), start + Duration::seconds(second as i64));
+            detector.apply(&mut data);
+
+            if let Some(beat) = find(&data, "Monitor/ECG_II_BEAT") {
+                beats += beat.raw_value.unwrap() as usize;
+            }
+            if second == 11 {
+                assert_eq!(find(&data, "Monitor/ECG_II_HR").unwrap().raw_value, Some(75.0));
+                assert_eq!(find(&data, "Monitor/ECG_II_RR_INTERVAL").unwrap().raw_value, Some(800.0));
+            }
+        }
+
+        // No beat counted twice although windows overlap
+        assert!((13..=15).contains(&beats), "counted {} beats", beats);
+    }
+
+    #[test]
+    fn test_non_ecg_waveform_ignored() {
+        let mut detector = HeartRateDetector::new(10);
+        let mut data = ecg_frame(vec![0.0; 250], Utc::now());
+        data.all_tracks[0].name = "Monitor/PLETH".to_string();
+        detector.apply(&mut data);
+        assert_eq!(data.all_tracks.len(), 1);
+    }
+
+    #[test]
+    fn test_idle_leads_dropped() {
+        let mut detector = HeartRateDetector::new(10);
+        let start = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+        let mut data = ecg_frame(vec![0.0; 250], start);
+        data.timestamp = start;
+        detector.apply(&mut data);
+        assert_eq!(detector.leads.len(), 1);
+
+        // Another device keeps sending after the first one left
+        let later = start + Duration::seconds(STALE_LEAD_SECS + 1);
+        let mut data = ecg_frame(vec![0.0; 250], later);
+        data.device_id = "VR2".to_string();
+        data.timestamp = later;
+        detector.apply(&mut data);
+        assert_eq!(detector.leads.len(), 1);
+        assert!(detector.leads.keys().all(|(device_id, _, _)| device_id == "VR2"));
+    }
+}
@@ -0,0 +1,9 @@
+// /src/derived/mod.rs
+// Module: derived
+// Purpose: Derived metrics computed from processed tracks
+
+pub mod early_warning;
+pub mod heart_rate;
+
+pub use early_warning::{EarlyWarningCalculator, EarlyWarningScore};
+pub use heart_rate::HeartRateDetector;
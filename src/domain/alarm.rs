@@ -0,0 +1,150 @@
+// /src/domain/alarm.rs
+// Module: domain.alarm
+// Purpose: Clinical alarm event structures dispatched to outputs
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// ID SRS: SRS-MOD-ALARMEVENT-001
+/// Title: AlarmEvent
+///
+/// Description: VRConnect shall define alarm event structures describing
+/// raise, clear, escalation, acknowledgment and silencing of clinical alarms
+/// and technical conditions (stale data, sensor off, clock drift) per device,
+/// room and parameter.
+///
+/// Version: V1.0
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlarmEvent {
+    pub alarm_id: String,
+    pub device_id: String,
+    pub room_name: String,
+    pub parameter: String,
+    pub condition: AlarmCondition,
+    pub action: AlarmAction,
+    pub priority: AlarmPriority,
+    pub value: Option<f64>,
+    pub limit: Option<f64>,
+    pub silenced: bool,
+    pub message: String,
+    #[serde(with = "chrono::serde::ts_milliseconds")]
+    pub timestamp: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlarmCondition {
+    Low,
+    High,
+    DataStale,
+    SensorOff,
+    ClockDrift,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AlarmAction {
+    Raised,
+    Cleared,
+    Escalated,
+    Acknowledged,
+    Silenced,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AlarmPriority {
+    Low,
+    Medium,
+    High,
+}
+
+impl AlarmCondition {
+    /// ID SRS: SRS-FN-ALARMCONDITION-001
+    /// Title: label
+    ///
+    /// Description: VRConnect shall provide a display label for an alarm condition.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Upper-case condition label
+    pub fn label(&self) -> &'static str {
+        match self {
+            AlarmCondition::Low => "LOW",
+            AlarmCondition::High => "HIGH",
+            AlarmCondition::DataStale => "DATA STALE",
+            AlarmCondition::SensorOff => "SENSOR OFF",
+            AlarmCondition::ClockDrift => "CLOCK DRIFT",
+        }
+    }
+}
+
+impl AlarmPriority {
+    /// ID SRS: SRS-FN-ALARMPRIORITY-001
+    /// Title: escalated
+    ///
+    /// Description: VRConnect shall return the next higher alarm priority,
+    /// saturating at High.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Escalated priority
+    pub fn escalated(self) -> Self {
+        match self {
+            AlarmPriority::Low => AlarmPriority::Medium,
+            AlarmPriority::Medium | AlarmPriority::High => AlarmPriority::High,
+        }
+    }
+}
+
+impl AlarmEvent {
+    /// ID SRS: SRS-FN-ALARMEVENT-001
+    /// Title: alarm_id_for
+    ///
+    /// Description: VRConnect shall build a stable alarm identifier from
+    /// device, room and parameter, used for acknowledgment.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `device_id` - VitalRecorder device identifier
+    /// * `room_name` - Room name
+    /// * `parameter` - Monitored parameter name
+    ///
+    /// # Returns
+    /// Alarm identifier string
+    pub fn alarm_id_for(device_id: &str, room_name: &str, parameter: &str) -> String {
+        format!("{}/{}/{}", device_id, room_name, parameter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_priority_escalation() {
+        assert_eq!(AlarmPriority::Low.escalated(), AlarmPriority::Medium);
+        assert_eq!(AlarmPriority::Medium.escalated(), AlarmPriority::High);
+        assert_eq!(AlarmPriority::High.escalated(), AlarmPriority::High);
+        assert!(AlarmPriority::High > AlarmPriority::Low);
+    }
+
+    #[test]
+    fn test_condition_serialization() {
+        assert_eq!(
+            serde_json::to_string(&AlarmCondition::DataStale).unwrap(),
+            "\"data_stale\""
+        );
+        assert_eq!(serde_json::to_string(&AlarmCondition::High).unwrap(), "\"high\"");
+        assert_eq!(AlarmCondition::SensorOff.label(), "SENSOR OFF");
+    }
+
+    #[test]
+    fn test_alarm_id_for() {
+        assert_eq!(AlarmEvent::alarm_id_for("VR1", "OR-3", "HR"), "VR1/OR-3/HR");
+    }
+}
@@ -2,8 +2,12 @@
 // Module: domain
 // Purpose: Domain models for vital data structures
 
+pub mod alarm;
 pub mod vital_data;
 pub mod processed_data;
+pub mod vital_sign;
 
+pub use alarm::*;
 pub use vital_data::*;
 pub use processed_data::*;
+pub use vital_sign::*;
@@ -32,6 +32,7 @@ pub struct ProcessedRoom {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProcessedTrack {
     pub name: String,
+    pub track_id: Option<String>,
     pub display_value: String,
     pub raw_value: Option<f64>,
     pub unit: String,
@@ -44,6 +45,18 @@ pub struct ProcessedTrack {
     pub track_type: TrackType,
     pub waveform_stats: Option<WaveformStats>,
     pub waveform_points: Option<Vec<f64>>,
+    pub sample_rate: Option<f64>,
+    pub components: Option<Vec<ValueComponent>>,
+    pub quality: Option<SignalQuality>,
+    pub trend: Option<Trend>,
+    pub timestamp_flag: Option<TimestampFlag>,
+    /// Original device timestamp when `timestamp` was corrected to the local clock
+    #[serde(with = "chrono::serde::ts_milliseconds_option")]
+    pub device_timestamp: Option<DateTime<Utc>>,
+    /// Time since the track was received, set when rendered from a snapshot
+    pub age_ms: Option<u64>,
+    /// Set by the watchdog when the value is no longer current
+    pub signal_status: Option<SignalStatus>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -61,6 +74,120 @@ pub struct WaveformStats {
     pub max: f64,
     pub avg: f64,
     pub count: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extended: Option<ExtendedWaveformStats>,
+}
+
+/// ID SRS: SRS-MOD-EXTENDEDSTATS-001
+/// Title: ExtendedWaveformStats
+///
+/// Description: VRConnect shall define optional extended statistics of a
+/// waveform segment for display auto-scaling and analytics.
+///
+/// Version: V1.0
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExtendedWaveformStats {
+    pub std_dev: f64,
+    pub rms: f64,
+    pub peak_to_peak: f64,
+    pub percentiles: Vec<Percentile>,
+    /// Mean crossings per second (per sample if the sample rate is unknown)
+    pub zero_crossing_rate: f64,
+    /// Strongest non-DC frequency in Hz, only with a known sample rate
+    pub dominant_frequency: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Percentile {
+    pub percentile: f64,
+    pub value: f64,
+}
+
+/// ID SRS: SRS-MOD-SIGNALQUALITY-001
+/// Title: SignalQuality
+///
+/// Description: VRConnect shall attach to a track a quality score between 0
+/// (unusable) and 1 (clean) and the artifacts detected in its segment or value.
+///
+/// Version: V1.0
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SignalQuality {
+    pub score: f64,
+    pub artifacts: Vec<ArtifactFlag>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArtifactFlag {
+    FlatLine,
+    Clipping,
+    Noise,
+    OutOfRange,
+    Jump,
+}
+
+/// ID SRS: SRS-MOD-TREND-001
+/// Title: Trend
+///
+/// Description: VRConnect shall attach to a numeric track its trend over the
+/// configured rolling window: direction, moving average, least-squares slope,
+/// window min/max and rate of change since the previous value.
+///
+/// Version: V1.0
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Trend {
+    pub direction: TrendDirection,
+    pub moving_average: f64,
+    /// Least-squares slope over the window, in units per minute
+    pub slope_per_min: f64,
+    pub min: f64,
+    pub max: f64,
+    /// Change from the previous value, in units per minute
+    pub rate_of_change_per_min: Option<f64>,
+    pub samples: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TrendDirection {
+    Rising,
+    Falling,
+    Stable,
+}
+
+/// ID SRS: SRS-MOD-TIMESTAMPFLAG-001
+/// Title: TimestampFlag
+///
+/// Description: VRConnect shall flag a record whose timestamp is missing (the
+/// receive time is used instead) or far from the receive time.
+///
+/// Version: V1.0
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimestampFlag {
+    Missing,
+    Skewed,
+}
+
+/// ID SRS: SRS-MOD-SIGNALSTATUS-001
+/// Title: SignalStatus
+///
+/// Description: VRConnect shall flag a track whose value is no longer
+/// current: its device stopped sending (stale) or the track stopped updating
+/// while the device continues (sensor off).
+///
+/// Version: V1.0
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SignalStatus {
+    Stale,
+    SensorOff,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValueComponent {
+    pub name: String,
+    pub value: f64,
 }
 
 impl ProcessedData {
@@ -108,9 +235,110 @@ impl ProcessedData {
             .filter(|track| track.track_type != TrackType::Waveform)
             .collect()
     }
+
+    /// ID SRS: SRS-FN-PROCESSEDDATA-004
+    /// Title: refresh_all_tracks
+    ///
+    /// Description: VRConnect shall rebuild the flattened track list from the
+    /// rooms after tracks were modified in place.
+    ///
+    /// Version: V1.0
+    pub fn refresh_all_tracks(&mut self) {
+        self.all_tracks = self
+            .rooms
+            .iter()
+            .flat_map(|room| room.tracks.clone())
+            .collect();
+    }
+
+    /// ID SRS: SRS-FN-PROCESSEDDATA-003
+    /// Title: add_tracks
+    ///
+    /// Description: VRConnect shall append derived tracks to a room (creating
+    /// the room if needed) and to the flattened track list.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `room_index` - Target room index
+    /// * `room_name` - Target room name
+    /// * `tracks` - Tracks to append
+    pub fn add_tracks(&mut self, room_index: i32, room_name: &str, tracks: Vec<ProcessedTrack>) {
+        if tracks.is_empty() {
+            return;
+        }
+
+        self.all_tracks.extend(tracks.iter().cloned());
+
+        match self.rooms.iter_mut().find(|room| room.room_index == room_index) {
+            Some(room) => room.tracks.extend(tracks),
+            None => self.rooms.push(ProcessedRoom {
+                room_index,
+                room_name: room_name.to_string(),
+                tracks,
+            }),
+        }
+    }
 }
 
 impl ProcessedTrack {
+    /// ID SRS: SRS-FN-PROCESSEDTRACK-002
+    /// Title: synthetic
+    ///
+    /// Description: VRConnect shall construct a derived (synthetic) track not
+    /// received from the monitor: Number type when a value is given, Other type
+    /// with "N/A" display otherwise. Synthetic tracks use track index -1.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `name` - Track name
+    /// * `raw_value` - Optional numeric value
+    /// * `unit` - Unit string
+    /// * `room_index` - Room index
+    /// * `room_name` - Room name
+    /// * `timestamp` - Track timestamp
+    ///
+    /// # Returns
+    /// New synthetic ProcessedTrack
+    pub fn synthetic(
+        name: &str,
+        raw_value: Option<f64>,
+        unit: &str,
+        room_index: i32,
+        room_name: &str,
+        timestamp: DateTime<Utc>,
+    ) -> Self {
+        let (track_type, display_value) = match raw_value {
+            Some(value) => (TrackType::Number, format!("{:.3}", value)),
+            None => (TrackType::Other, "N/A".to_string()),
+        };
+
+        Self {
+            name: name.to_string(),
+            track_id: None,
+            display_value,
+            raw_value,
+            unit: unit.to_string(),
+            timestamp,
+            room_index,
+            room_name: room_name.to_string(),
+            track_index: -1,
+            record_index: 0,
+            track_type,
+            waveform_stats: None,
+            waveform_points: None,
+            sample_rate: None,
+            components: None,
+            quality: None,
+            trend: None,
+            timestamp_flag: None,
+            device_timestamp: None,
+            age_ms: None,
+            signal_status: None,
+        }
+    }
+
     /// ID SRS: SRS-FN-PROCESSEDTRACK-001
     /// Title: is_waveform
     ///
@@ -120,12 +348,163 @@ impl ProcessedTrack {
     ///
     /// # Returns
     /// True if track is waveform type
-    #[allow(dead_code)]
     pub fn is_waveform(&self) -> bool {
         self.track_type == TrackType::Waveform
     }
 }
 
+impl WaveformStats {
+    /// ID SRS: SRS-FN-WAVEFORMSTATS-001
+    /// Title: from_points
+    ///
+    /// Description: VRConnect shall compute waveform statistics (min, max, avg,
+    /// count) from waveform points.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `points` - Waveform points
+    ///
+    /// # Returns
+    /// Statistics, or None if there are no points
+    pub fn from_points(points: &[f64]) -> Option<Self> {
+        if points.is_empty() {
+            return None;
+        }
+
+        let min = points.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = points.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let sum: f64 = points.iter().sum();
+
+        Some(Self {
+            min,
+            max,
+            avg: sum / points.len() as f64,
+            count: points.len(),
+            extended: None,
+        })
+    }
+
+    /// ID SRS: SRS-FN-WAVEFORMSTATS-002
+    /// Title: describe
+    ///
+    /// Description: VRConnect shall format waveform statistics as the display
+    /// value of a waveform track.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Display string, e.g. "500 points (-0.100 to 1.200, avg: 0.050)"
+    pub fn describe(&self) -> String {
+        format!(
+            "{} points ({:.3} to {:.3}, avg: {:.3})",
+            self.count, self.min, self.max, self.avg
+        )
+    }
+}
+
+impl SignalQuality {
+    /// ID SRS: SRS-FN-SIGNALQUALITY-001
+    /// Title: is_reliable
+    ///
+    /// Description: VRConnect shall determine whether a track's quality reaches
+    /// a minimum score.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `min_score` - Minimum acceptable score
+    ///
+    /// # Returns
+    /// True if the score is at least the minimum
+    pub fn is_reliable(&self, min_score: f64) -> bool {
+        self.score >= min_score
+    }
+}
+
+impl ArtifactFlag {
+    /// ID SRS: SRS-FN-ARTIFACTFLAG-001
+    /// Title: label
+    ///
+    /// Description: VRConnect shall provide a display label for an artifact flag.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Lower-case artifact label
+    pub fn label(&self) -> &'static str {
+        match self {
+            ArtifactFlag::FlatLine => "flat line",
+            ArtifactFlag::Clipping => "clipping",
+            ArtifactFlag::Noise => "noise",
+            ArtifactFlag::OutOfRange => "out of range",
+            ArtifactFlag::Jump => "jump",
+        }
+    }
+}
+
+impl TrendDirection {
+    /// ID SRS: SRS-FN-TRENDDIRECTION-001
+    /// Title: arrow
+    ///
+    /// Description: VRConnect shall provide an arrow symbol for a trend
+    /// direction, shown next to the current value.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Arrow symbol
+    pub fn arrow(&self) -> &'static str {
+        match self {
+            TrendDirection::Rising => "↑",
+            TrendDirection::Falling => "↓",
+            TrendDirection::Stable => "→",
+        }
+    }
+}
+
+impl SignalStatus {
+    /// ID SRS: SRS-FN-SIGNALSTATUS-001
+    /// Title: label
+    ///
+    /// Description: VRConnect shall provide a display label for a signal
+    /// status.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Upper-case status label
+    pub fn label(&self) -> &'static str {
+        match self {
+            SignalStatus::Stale => "STALE",
+            SignalStatus::SensorOff => "SENSOR OFF",
+        }
+    }
+}
+
+impl ValueComponent {
+    /// ID SRS: SRS-FN-VALUECOMPONENT-001
+    /// Title: new
+    ///
+    /// Description: VRConnect shall construct a named numeric component
+    /// extracted from a composite value (e.g. systolic pressure).
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `name` - Component name (systolic, diastolic, mean)
+    /// * `value` - Numeric value
+    ///
+    /// # Returns
+    /// New ValueComponent instance
+    pub fn new(name: &str, value: f64) -> Self {
+        Self {
+            name: name.to_string(),
+            value,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
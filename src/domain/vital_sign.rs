@@ -0,0 +1,185 @@
+// /src/domain/vital_sign.rs
+// Module: domain.vital_sign
+// Purpose: Canonical vital sign identification from monitor track names
+
+use crate::domain::{ProcessedTrack, TrackType};
+use serde::{Deserialize, Serialize};
+
+/// ID SRS: SRS-MOD-VITALSIGN-001
+/// Title: VitalSign
+///
+/// Description: VRConnect shall map monitor-specific track names (e.g.
+/// "Solar8000/PLETH_SPO2", "NIBP_SBP") to canonical vital signs used by
+/// derived metrics.
+///
+/// Version: V1.0
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum VitalSign {
+    HeartRate,
+    RespiratoryRate,
+    SpO2,
+    SystolicPressure,
+    Temperature,
+}
+
+/// Composite pressure tracks whose "systolic" component is a systolic pressure
+const PRESSURE_TRACKS: &[&str] = &["NIBP", "ART", "ABP", "BP"];
+
+impl VitalSign {
+    /// ID SRS: SRS-FN-VITALSIGN-001
+    /// Title: code
+    ///
+    /// Description: VRConnect shall provide a short code for a vital sign, used
+    /// in synthetic track names and messages.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Short upper-case code
+    pub fn code(&self) -> &'static str {
+        match self {
+            VitalSign::HeartRate => "HR",
+            VitalSign::RespiratoryRate => "RR",
+            VitalSign::SpO2 => "SPO2",
+            VitalSign::SystolicPressure => "SBP",
+            VitalSign::Temperature => "TEMP",
+        }
+    }
+
+    /// ID SRS: SRS-FN-VITALSIGN-002
+    /// Title: aliases
+    ///
+    /// Description: VRConnect shall list the numeric track names recognised for
+    /// a vital sign.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Upper-case track name aliases
+    pub fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            VitalSign::HeartRate => &["HR", "ECG_HR", "PLETH_HR", "PULSE", "PR"],
+            VitalSign::RespiratoryRate => &["RR", "RESP", "RR_CO2", "CO2_RR", "AWRR"],
+            VitalSign::SpO2 => &["SPO2", "PLETH_SPO2", "SAT_O2", "PLETH_SAT_O2"],
+            VitalSign::SystolicPressure => &["NIBP_SBP", "ART_SBP", "ABP_SBP", "SBP"],
+            VitalSign::Temperature => &["BT", "TEMP", "TEMP1", "T1", "TEMPERATURE"],
+        }
+    }
+
+    /// ID SRS: SRS-FN-VITALSIGN-004
+    /// Title: plausible_range
+    ///
+    /// Description: VRConnect shall provide the physiologically plausible range
+    /// of a vital sign; values outside are measurement artifacts.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Tuple: (minimum, maximum)
+    pub fn plausible_range(&self) -> (f64, f64) {
+        match self {
+            VitalSign::HeartRate => (20.0, 300.0),
+            VitalSign::RespiratoryRate => (0.0, 80.0),
+            VitalSign::SpO2 => (40.0, 100.0),
+            VitalSign::SystolicPressure => (30.0, 300.0),
+            VitalSign::Temperature => (25.0, 45.0),
+        }
+    }
+
+    /// ID SRS: SRS-FN-VITALSIGN-005
+    /// Title: max_jump
+    ///
+    /// Description: VRConnect shall provide the largest plausible change of a
+    /// vital sign between two consecutive values.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Maximum absolute change
+    pub fn max_jump(&self) -> f64 {
+        match self {
+            VitalSign::HeartRate => 50.0,
+            VitalSign::RespiratoryRate => 20.0,
+            VitalSign::SpO2 => 15.0,
+            VitalSign::SystolicPressure => 60.0,
+            VitalSign::Temperature => 2.0,
+        }
+    }
+
+    /// ID SRS: SRS-FN-VITALSIGN-003
+    /// Title: from_track
+    ///
+    /// Description: VRConnect shall identify the vital sign carried by a track
+    /// and extract its value: numeric tracks by name alias (ignoring any
+    /// "Device/" prefix), composite pressure tracks by their systolic component.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `track` - Processed track
+    ///
+    /// # Returns
+    /// Vital sign and value, or None if the track is not recognised
+    pub fn from_track(track: &ProcessedTrack) -> Option<(VitalSign, f64)> {
+        let short_name = track
+            .name
+            .rsplit('/')
+            .next()
+            .unwrap_or(&track.name)
+            .to_uppercase();
+
+        match track.track_type {
+            TrackType::Number => {
+                let value = track.raw_value?;
+                [
+                    VitalSign::HeartRate,
+                    VitalSign::RespiratoryRate,
+                    VitalSign::SpO2,
+                    VitalSign::SystolicPressure,
+                    VitalSign::Temperature,
+                ]
+                .into_iter()
+                .find(|sign| sign.aliases().contains(&short_name.as_str()))
+                .map(|sign| (sign, value))
+            }
+            TrackType::String if PRESSURE_TRACKS.contains(&short_name.as_str()) => track
+                .components
+                .as_ref()?
+                .iter()
+                .find(|c| c.name == "systolic")
+                .map(|c| (VitalSign::SystolicPressure, c.value)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::ValueComponent;
+    use chrono::Utc;
+
+    #[test]
+    fn test_from_track_numeric_alias() {
+        let track = ProcessedTrack::synthetic("Solar8000/pleth_spo2", Some(97.0), "", 0, "OR-1", Utc::now());
+        assert_eq!(VitalSign::from_track(&track), Some((VitalSign::SpO2, 97.0)));
+
+        let unknown = ProcessedTrack::synthetic("ST_II", Some(0.1), "mm", 0, "OR-1", Utc::now());
+        assert_eq!(VitalSign::from_track(&unknown), None);
+    }
+
+    #[test]
+    fn test_from_track_composite_pressure() {
+        let mut track = ProcessedTrack::synthetic("NIBP", None, "mmHg", 0, "OR-1", Utc::now());
+        track.track_type = TrackType::String;
+        track.components = Some(vec![
+            ValueComponent::new("systolic", 118.0),
+            ValueComponent::new("diastolic", 76.0),
+        ]);
+
+        assert_eq!(
+            VitalSign::from_track(&track),
+            Some((VitalSign::SystolicPressure, 118.0))
+        );
+    }
+}
@@ -0,0 +1,616 @@
+// /src/ffi/api.rs
+// Module: ffi.api
+// Purpose: C functions creating, feeding and polling an embedded processor
+
+use crate::config::Config;
+use crate::core::VitalProcessor;
+use crate::ffi::bridge::{lock, FfiShared, FfiSink, FrameInput, Registered};
+use crate::ffi::types::{VrcEvent, VrcEventCallback, VrcStatus, VrcValue, VrcValueCallback, VrcWaveform, VrcWaveformCallback};
+use crate::pipeline::RawPipeline;
+use clap::Parser;
+use std::cell::RefCell;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int, c_void};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+
+/// Frames fed but not yet processed, beyond which feeding fails
+const FRAME_QUEUE_CAPACITY: usize = 256;
+
+thread_local! {
+    static LAST_ERROR: RefCell<CString> = RefCell::new(CString::default());
+}
+
+/// Record the message returned by `vrc_last_error` on this thread
+fn set_last_error(message: impl Into<String>) {
+    let message = CString::new(message.into().replace('\0', " ")).unwrap_or_default();
+    LAST_ERROR.with(|last| *last.borrow_mut() = message);
+}
+
+/// Processor running on its own thread
+struct Running {
+    frames: mpsc::Sender<Vec<u8>>,
+    shutdown: CancellationToken,
+    thread: JoinHandle<Result<(), String>>,
+}
+
+/// ID SRS: SRS-MOD-FFIAPI-001
+/// Title: VrcProcessor
+///
+/// Description: VRConnect shall expose an embedded processor to C callers as
+/// an opaque handle.
+///
+/// Version: V1.0
+pub struct VrcProcessor {
+    config: Config,
+    shared: Arc<FfiShared>,
+    running: Option<Running>,
+}
+
+/// ID SRS: SRS-FN-FFIAPI-001
+/// Title: vrc_create
+///
+/// Description: VRConnect shall create a processor from command-line style
+/// arguments (same options and environment variables as the executable).
+///
+/// Version: V1.0
+///
+/// # Arguments
+/// * `argc` - Number of arguments, 0 for the defaults
+/// * `argv` - Arguments, without the program name (may be NULL if argc is 0)
+///
+/// # Returns
+/// Processor handle, or NULL on invalid arguments
+///
+/// # Safety
+/// `argv` must point to `argc` NUL-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn vrc_create(argc: c_int, argv: *const *const c_char) -> *mut VrcProcessor {
+    let mut args = vec!["vrconnect".to_string()];
+    if argc > 0 {
+        if argv.is_null() {
+            set_last_error("argv is NULL");
+            return std::ptr::null_mut();
+        }
+        for i in 0..argc as usize {
+            let arg = *argv.add(i);
+            if arg.is_null() {
+                set_last_error(format!("argv[{}] is NULL", i));
+                return std::ptr::null_mut();
+            }
+            args.push(CStr::from_ptr(arg).to_string_lossy().into_owned());
+        }
+    }
+
+    let config = match Config::try_parse_from(args) {
+        Ok(config) => config,
+        Err(e) => {
+            set_last_error(e.to_string());
+            return std::ptr::null_mut();
+        }
+    };
+    if let Err(e) = config.validate() {
+        set_last_error(e);
+        return std::ptr::null_mut();
+    }
+
+    Box::into_raw(Box::new(VrcProcessor {
+        config,
+        shared: Arc::new(FfiShared::default()),
+        running: None,
+    }))
+}
+
+/// ID SRS: SRS-FN-FFIAPI-002
+/// Title: vrc_start
+///
+/// Description: VRConnect shall start the processor on a background thread,
+/// receiving fed frames and, if a port is given, Socket.IO connections.
+///
+/// Version: V1.0
+///
+/// # Arguments
+/// * `processor` - Processor handle
+/// * `port` - Socket.IO port to listen on, 0 to only receive fed frames
+///
+/// # Returns
+/// Status code
+///
+/// # Safety
+/// `processor` must be a handle returned by `vrc_create`.
+#[no_mangle]
+pub unsafe extern "C" fn vrc_start(processor: *mut VrcProcessor, port: u16) -> VrcStatus {
+    let Some(processor) = processor.as_mut() else {
+        set_last_error("processor is NULL");
+        return VrcStatus::InvalidArgument;
+    };
+    if processor.running.is_some() {
+        set_last_error("processor already running");
+        return VrcStatus::AlreadyRunning;
+    }
+
+    let mut config = processor.config.clone();
+    if port != 0 {
+        config.socketio_port = port;
+    }
+
+    let (frames_tx, frames_rx) = mpsc::channel(FRAME_QUEUE_CAPACITY);
+    let built = VitalProcessor::builder(config)
+        .with_sink(Arc::new(FfiSink::new(processor.shared.clone())))
+        .with_input(Arc::new(FrameInput::new(frames_rx, RawPipeline::new())))
+        .with_signal_handling(false)
+        .with_socketio(port != 0)
+        .build();
+    let shutdown = built.shutdown_token();
+
+    let runtime = match tokio::runtime::Builder::new_multi_thread().enable_all().build() {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            set_last_error(format!("Failed to create runtime: {}", e));
+            return VrcStatus::Failed;
+        }
+    };
+    let thread = std::thread::spawn(move || {
+        runtime.block_on(built.run()).map_err(|e| {
+            log::error!("Embedded processor stopped: {}", e);
+            e.to_string()
+        })
+    });
+
+    processor.running = Some(Running {
+        frames: frames_tx,
+        shutdown,
+        thread,
+    });
+    VrcStatus::Ok
+}
+
+/// ID SRS: SRS-FN-FFIAPI-003
+/// Title: vrc_stop
+///
+/// Description: VRConnect shall stop a running processor, draining
+/// in-flight data within the shutdown timeout, and report an error that
+/// stopped it earlier.
+///
+/// Version: V1.0
+///
+/// # Arguments
+/// * `processor` - Processor handle
+///
+/// # Returns
+/// Status code (Failed with the last error set if the processor stopped on
+/// an error)
+///
+/// # Safety
+/// `processor` must be a handle returned by `vrc_create`.
+#[no_mangle]
+pub unsafe extern "C" fn vrc_stop(processor: *mut VrcProcessor) -> VrcStatus {
+    let Some(processor) = processor.as_mut() else {
+        set_last_error("processor is NULL");
+        return VrcStatus::InvalidArgument;
+    };
+    let Some(running) = processor.running.take() else {
+        set_last_error("processor not running");
+        return VrcStatus::NotRunning;
+    };
+
+    running.shutdown.cancel();
+    drop(running.frames);
+    match running.thread.join() {
+        Ok(Ok(())) => VrcStatus::Ok,
+        Ok(Err(e)) => {
+            set_last_error(e);
+            VrcStatus::Failed
+        }
+        Err(_) => {
+            set_last_error("processor thread panicked");
+            VrcStatus::Failed
+        }
+    }
+}
+
+/// ID SRS: SRS-FN-FFIAPI-004
+/// Title: vrc_destroy
+///
+/// Description: VRConnect shall stop the processor if running and release
+/// its handle.
+///
+/// Version: V1.0
+///
+/// # Arguments
+/// * `processor` - Processor handle (NULL is ignored)
+///
+/// # Safety
+/// `processor` must be a handle returned by `vrc_create`, not used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn vrc_destroy(processor: *mut VrcProcessor) {
+    if processor.is_null() {
+        return;
+    }
+    if (*processor).running.is_some() {
+        vrc_stop(processor);
+    }
+    drop(Box::from_raw(processor));
+}
+
+/// ID SRS: SRS-FN-FFIAPI-005
+/// Title: vrc_feed_frame
+///
+/// Description: VRConnect shall queue a raw frame (JSON, compressed or not,
+/// as sent by VitalRecorder) for processing.
+///
+/// Version: V1.0
+///
+/// # Arguments
+/// * `processor` - Processor handle
+/// * `data` - Frame bytes
+/// * `len` - Number of bytes
+///
+/// # Returns
+/// Status code (QUEUE_FULL when frames are fed faster than processed)
+///
+/// # Safety
+/// `processor` must be a handle returned by `vrc_create` and `data` must
+/// point to `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn vrc_feed_frame(processor: *mut VrcProcessor, data: *const u8, len: usize) -> VrcStatus {
+    let Some(processor) = processor.as_ref() else {
+        set_last_error("processor is NULL");
+        return VrcStatus::InvalidArgument;
+    };
+    if data.is_null() || len == 0 {
+        set_last_error("empty frame");
+        return VrcStatus::InvalidArgument;
+    }
+    let Some(running) = processor.running.as_ref() else {
+        set_last_error("processor not running");
+        return VrcStatus::NotRunning;
+    };
+
+    let frame = std::slice::from_raw_parts(data, len).to_vec();
+    match running.frames.try_send(frame) {
+        Ok(()) => VrcStatus::Ok,
+        Err(mpsc::error::TrySendError::Full(_)) => {
+            set_last_error("frame queue full");
+            VrcStatus::QueueFull
+        }
+        Err(mpsc::error::TrySendError::Closed(_)) => {
+            set_last_error("processor stopped");
+            VrcStatus::NotRunning
+        }
+    }
+}
+
+/// ID SRS: SRS-FN-FFIAPI-006
+/// Title: vrc_latest_values
+///
+/// Description: VRConnect shall copy the latest value of every track into a
+/// caller buffer.
+///
+/// Version: V1.0
+///
+/// # Arguments
+/// * `processor` - Processor handle
+/// * `out` - Buffer of `capacity` values (may be NULL if capacity is 0)
+/// * `capacity` - Buffer capacity
+///
+/// # Returns
+/// Total number of values (more than `capacity` if the buffer is too small)
+///
+/// # Safety
+/// `processor` must be a handle returned by `vrc_create` and `out` must
+/// point to `capacity` writable values.
+#[no_mangle]
+pub unsafe extern "C" fn vrc_latest_values(processor: *const VrcProcessor, out: *mut VrcValue, capacity: usize) -> usize {
+    let Some(processor) = processor.as_ref() else {
+        set_last_error("processor is NULL");
+        return 0;
+    };
+    let values = processor.shared.latest_values();
+    if !out.is_null() {
+        for (i, value) in values.iter().take(capacity).enumerate() {
+            out.add(i).write(*value);
+        }
+    }
+    values.len()
+}
+
+/// ID SRS: SRS-FN-FFIAPI-007
+/// Title: vrc_poll_event
+///
+/// Description: VRConnect shall hand the oldest queued alarm event to the
+/// caller (events are only queued while no event callback is registered).
+///
+/// Version: V1.0
+///
+/// # Arguments
+/// * `processor` - Processor handle
+/// * `out` - Event to fill
+///
+/// # Returns
+/// true if an event was written
+///
+/// # Safety
+/// `processor` must be a handle returned by `vrc_create` and `out` must
+/// point to a writable event.
+#[no_mangle]
+pub unsafe extern "C" fn vrc_poll_event(processor: *const VrcProcessor, out: *mut VrcEvent) -> bool {
+    let Some(processor) = processor.as_ref() else {
+        set_last_error("processor is NULL");
+        return false;
+    };
+    if out.is_null() {
+        set_last_error("out is NULL");
+        return false;
+    }
+    match processor.shared.pop_event() {
+        Some(event) => {
+            out.write(event);
+            true
+        }
+        None => false,
+    }
+}
+
+/// ID SRS: SRS-FN-FFIAPI-008
+/// Title: vrc_poll_waveform
+///
+/// Description: VRConnect shall hand the oldest queued waveform segment to
+/// the caller, its points copied into a caller buffer (segments are only
+/// queued while no waveform callback is registered).
+///
+/// Version: V1.0
+///
+/// # Arguments
+/// * `processor` - Processor handle
+/// * `out` - Segment to fill; `points` is set to `points` and `point_count`
+///   to the number of points copied
+/// * `points` - Buffer of `capacity` points (extra points are dropped)
+/// * `capacity` - Buffer capacity
+///
+/// # Returns
+/// true if a segment was written
+///
+/// # Safety
+/// `processor` must be a handle returned by `vrc_create`, `out` must point
+/// to a writable segment and `points` to `capacity` writable values.
+#[no_mangle]
+pub unsafe extern "C" fn vrc_poll_waveform(
+    processor: *const VrcProcessor,
+    out: *mut VrcWaveform,
+    points: *mut f64,
+    capacity: usize,
+) -> bool {
+    let Some(processor) = processor.as_ref() else {
+        set_last_error("processor is NULL");
+        return false;
+    };
+    if out.is_null() || (points.is_null() && capacity > 0) {
+        set_last_error("out or points is NULL");
+        return false;
+    }
+    let Some(segment) = processor.shared.pop_waveform() else {
+        return false;
+    };
+
+    let count = segment.points.len().min(capacity);
+    if count > 0 {
+        std::ptr::copy_nonoverlapping(segment.points.as_ptr(), points, count);
+    }
+    let mut header = segment.header;
+    header.points = points;
+    header.point_count = count;
+    out.write(header);
+    true
+}
+
+/// ID SRS: SRS-FN-FFIAPI-009
+/// Title: vrc_set_value_callback
+///
+/// Description: VRConnect shall call a function with every updated value,
+/// on the processor thread.
+///
+/// Version: V1.0
+///
+/// # Arguments
+/// * `processor` - Processor handle
+/// * `callback` - Function to call, NULL to unregister
+/// * `user_data` - Pointer passed back to the callback
+///
+/// # Returns
+/// Status code, once a call in progress of the previous callback returned
+///
+/// # Safety
+/// `processor` must be a handle returned by `vrc_create`; the callback must
+/// be callable from another thread with `user_data`, and must not set a
+/// callback itself. `user_data` of a replaced callback may be released
+/// when this function returns.
+#[no_mangle]
+pub unsafe extern "C" fn vrc_set_value_callback(
+    processor: *mut VrcProcessor,
+    callback: VrcValueCallback,
+    user_data: *mut c_void,
+) -> VrcStatus {
+    let Some(processor) = processor.as_ref() else {
+        set_last_error("processor is NULL");
+        return VrcStatus::InvalidArgument;
+    };
+    *lock(&processor.shared.value_callback) = callback.map(|_| Registered { callback, user_data });
+    VrcStatus::Ok
+}
+
+/// ID SRS: SRS-FN-FFIAPI-010
+/// Title: vrc_set_event_callback
+///
+/// Description: VRConnect shall call a function with every alarm event, on
+/// the processor thread, instead of queuing it for polling.
+///
+/// Version: V1.0
+///
+/// # Arguments
+/// * `processor` - Processor handle
+/// * `callback` - Function to call, NULL to unregister
+/// * `user_data` - Pointer passed back to the callback
+///
+/// # Returns
+/// Status code, once a call in progress of the previous callback returned
+///
+/// # Safety
+/// `processor` must be a handle returned by `vrc_create`; the callback must
+/// be callable from another thread with `user_data`, and must not set a
+/// callback itself. `user_data` of a replaced callback may be released
+/// when this function returns.
+#[no_mangle]
+pub unsafe extern "C" fn vrc_set_event_callback(
+    processor: *mut VrcProcessor,
+    callback: VrcEventCallback,
+    user_data: *mut c_void,
+) -> VrcStatus {
+    let Some(processor) = processor.as_ref() else {
+        set_last_error("processor is NULL");
+        return VrcStatus::InvalidArgument;
+    };
+    *lock(&processor.shared.event_callback) = callback.map(|_| Registered { callback, user_data });
+    VrcStatus::Ok
+}
+
+/// ID SRS: SRS-FN-FFIAPI-011
+/// Title: vrc_set_waveform_callback
+///
+/// Description: VRConnect shall call a function with every waveform segment,
+/// on the processor thread, instead of queuing it for polling; the points
+/// are only valid during the call.
+///
+/// Version: V1.0
+///
+/// # Arguments
+/// * `processor` - Processor handle
+/// * `callback` - Function to call, NULL to unregister
+/// * `user_data` - Pointer passed back to the callback
+///
+/// # Returns
+/// Status code, once a call in progress of the previous callback returned
+///
+/// # Safety
+/// `processor` must be a handle returned by `vrc_create`; the callback must
+/// be callable from another thread with `user_data`, and must not set a
+/// callback itself. `user_data` of a replaced callback may be released
+/// when this function returns.
+#[no_mangle]
+pub unsafe extern "C" fn vrc_set_waveform_callback(
+    processor: *mut VrcProcessor,
+    callback: VrcWaveformCallback,
+    user_data: *mut c_void,
+) -> VrcStatus {
+    let Some(processor) = processor.as_ref() else {
+        set_last_error("processor is NULL");
+        return VrcStatus::InvalidArgument;
+    };
+    *lock(&processor.shared.waveform_callback) = callback.map(|_| Registered { callback, user_data });
+    VrcStatus::Ok
+}
+
+/// ID SRS: SRS-FN-FFIAPI-012
+/// Title: vrc_last_error
+///
+/// Description: VRConnect shall describe the last error of a C API call on
+/// the calling thread.
+///
+/// Version: V1.0
+///
+/// # Returns
+/// NUL-terminated message, valid until the next failing call on this thread
+#[no_mangle]
+pub extern "C" fn vrc_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| last.borrow().as_ptr())
+}
+
+/// ID SRS: SRS-FN-FFIAPI-013
+/// Title: vrc_version
+///
+/// Description: VRConnect shall report the version of the library.
+///
+/// Version: V1.0
+///
+/// # Returns
+/// NUL-terminated version string
+#[no_mangle]
+pub extern "C" fn vrc_version() -> *const c_char {
+    concat!(env!("CARGO_PKG_VERSION"), "\0").as_ptr() as *const c_char
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn test_feed_frame_and_poll_values() {
+        let args = [c"--input-queue-capacity", c"16"];
+        let argv: Vec<*const c_char> = args.iter().map(|arg| arg.as_ptr()).collect();
+
+        unsafe {
+            assert!(vrc_create(1, std::ptr::null()).is_null());
+            assert_eq!(CStr::from_ptr(vrc_last_error()).to_str().unwrap(), "argv is NULL");
+
+            let processor = vrc_create(argv.len() as c_int, argv.as_ptr());
+            assert!(!processor.is_null());
+            assert_eq!(vrc_feed_frame(processor, b"{}".as_ptr(), 2), VrcStatus::NotRunning);
+            assert_eq!(vrc_start(processor, 0), VrcStatus::Ok);
+            assert_eq!(vrc_start(processor, 0), VrcStatus::AlreadyRunning);
+
+            let frame = format!(
+                r#"{{"vrcode":"VR-1","rooms":[{{"seqid":0,"roomname":"OR-1","trks":[{{"id":"1","name":"HR","type":"num","unit":"/min","recs":[{{"val":72,"dt":{}}}]}}]}}]}}"#,
+                chrono::Utc::now().timestamp_millis()
+            );
+            assert_eq!(vrc_feed_frame(processor, frame.as_ptr(), frame.len()), VrcStatus::Ok);
+
+            let mut values = [std::mem::zeroed::<VrcValue>(); 4];
+            let deadline = Instant::now() + Duration::from_secs(5);
+            let mut count = 0;
+            while count == 0 && Instant::now() < deadline {
+                std::thread::sleep(Duration::from_millis(20));
+                count = vrc_latest_values(processor, values.as_mut_ptr(), values.len());
+            }
+            // Derived tracks (e.g. early warning subscores) follow the received one
+            assert!(count >= 1);
+            assert_eq!(CStr::from_ptr(values[0].track_name.as_ptr()).to_str().unwrap(), "HR");
+            assert_eq!(CStr::from_ptr(values[0].device_id.as_ptr()).to_str().unwrap(), "VR-1");
+            assert_eq!(values[0].value, 72.0);
+
+            assert_eq!(vrc_stop(processor), VrcStatus::Ok);
+            assert_eq!(vrc_stop(processor), VrcStatus::NotRunning);
+            vrc_destroy(processor);
+        }
+    }
+
+    #[test]
+    fn test_stop_reports_processor_error() {
+        let args = [c"--input-queue-capacity", c"16"];
+        let argv: Vec<*const c_char> = args.iter().map(|arg| arg.as_ptr()).collect();
+
+        unsafe {
+            let processor = vrc_create(argv.len() as c_int, argv.as_ptr());
+            assert!(!processor.is_null());
+            // Unknown stage: run() fails before processing any frame
+            (*processor).config.pipeline_stages = "unknown".to_string();
+            assert_eq!(vrc_start(processor, 0), VrcStatus::Ok);
+
+            assert_eq!(vrc_stop(processor), VrcStatus::Failed);
+            assert!(CStr::from_ptr(vrc_last_error()).to_str().unwrap().contains("unknown"));
+            vrc_destroy(processor);
+        }
+    }
+
+    #[test]
+    fn test_committed_header_matches_generated() {
+        let generated = std::fs::read_to_string(concat!(env!("OUT_DIR"), "/vrconnect.h")).unwrap();
+        let committed = include_str!("../../include/vrconnect.h");
+        assert!(
+            generated == committed,
+            "include/vrconnect.h is outdated, regenerate it with VRC_GENERATE_HEADER=1 cargo build"
+        );
+    }
+}
@@ -0,0 +1,377 @@
+// /src/ffi/bridge.rs
+// Module: ffi.bridge
+// Purpose: Output and input connecting a processor to the C API
+
+use crate::domain::{AlarmEvent, ProcessedData, ProcessedTrack, VitalData};
+use crate::error::Result;
+use crate::ffi::types::{
+    c_string, VrcEvent, VrcEventCallback, VrcValue, VrcValueCallback, VrcWaveform, VrcWaveformCallback,
+};
+use crate::input::InputSource;
+use crate::metrics::metrics;
+use crate::output::{OutputMode, OutputSink, SinkHealth};
+use crate::pipeline::RawPipeline;
+use crate::processor::VitalDataTransformer;
+use async_trait::async_trait;
+use std::collections::{BTreeMap, VecDeque};
+use std::os::raw::c_void;
+use std::sync::{Mutex, MutexGuard};
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+
+/// Alarm events kept for polling (oldest dropped beyond)
+const MAX_QUEUED_EVENTS: usize = 1024;
+
+/// Waveform segments kept for polling (oldest dropped beyond)
+const MAX_QUEUED_WAVEFORMS: usize = 256;
+
+/// Callback with its opaque user data
+#[derive(Clone, Copy)]
+pub(crate) struct Registered<F> {
+    pub callback: F,
+    pub user_data: *mut c_void,
+}
+
+// The user data is only handed back to the callback; the caller guarantees
+// that the callback may run on the processor thread.
+unsafe impl<F> Send for Registered<F> {}
+
+/// Waveform segment owning its points
+pub(crate) struct WaveformSegment {
+    pub header: VrcWaveform,
+    pub points: Vec<f64>,
+}
+
+// The header pointer is null while queued and only set for the C caller.
+unsafe impl Send for WaveformSegment {}
+
+/// ID SRS: SRS-MOD-FFIBRIDGE-001
+/// Title: FfiShared
+///
+/// Description: VRConnect shall keep the latest value of every track and
+/// bounded queues of alarm events and waveform segments for a C caller that
+/// polls, or hand them to the registered callbacks.
+///
+/// Version: V1.0
+#[derive(Default)]
+pub struct FfiShared {
+    latest: Mutex<BTreeMap<(String, String, String), VrcValue>>,
+    events: Mutex<VecDeque<VrcEvent>>,
+    waveforms: Mutex<VecDeque<WaveformSegment>>,
+    pub(crate) value_callback: Mutex<Option<Registered<VrcValueCallback>>>,
+    pub(crate) event_callback: Mutex<Option<Registered<VrcEventCallback>>>,
+    pub(crate) waveform_callback: Mutex<Option<Registered<VrcWaveformCallback>>>,
+}
+
+/// Lock a mutex (a poisoned lock still holds consistent data)
+pub(crate) fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+impl FfiShared {
+    /// ID SRS: SRS-FN-FFIBRIDGE-001
+    /// Title: latest_values
+    ///
+    /// Description: VRConnect shall list the latest value of every track,
+    /// sorted by device, room and track.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Latest values
+    pub fn latest_values(&self) -> Vec<VrcValue> {
+        lock(&self.latest).values().copied().collect()
+    }
+
+    /// ID SRS: SRS-FN-FFIBRIDGE-002
+    /// Title: pop_event
+    ///
+    /// Description: VRConnect shall remove the oldest queued alarm event.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Event, or None if the queue is empty
+    pub fn pop_event(&self) -> Option<VrcEvent> {
+        lock(&self.events).pop_front()
+    }
+
+    /// ID SRS: SRS-FN-FFIBRIDGE-003
+    /// Title: pop_waveform
+    ///
+    /// Description: VRConnect shall remove the oldest queued waveform
+    /// segment.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Segment, or None if the queue is empty
+    pub(crate) fn pop_waveform(&self) -> Option<WaveformSegment> {
+        lock(&self.waveforms).pop_front()
+    }
+
+    /// ID SRS: SRS-FN-FFIBRIDGE-004
+    /// Title: publish_value
+    ///
+    /// Description: VRConnect shall store a track value as the latest one and
+    /// hand it to the value callback. The callback runs under its lock, so
+    /// that unregistering waits for a call in progress.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `device_id` - Device identifier
+    /// * `track` - Track of the value
+    /// * `name` - Value name (track or component name)
+    /// * `value` - Numeric value
+    fn publish_value(&self, device_id: &str, track: &ProcessedTrack, name: &str, value: f64) {
+        let value = VrcValue {
+            device_id: c_string(device_id),
+            room_name: c_string(&track.room_name),
+            track_name: c_string(name),
+            unit: c_string(&track.unit),
+            value,
+            timestamp_ms: track.timestamp.timestamp_millis(),
+        };
+        lock(&self.latest).insert(
+            (device_id.to_string(), track.room_name.clone(), name.to_string()),
+            value,
+        );
+
+        let registered = lock(&self.value_callback);
+        if let Some(Registered { callback: Some(callback), user_data }) = *registered {
+            unsafe { callback(&value, user_data) };
+        }
+    }
+
+    /// ID SRS: SRS-FN-FFIBRIDGE-005
+    /// Title: publish_waveform
+    ///
+    /// Description: VRConnect shall hand a waveform segment to the waveform
+    /// callback (under its lock), or queue it for polling when no callback is
+    /// registered.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `device_id` - Device identifier
+    /// * `track` - Waveform track
+    /// * `points` - Segment points
+    fn publish_waveform(&self, device_id: &str, track: &ProcessedTrack, points: &[f64]) {
+        let mut header = VrcWaveform {
+            device_id: c_string(device_id),
+            room_name: c_string(&track.room_name),
+            track_name: c_string(&track.name),
+            unit: c_string(&track.unit),
+            sample_rate: track.sample_rate.unwrap_or(0.0),
+            timestamp_ms: track.timestamp.timestamp_millis(),
+            points: std::ptr::null(),
+            point_count: points.len(),
+        };
+
+        let registered = lock(&self.waveform_callback);
+        match *registered {
+            Some(Registered { callback: Some(callback), user_data }) => {
+                header.points = points.as_ptr();
+                unsafe { callback(&header, user_data) };
+            }
+            _ => {
+                let mut waveforms = lock(&self.waveforms);
+                if waveforms.len() >= MAX_QUEUED_WAVEFORMS {
+                    waveforms.pop_front();
+                }
+                waveforms.push_back(WaveformSegment {
+                    header,
+                    points: points.to_vec(),
+                });
+            }
+        }
+    }
+
+    /// ID SRS: SRS-FN-FFIBRIDGE-006
+    /// Title: publish_event
+    ///
+    /// Description: VRConnect shall hand an alarm event to the event
+    /// callback (under its lock), or queue it for polling when no callback is
+    /// registered.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `event` - Alarm event
+    fn publish_event(&self, event: &AlarmEvent) {
+        let event = VrcEvent::from(event);
+
+        let registered = lock(&self.event_callback);
+        match *registered {
+            Some(Registered { callback: Some(callback), user_data }) => unsafe { callback(&event, user_data) },
+            _ => {
+                let mut events = lock(&self.events);
+                if events.len() >= MAX_QUEUED_EVENTS {
+                    events.pop_front();
+                }
+                events.push_back(event);
+            }
+        }
+    }
+}
+
+/// ID SRS: SRS-MOD-FFIBRIDGE-002
+/// Title: FfiSink
+///
+/// Description: VRConnect shall deliver processed data and alarm events to
+/// the C API: numeric values and composite components as latest values,
+/// waveform points as segments.
+///
+/// Version: V1.0
+pub struct FfiSink {
+    shared: std::sync::Arc<FfiShared>,
+}
+
+impl FfiSink {
+    /// ID SRS: SRS-FN-FFIBRIDGE-007
+    /// Title: new
+    ///
+    /// Description: VRConnect shall construct an FfiSink on the shared state
+    /// of a C API processor.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `shared` - State shared with the C API
+    ///
+    /// # Returns
+    /// New FfiSink instance
+    pub fn new(shared: std::sync::Arc<FfiShared>) -> Self {
+        Self { shared }
+    }
+}
+
+#[async_trait]
+impl OutputSink for FfiSink {
+    fn name(&self) -> &str {
+        "ffi"
+    }
+
+    fn mode(&self) -> OutputMode {
+        OutputMode::Delta
+    }
+
+    async fn handle_data(&self, data: &ProcessedData) -> Result<()> {
+        for track in &data.all_tracks {
+            if let Some(value) = track.raw_value {
+                self.shared.publish_value(&data.device_id, track, &track.name, value);
+            }
+            for component in track.components.iter().flatten() {
+                let name = format!("{}.{}", track.name, component.name);
+                self.shared.publish_value(&data.device_id, track, &name, component.value);
+            }
+            if let Some(points) = track.waveform_points.as_ref().filter(|points| !points.is_empty()) {
+                self.shared.publish_waveform(&data.device_id, track, points);
+            }
+        }
+        Ok(())
+    }
+
+    async fn handle_event(&self, event: &AlarmEvent) -> Result<()> {
+        self.shared.publish_event(event);
+        Ok(())
+    }
+
+    fn health(&self) -> SinkHealth {
+        SinkHealth::Healthy
+    }
+}
+
+/// ID SRS: SRS-MOD-FFIBRIDGE-003
+/// Title: FrameInput
+///
+/// Description: VRConnect shall process the frames fed through the C API
+/// like frames received by the Socket.IO server (pre-parse stages, parsing,
+/// transformation).
+///
+/// Version: V1.0
+pub struct FrameInput {
+    frames: tokio::sync::Mutex<mpsc::Receiver<Vec<u8>>>,
+    raw_pipeline: RawPipeline,
+    transformer: VitalDataTransformer,
+}
+
+impl FrameInput {
+    /// ID SRS: SRS-FN-FFIBRIDGE-008
+    /// Title: new
+    ///
+    /// Description: VRConnect shall construct a FrameInput reading fed frames
+    /// from a channel.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `frames` - Receiver of the fed frames
+    /// * `raw_pipeline` - Pre-parse stages
+    ///
+    /// # Returns
+    /// New FrameInput instance
+    pub fn new(frames: mpsc::Receiver<Vec<u8>>, raw_pipeline: RawPipeline) -> Self {
+        Self {
+            frames: tokio::sync::Mutex::new(frames),
+            raw_pipeline,
+            transformer: VitalDataTransformer::new(),
+        }
+    }
+
+    /// ID SRS: SRS-FN-FFIBRIDGE-009
+    /// Title: decode
+    ///
+    /// Description: VRConnect shall decode a fed frame into processed data.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `frame` - Frame bytes (compressed or not)
+    ///
+    /// # Returns
+    /// Processed data or error
+    async fn decode(&self, frame: Vec<u8>) -> Result<ProcessedData> {
+        let json = self.raw_pipeline.process(frame)?;
+        let vital_data: VitalData = serde_json::from_slice(&json).inspect_err(|_| {
+            metrics().parse_failures.inc();
+        })?;
+
+        let started = std::time::Instant::now();
+        let data = self.transformer.transform(vital_data);
+        metrics().transform_duration.observe(started.elapsed().as_secs_f64());
+        Ok(data)
+    }
+}
+
+#[async_trait]
+impl InputSource for FrameInput {
+    fn name(&self) -> &str {
+        "ffi"
+    }
+
+    async fn run(&self, tx: mpsc::Sender<ProcessedData>, shutdown: CancellationToken) -> Result<()> {
+        let mut frames = self.frames.lock().await;
+        loop {
+            let frame = tokio::select! {
+                frame = frames.recv() => match frame {
+                    Some(frame) => frame,
+                    None => return Ok(()),
+                },
+                _ = shutdown.cancelled() => return Ok(()),
+            };
+
+            metrics().frames_received.with_label("ffi").inc();
+            match self.decode(frame).await {
+                Ok(data) => {
+                    if tx.try_send(data).is_err() {
+                        metrics().frames_dropped.with_label("ffi").inc();
+                        log::warn!("⚠️  Processing queue full: fed frame dropped");
+                    }
+                }
+                Err(e) => log::error!("Error processing fed frame: {}", e),
+            }
+        }
+    }
+}
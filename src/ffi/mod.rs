@@ -0,0 +1,11 @@
+// /src/ffi/mod.rs
+// Module: ffi
+// Purpose: C API for embedding the processor in native applications (game engines, simulators)
+
+pub mod api;
+pub mod bridge;
+pub mod types;
+
+pub use api::VrcProcessor;
+pub use bridge::{FfiShared, FfiSink, FrameInput};
+pub use types::{VrcEvent, VrcStatus, VrcValue, VrcWaveform};
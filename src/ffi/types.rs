@@ -0,0 +1,212 @@
+// /src/ffi/types.rs
+// Module: ffi.types
+// Purpose: Plain C structures exchanged through the C API
+
+use crate::domain::{AlarmAction, AlarmCondition, AlarmEvent, AlarmPriority};
+use std::os::raw::{c_char, c_void};
+
+/// Size of the name fields, including the terminating NUL
+pub const VRC_NAME_LEN: usize = 64;
+
+/// Size of the unit field, including the terminating NUL
+pub const VRC_UNIT_LEN: usize = 16;
+
+/// Size of the message field, including the terminating NUL
+pub const VRC_MESSAGE_LEN: usize = 256;
+
+/// ID SRS: SRS-MOD-FFITYPES-001
+/// Title: VrcStatus
+///
+/// Description: VRConnect shall report the outcome of C API calls as status
+/// codes, with details available from `vrc_last_error`.
+///
+/// Version: V1.0
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VrcStatus {
+    Ok = 0,
+    InvalidArgument = -1,
+    NotRunning = -2,
+    AlreadyRunning = -3,
+    QueueFull = -4,
+    Failed = -5,
+}
+
+/// ID SRS: SRS-MOD-FFITYPES-002
+/// Title: VrcValue
+///
+/// Description: VRConnect shall provide the latest numeric value of a track
+/// as a plain C structure (composite values such as NIBP as one value per
+/// component, e.g. "NIBP.systolic").
+///
+/// Version: V1.0
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct VrcValue {
+    pub device_id: [c_char; VRC_NAME_LEN],
+    pub room_name: [c_char; VRC_NAME_LEN],
+    pub track_name: [c_char; VRC_NAME_LEN],
+    pub unit: [c_char; VRC_UNIT_LEN],
+    pub value: f64,
+    /// Milliseconds since the Unix epoch
+    pub timestamp_ms: i64,
+}
+
+/// ID SRS: SRS-MOD-FFITYPES-003
+/// Title: VrcWaveform
+///
+/// Description: VRConnect shall provide a waveform segment as a plain C
+/// structure; `points` is valid during the callback, or points to the
+/// caller buffer when polled.
+///
+/// Version: V1.0
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct VrcWaveform {
+    pub device_id: [c_char; VRC_NAME_LEN],
+    pub room_name: [c_char; VRC_NAME_LEN],
+    pub track_name: [c_char; VRC_NAME_LEN],
+    pub unit: [c_char; VRC_UNIT_LEN],
+    /// Samples per second, 0 if unknown
+    pub sample_rate: f64,
+    /// Milliseconds since the Unix epoch
+    pub timestamp_ms: i64,
+    pub points: *const f64,
+    pub point_count: usize,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VrcAlarmCondition {
+    Low,
+    High,
+    DataStale,
+    SensorOff,
+    ClockDrift,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VrcAlarmAction {
+    Raised,
+    Cleared,
+    Escalated,
+    Acknowledged,
+    Silenced,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VrcAlarmPriority {
+    Low,
+    Medium,
+    High,
+}
+
+/// ID SRS: SRS-MOD-FFITYPES-004
+/// Title: VrcEvent
+///
+/// Description: VRConnect shall provide an alarm event as a plain C
+/// structure; missing values and limits are NaN.
+///
+/// Version: V1.0
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct VrcEvent {
+    pub device_id: [c_char; VRC_NAME_LEN],
+    pub room_name: [c_char; VRC_NAME_LEN],
+    pub parameter: [c_char; VRC_NAME_LEN],
+    pub condition: VrcAlarmCondition,
+    pub action: VrcAlarmAction,
+    pub priority: VrcAlarmPriority,
+    pub value: f64,
+    pub limit: f64,
+    pub silenced: bool,
+    pub message: [c_char; VRC_MESSAGE_LEN],
+    /// Milliseconds since the Unix epoch
+    pub timestamp_ms: i64,
+}
+
+/// Called with every updated value
+pub type VrcValueCallback = Option<unsafe extern "C" fn(value: *const VrcValue, user_data: *mut c_void)>;
+
+/// Called with every alarm event
+pub type VrcEventCallback = Option<unsafe extern "C" fn(event: *const VrcEvent, user_data: *mut c_void)>;
+
+/// Called with every waveform segment
+pub type VrcWaveformCallback = Option<unsafe extern "C" fn(waveform: *const VrcWaveform, user_data: *mut c_void)>;
+
+/// ID SRS: SRS-FN-FFITYPES-001
+/// Title: c_string
+///
+/// Description: VRConnect shall copy a string into a fixed-size C field,
+/// truncated on a character boundary and NUL-terminated.
+///
+/// Version: V1.0
+///
+/// # Arguments
+/// * `text` - String to copy
+///
+/// # Returns
+/// C character array
+pub fn c_string<const N: usize>(text: &str) -> [c_char; N] {
+    let mut field = [0 as c_char; N];
+    let mut len = text.len().min(N - 1);
+    while !text.is_char_boundary(len) {
+        len -= 1;
+    }
+    for (dst, src) in field.iter_mut().zip(&text.as_bytes()[..len]) {
+        *dst = *src as c_char;
+    }
+    field
+}
+
+impl From<&AlarmEvent> for VrcEvent {
+    fn from(event: &AlarmEvent) -> Self {
+        VrcEvent {
+            device_id: c_string(&event.device_id),
+            room_name: c_string(&event.room_name),
+            parameter: c_string(&event.parameter),
+            condition: match event.condition {
+                AlarmCondition::Low => VrcAlarmCondition::Low,
+                AlarmCondition::High => VrcAlarmCondition::High,
+                AlarmCondition::DataStale => VrcAlarmCondition::DataStale,
+                AlarmCondition::SensorOff => VrcAlarmCondition::SensorOff,
+                AlarmCondition::ClockDrift => VrcAlarmCondition::ClockDrift,
+            },
+            action: match event.action {
+                AlarmAction::Raised => VrcAlarmAction::Raised,
+                AlarmAction::Cleared => VrcAlarmAction::Cleared,
+                AlarmAction::Escalated => VrcAlarmAction::Escalated,
+                AlarmAction::Acknowledged => VrcAlarmAction::Acknowledged,
+                AlarmAction::Silenced => VrcAlarmAction::Silenced,
+            },
+            priority: match event.priority {
+                AlarmPriority::Low => VrcAlarmPriority::Low,
+                AlarmPriority::Medium => VrcAlarmPriority::Medium,
+                AlarmPriority::High => VrcAlarmPriority::High,
+            },
+            value: event.value.unwrap_or(f64::NAN),
+            limit: event.limit.unwrap_or(f64::NAN),
+            silenced: event.silenced,
+            message: c_string(&event.message),
+            timestamp_ms: event.timestamp.timestamp_millis(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+
+    #[test]
+    fn test_c_string_truncation() {
+        let field: [c_char; 8] = c_string("TEMP1 °C");
+        let text = unsafe { CStr::from_ptr(field.as_ptr()) };
+        // 7 bytes would cut the 2-byte '°' in half
+        assert_eq!(text.to_str().unwrap(), "TEMP1 ");
+        let field: [c_char; 8] = c_string("HR");
+        assert_eq!(unsafe { CStr::from_ptr(field.as_ptr()) }.to_str().unwrap(), "HR");
+    }
+}
@@ -0,0 +1,391 @@
+// /src/http/api.rs
+// Module: http.api
+// Purpose: Read-only JSON API for devices, latest values, history and alarm events
+
+use crate::core::history::{HistoryKey, HistoryQuery, HistorySegment, HistoryValue};
+use crate::core::{DeviceRegistry, EventLog, History, Watchdog};
+use crate::domain::{ProcessedTrack, TrackType};
+use crate::http::{HttpHandler, HttpRequest, HttpResponse};
+use crate::signal::Decimation;
+use async_trait::async_trait;
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::Serialize;
+use std::str::FromStr;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// Events returned when no limit is given
+const DEFAULT_EVENT_LIMIT: usize = 100;
+
+/// ID SRS: SRS-MOD-API-001
+/// Title: LatestValues
+///
+/// Description: VRConnect shall describe the latest record of every track of
+/// a device, with its age and its stale or sensor-off flag.
+///
+/// Version: V1.0
+#[derive(Debug, Clone, Serialize)]
+pub struct LatestValues {
+    pub device_id: String,
+    #[serde(with = "chrono::serde::ts_milliseconds")]
+    pub timestamp: DateTime<Utc>,
+    pub tracks: Vec<LatestTrack>,
+}
+
+/// ID SRS: SRS-MOD-API-004
+/// Title: LatestTrack
+///
+/// Description: VRConnect shall describe the latest record of a track with
+/// the milliseconds elapsed since its timestamp.
+///
+/// Version: V1.0
+#[derive(Debug, Clone, Serialize)]
+pub struct LatestTrack {
+    #[serde(flatten)]
+    pub track: ProcessedTrack,
+    pub age_ms: i64,
+}
+
+/// ID SRS: SRS-MOD-API-002
+/// Title: TrackHistory
+///
+/// Description: VRConnect shall describe the history of a track: numeric
+/// values, or waveform segments for waveform tracks.
+///
+/// Version: V1.0
+#[derive(Debug, Clone, Serialize)]
+pub struct TrackHistory {
+    #[serde(flatten)]
+    pub key: HistoryKey,
+    pub unit: String,
+    pub track_type: TrackType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub values: Option<Vec<HistoryValue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub segments: Option<Vec<HistorySegment>>,
+}
+
+/// ID SRS: SRS-MOD-API-003
+/// Title: ApiEndpoint
+///
+/// Description: VRConnect shall serve a read-only JSON API under `/api`
+/// (devices and rooms, latest values, track history with decimation, recent
+/// events, active alarms) for clients that do not speak Socket.IO.
+///
+/// Version: V1.0
+pub struct ApiEndpoint {
+    devices: Arc<RwLock<DeviceRegistry>>,
+    history: Option<Arc<RwLock<History>>>,
+    watchdog: Option<Arc<RwLock<Watchdog>>>,
+    events: Arc<RwLock<EventLog>>,
+}
+
+impl ApiEndpoint {
+    /// ID SRS: SRS-FN-API-001
+    /// Title: new
+    ///
+    /// Description: VRConnect shall construct an ApiEndpoint on the shared
+    /// state of a running processor.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `devices` - Device registry
+    /// * `history` - Time-series history, or None if disabled
+    /// * `watchdog` - Watchdog flagging stale and sensor-off tracks, or None if disabled
+    /// * `events` - Recent alarm events
+    ///
+    /// # Returns
+    /// New ApiEndpoint instance
+    pub fn new(
+        devices: Arc<RwLock<DeviceRegistry>>,
+        history: Option<Arc<RwLock<History>>>,
+        watchdog: Option<Arc<RwLock<Watchdog>>>,
+        events: Arc<RwLock<EventLog>>,
+    ) -> Self {
+        Self {
+            devices,
+            history,
+            watchdog,
+            events,
+        }
+    }
+
+    /// ID SRS: SRS-FN-API-002
+    /// Title: latest
+    ///
+    /// Description: VRConnect shall answer the latest record of every track
+    /// of a device, from its snapshot flagged by the watchdog, with its age,
+    /// without waveform points unless requested (`waveforms=true`).
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `device_id` - Device identifier
+    /// * `request` - Request (query parameters)
+    ///
+    /// # Returns
+    /// Response (404 for an unknown device)
+    async fn latest(&self, device_id: &str, request: &HttpRequest) -> HttpResponse {
+        let waveforms = match param::<bool>(request, "waveforms") {
+            Ok(waveforms) => waveforms.unwrap_or(false),
+            Err(response) => return response,
+        };
+
+        let now = Utc::now();
+        let Some(mut snapshot) = self.devices.read().await.snapshot(device_id, now) else {
+            return HttpResponse::error(404, &format!("Unknown device {}", device_id));
+        };
+        if let Some(watchdog) = &self.watchdog {
+            watchdog.read().await.mark(&mut snapshot);
+        }
+
+        let tracks = snapshot
+            .all_tracks
+            .into_iter()
+            .map(|mut track| {
+                if !waveforms {
+                    track.waveform_points = None;
+                }
+                LatestTrack {
+                    age_ms: (now - track.timestamp).num_milliseconds().max(0),
+                    track,
+                }
+            })
+            .collect();
+        HttpResponse::json(
+            200,
+            &LatestValues {
+                device_id: snapshot.device_id,
+                timestamp: now,
+                tracks,
+            },
+        )
+    }
+
+    /// ID SRS: SRS-FN-API-003
+    /// Title: track_history
+    ///
+    /// Description: VRConnect shall answer the history of a device track
+    /// (`track`, `room` when the track exists in several rooms) over the
+    /// last `seconds` or a `from`/`to` range in epoch milliseconds, with an
+    /// optional `decimation` ("lttb:500", "minmax:500", "resample:25").
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `device_id` - Device identifier
+    /// * `request` - Request (query parameters)
+    ///
+    /// # Returns
+    /// Response (400 for invalid parameters, 404 for an unknown track or a
+    /// disabled history)
+    async fn track_history(&self, device_id: &str, request: &HttpRequest) -> HttpResponse {
+        let Some(history) = &self.history else {
+            return HttpResponse::error(404, "History is disabled");
+        };
+        let Some(track_name) = request.query.get("track") else {
+            return HttpResponse::error(400, "Missing track parameter");
+        };
+        let query = match history_query(request) {
+            Ok(query) => query,
+            Err(response) => return response,
+        };
+
+        let history = history.read().await;
+        let candidates: Vec<_> = history
+            .tracks(Some(device_id))
+            .into_iter()
+            .filter(|summary| &summary.key.track_name == track_name)
+            .filter(|summary| {
+                request
+                    .query
+                    .get("room")
+                    .is_none_or(|room| &summary.key.room_name == room)
+            })
+            .collect();
+        let summary = match candidates.as_slice() {
+            [summary] => summary,
+            [] => return HttpResponse::error(404, &format!("No history for track {} of {}", track_name, device_id)),
+            _ => {
+                return HttpResponse::error(
+                    400,
+                    &format!("Track {} exists in several rooms, specify room", track_name),
+                )
+            }
+        };
+
+        let (values, segments) = if summary.track_type == TrackType::Waveform {
+            (None, history.query_waveform(&summary.key, &query))
+        } else {
+            (history.query_values(&summary.key, &query), None)
+        };
+        HttpResponse::json(
+            200,
+            &TrackHistory {
+                key: summary.key.clone(),
+                unit: summary.unit.clone(),
+                track_type: summary.track_type.clone(),
+                values,
+                segments,
+            },
+        )
+    }
+}
+
+/// Parse an optional query parameter (400 response if invalid)
+fn param<T: FromStr>(request: &HttpRequest, name: &str) -> Result<Option<T>, HttpResponse> {
+    request
+        .query
+        .get(name)
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|_| HttpResponse::error(400, &format!("Invalid {} parameter '{}'", name, value)))
+        })
+        .transpose()
+}
+
+/// Build the history query of a request (seconds, from, to, decimation)
+fn history_query(request: &HttpRequest) -> Result<HistoryQuery, HttpResponse> {
+    let timestamp = |millis: i64| {
+        DateTime::from_timestamp_millis(millis).ok_or_else(|| HttpResponse::error(400, "Timestamp out of range"))
+    };
+
+    let mut from = param::<i64>(request, "from")?.map(timestamp).transpose()?;
+    let to = param::<i64>(request, "to")?.map(timestamp).transpose()?;
+    if let Some(seconds) = param::<i64>(request, "seconds")? {
+        let start = TimeDelta::try_seconds(seconds.max(0)).and_then(|delta| Utc::now().checked_sub_signed(delta));
+        from = Some(start.ok_or_else(|| HttpResponse::error(400, "seconds parameter out of range"))?);
+    }
+    let decimation = match request.query.get("decimation") {
+        Some(spec) => Decimation::parse(spec).map_err(|e| HttpResponse::error(400, &e))?,
+        None => None,
+    };
+
+    Ok(HistoryQuery { from, to, decimation })
+}
+
+#[async_trait]
+impl HttpHandler for ApiEndpoint {
+    async fn handle(&self, request: &HttpRequest) -> Option<HttpResponse> {
+        let route = request.path.strip_prefix("/api/")?.trim_end_matches('/');
+        let segments: Vec<&str> = route.split('/').collect();
+
+        let response = match segments.as_slice() {
+            ["devices"] => HttpResponse::json(200, &self.devices.read().await.devices()),
+            ["devices", device_id] => {
+                let devices = self.devices.read().await.devices();
+                match devices.iter().find(|summary| summary.device_id == *device_id) {
+                    Some(summary) => HttpResponse::json(200, summary),
+                    None => HttpResponse::error(404, &format!("Unknown device {}", device_id)),
+                }
+            }
+            ["devices", device_id, "latest"] => self.latest(device_id, request).await,
+            ["devices", device_id, "history"] => self.track_history(device_id, request).await,
+            ["events"] => match param::<usize>(request, "limit") {
+                Ok(limit) => {
+                    let device_id = request.query.get("device").map(String::as_str);
+                    let events = self
+                        .events
+                        .read()
+                        .await
+                        .recent(limit.unwrap_or(DEFAULT_EVENT_LIMIT), device_id);
+                    HttpResponse::json(200, &events)
+                }
+                Err(response) => response,
+            },
+            ["alarms"] => {
+                let device_id = request.query.get("device").map(String::as_str);
+                HttpResponse::json(200, &self.events.read().await.active(device_id))
+            }
+            _ => return None,
+        };
+        Some(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::HistoryLimits;
+    use crate::domain::{ProcessedData, ProcessedRoom};
+
+    async fn get(endpoint: &ApiEndpoint, target: &str) -> (u16, serde_json::Value) {
+        let request = HttpRequest::parse(&format!("GET {} HTTP/1.1\r\n\r\n", target)).unwrap();
+        let response = endpoint.handle(&request).await.unwrap();
+        (response.status, serde_json::from_slice(&response.body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_devices_latest_and_history() {
+        let devices = Arc::new(RwLock::new(DeviceRegistry::new(300)));
+        let history = Arc::new(RwLock::new(History::new(HistoryLimits {
+            retention_secs: 3600,
+            max_values_per_track: 100,
+            max_waveform_points_per_track: 1000,
+            max_tracks: 10,
+        })));
+        let watchdog = Arc::new(RwLock::new(Watchdog::new(10, 30)));
+        let endpoint = ApiEndpoint::new(
+            devices.clone(),
+            Some(history.clone()),
+            Some(watchdog.clone()),
+            Arc::new(RwLock::new(EventLog::new(10))),
+        );
+
+        let now = Utc::now();
+        for (offset, value) in [(2, 70.0), (1, 72.0)] {
+            let data = ProcessedData::new(
+                "VR-1".to_string(),
+                vec![ProcessedRoom {
+                    room_index: 0,
+                    room_name: "OR-1".to_string(),
+                    tracks: vec![ProcessedTrack::synthetic(
+                        "HR",
+                        Some(value),
+                        "bpm",
+                        0,
+                        "OR-1",
+                        now - TimeDelta::seconds(offset),
+                    )],
+                }],
+            );
+            devices.write().await.record(&data);
+            history.write().await.record(&data);
+            watchdog.write().await.record(&data);
+        }
+
+        let (status, body) = get(&endpoint, "/api/devices").await;
+        assert_eq!(status, 200);
+        assert_eq!(body[0]["rooms"][0], "OR-1");
+
+        let (status, body) = get(&endpoint, "/api/devices/VR-1/latest").await;
+        assert_eq!(status, 200);
+        assert_eq!(body["tracks"][0]["raw_value"], 72.0);
+        assert!(body["tracks"][0]["age_ms"].as_i64().unwrap() >= 1000);
+        assert!(body["tracks"][0]["signal_status"].is_null());
+
+        // Last values of a stale device are flagged, not shown as current
+        watchdog.write().await.check(now + TimeDelta::seconds(60));
+        let (_, body) = get(&endpoint, "/api/devices/VR-1/latest").await;
+        assert_eq!(body["tracks"][0]["signal_status"], "stale");
+
+        let (status, body) = get(&endpoint, "/api/devices/VR-1/history?track=HR&seconds=60").await;
+        assert_eq!(status, 200);
+        assert_eq!(body["values"].as_array().unwrap().len(), 2);
+
+        assert_eq!(get(&endpoint, "/api/devices/VR-2/latest").await.0, 404);
+        assert_eq!(get(&endpoint, "/api/devices/VR-1/history?track=SPO2").await.0, 404);
+        assert_eq!(get(&endpoint, "/api/devices/VR-1/history?track=HR&decimation=lttb:1").await.0, 400);
+        assert_eq!(
+            get(&endpoint, "/api/devices/VR-1/history?track=HR&seconds=1000000000000000").await.0,
+            400
+        );
+        assert_eq!(get(&endpoint, "/api/events?limit=x").await.0, 400);
+        assert!(endpoint
+            .handle(&HttpRequest::parse("GET /metrics HTTP/1.1\r\n\r\n").unwrap())
+            .await
+            .is_none());
+    }
+}
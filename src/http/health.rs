@@ -0,0 +1,259 @@
+// /src/http/health.rs
+// Module: http.health
+// Purpose: /healthz and /readyz endpoints reporting component status and data freshness
+
+use crate::core::supervisor::{ComponentReport, ComponentState};
+use crate::core::{DeviceRegistry, Supervisor};
+use crate::http::{HttpHandler, HttpRequest, HttpResponse};
+use crate::input::SocketIOStatus;
+use crate::output::{OutputSink, SinkHealth};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// ID SRS: SRS-MOD-HEALTH-001
+/// Title: OutputReport
+///
+/// Description: VRConnect shall describe the health of an output in health
+/// reports.
+///
+/// Version: V1.0
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct OutputReport {
+    pub name: String,
+    pub health: SinkHealth,
+}
+
+/// ID SRS: SRS-MOD-HEALTH-002
+/// Title: HealthReport
+///
+/// Description: VRConnect shall report whether it is live (no critical
+/// component down) and ready (live, Socket.IO listener bound, no output down,
+/// fresh data), with the component states, connected devices, time since the
+/// last frame and the reasons for not being live or ready.
+///
+/// Version: V1.0
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HealthReport {
+    pub live: bool,
+    pub ready: bool,
+    pub problems: Vec<String>,
+    pub components: Vec<ComponentReport>,
+    pub outputs: Vec<OutputReport>,
+    /// None when the Socket.IO server is disabled
+    pub socketio_listening: Option<bool>,
+    pub connected_devices: i64,
+    pub known_devices: usize,
+    /// None before the first frame
+    pub last_frame_age_secs: Option<f64>,
+}
+
+/// ID SRS: SRS-MOD-HEALTH-003
+/// Title: HealthEndpoint
+///
+/// Description: VRConnect shall serve its health on `/healthz` (liveness)
+/// and `/readyz` (readiness), answering 503 with the report when not live or
+/// not ready, for service managers and deployment scripts.
+///
+/// Version: V1.0
+pub struct HealthEndpoint {
+    supervisor: Supervisor,
+    sinks: Vec<Arc<dyn OutputSink>>,
+    devices: Arc<RwLock<DeviceRegistry>>,
+    socketio: Option<Arc<SocketIOStatus>>,
+    stale_after_secs: u64,
+}
+
+impl HealthEndpoint {
+    /// ID SRS: SRS-FN-HEALTH-001
+    /// Title: new
+    ///
+    /// Description: VRConnect shall construct a HealthEndpoint on the
+    /// components of a running processor.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `supervisor` - Supervisor of the inputs and outputs
+    /// * `sinks` - Outputs of the processor
+    /// * `devices` - Device registry
+    /// * `socketio` - Status of the processor's Socket.IO server, which must
+    ///   be listening, or None if disabled
+    /// * `stale_after_secs` - Time without frames after which the data is stale
+    ///
+    /// # Returns
+    /// New HealthEndpoint instance
+    pub fn new(
+        supervisor: Supervisor,
+        sinks: Vec<Arc<dyn OutputSink>>,
+        devices: Arc<RwLock<DeviceRegistry>>,
+        socketio: Option<Arc<SocketIOStatus>>,
+        stale_after_secs: u64,
+    ) -> Self {
+        Self {
+            supervisor,
+            sinks,
+            devices,
+            socketio,
+            stale_after_secs,
+        }
+    }
+
+    /// ID SRS: SRS-FN-HEALTH-002
+    /// Title: report
+    ///
+    /// Description: VRConnect shall assess liveness and readiness: a critical
+    /// component restarting or failed makes it not live; not live, the
+    /// Socket.IO server not listening, an output down, or no frame within the
+    /// staleness delay makes it not ready.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `now` - Reference time for the data age
+    ///
+    /// # Returns
+    /// Health report
+    pub async fn report(&self, now: DateTime<Utc>) -> HealthReport {
+        let mut problems = Vec::new();
+
+        let components = self.supervisor.reports();
+        for component in components.iter().filter(|component| component.critical && component.is_degraded()) {
+            let state = if component.state == ComponentState::Failed { "failed" } else { "restarting" };
+            problems.push(format!("critical component {} {}", component.name, state));
+        }
+        if let Some(failure) = self.supervisor.failure() {
+            problems.push(format!("critical failure: {}", failure));
+        }
+        let live = problems.is_empty();
+
+        let socketio_listening = self.socketio.as_ref().map(|status| status.listening());
+        if socketio_listening == Some(false) {
+            problems.push("Socket.IO server not listening".to_string());
+        }
+
+        let outputs: Vec<OutputReport> = self
+            .sinks
+            .iter()
+            .map(|sink| OutputReport {
+                name: sink.name().to_string(),
+                health: sink.health(),
+            })
+            .collect();
+        for output in &outputs {
+            if let SinkHealth::Down(reason) = &output.health {
+                problems.push(format!("output {} down: {}", output.name, reason));
+            }
+        }
+
+        let (known_devices, last_seen) = {
+            let devices = self.devices.read().await;
+            (devices.devices().len(), devices.last_seen())
+        };
+        let last_frame_age_secs = last_seen.map(|last| (now - last).num_milliseconds().max(0) as f64 / 1000.0);
+        match last_frame_age_secs {
+            None => problems.push("no frame received".to_string()),
+            Some(age) if age > self.stale_after_secs as f64 => {
+                problems.push(format!("data stale (last frame {:.0}s ago)", age))
+            }
+            Some(_) => {}
+        }
+
+        HealthReport {
+            live,
+            ready: problems.is_empty(),
+            problems,
+            components,
+            outputs,
+            socketio_listening,
+            connected_devices: self.socketio.as_ref().map_or(0, |status| status.connections()),
+            known_devices,
+            last_frame_age_secs,
+        }
+    }
+}
+
+#[async_trait]
+impl HttpHandler for HealthEndpoint {
+    async fn handle(&self, request: &HttpRequest) -> Option<HttpResponse> {
+        let liveness = match request.path.as_str() {
+            "/healthz" => true,
+            "/readyz" => false,
+            _ => return None,
+        };
+
+        let report = self.report(Utc::now()).await;
+        let ok = if liveness { report.live } else { report.ready };
+        Some(HttpResponse::json(if ok { 200 } else { 503 }, &report))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Backoff;
+    use crate::domain::{ProcessedData, ProcessedRoom, ProcessedTrack};
+    use std::sync::atomic::Ordering;
+    use std::time::Duration;
+    use tokio_util::sync::CancellationToken;
+
+    #[tokio::test]
+    async fn test_readiness_follows_data_freshness() {
+        let supervisor = Supervisor::new(
+            Backoff {
+                initial: Duration::from_millis(10),
+                max: Duration::from_millis(100),
+            },
+            1,
+            Supervisor::parse_critical("socketio"),
+            CancellationToken::new(),
+        );
+        let devices = Arc::new(RwLock::new(DeviceRegistry::new(300)));
+        let socketio = Arc::new(SocketIOStatus::default());
+        socketio.listening.store(true, Ordering::Relaxed);
+        let endpoint = HealthEndpoint::new(
+            supervisor,
+            Vec::new(),
+            devices.clone(),
+            Some(socketio.clone()),
+            30,
+        );
+
+        let report = endpoint.report(Utc::now()).await;
+        assert!(report.live);
+        assert!(!report.ready);
+        assert_eq!(report.problems, vec!["no frame received".to_string()]);
+
+        devices.write().await.record(&ProcessedData::new(
+            "VR-1".to_string(),
+            vec![ProcessedRoom {
+                room_index: 0,
+                room_name: "OR-1".to_string(),
+                tracks: vec![ProcessedTrack::synthetic("HR", Some(70.0), "bpm", 0, "OR-1", Utc::now())],
+            }],
+        ));
+        let report = endpoint.report(Utc::now()).await;
+        assert!(report.ready);
+        assert_eq!(report.known_devices, 1);
+
+        // Listening state of this processor's server, not of the process
+        socketio.listening.store(false, Ordering::Relaxed);
+        let report = endpoint.report(Utc::now()).await;
+        assert!(!report.ready);
+        assert_eq!(report.socketio_listening, Some(false));
+        assert_eq!(report.problems, vec!["Socket.IO server not listening".to_string()]);
+        socketio.listening.store(true, Ordering::Relaxed);
+
+        let report = endpoint.report(Utc::now() + chrono::Duration::seconds(60)).await;
+        assert!(report.live);
+        assert!(!report.ready);
+
+        let response = endpoint
+            .handle(&HttpRequest::parse("GET /healthz HTTP/1.1\r\n\r\n").unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status, 200);
+    }
+}
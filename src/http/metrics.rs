@@ -0,0 +1,27 @@
+// /src/http/metrics.rs
+// Module: http.metrics
+// Purpose: /metrics endpoint exporting the processing metrics to Prometheus
+
+use crate::http::{HttpHandler, HttpRequest, HttpResponse};
+use crate::metrics::metrics;
+use async_trait::async_trait;
+
+/// Content type of the Prometheus text exposition format
+const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// ID SRS: SRS-MOD-HTTPMETRICS-001
+/// Title: MetricsEndpoint
+///
+/// Description: VRConnect shall serve the processing metrics on `/metrics`
+/// in the Prometheus text format.
+///
+/// Version: V1.0
+#[derive(Debug, Default)]
+pub struct MetricsEndpoint;
+
+#[async_trait]
+impl HttpHandler for MetricsEndpoint {
+    async fn handle(&self, request: &HttpRequest) -> Option<HttpResponse> {
+        (request.path == "/metrics").then(|| HttpResponse::text(200, PROMETHEUS_CONTENT_TYPE, metrics().render()))
+    }
+}
@@ -0,0 +1,13 @@
+// /src/http/mod.rs
+// Module: http
+// Purpose: HTTP server for the observability endpoints (metrics, health) and the REST API
+
+pub mod api;
+pub mod health;
+pub mod metrics;
+pub mod server;
+
+pub use api::ApiEndpoint;
+pub use health::{HealthEndpoint, HealthReport};
+pub use metrics::MetricsEndpoint;
+pub use server::{HttpHandler, HttpRequest, HttpResponse, HttpServer};
@@ -0,0 +1,407 @@
+// /src/http/server.rs
+// Module: http.server
+// Purpose: Minimal HTTP/1.1 server answering GET requests through route handlers
+
+use crate::error::{Result, VitalError};
+use async_trait::async_trait;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio_util::sync::CancellationToken;
+use tokio_util::task::TaskTracker;
+
+/// Maximum size of a request head (request line and headers)
+const MAX_REQUEST_BYTES: usize = 8192;
+
+/// Time allowed to a client to send its request
+const REQUEST_TIMEOUT_SECS: u64 = 5;
+
+/// ID SRS: SRS-MOD-HTTP-001
+/// Title: HttpRequest
+///
+/// Description: VRConnect shall provide the method, path and query
+/// parameters of an HTTP request to the route handlers.
+///
+/// Version: V1.0
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpRequest {
+    pub method: String,
+    pub path: String,
+    pub query: BTreeMap<String, String>,
+}
+
+impl HttpRequest {
+    /// ID SRS: SRS-FN-HTTP-001
+    /// Title: parse
+    ///
+    /// Description: VRConnect shall parse the request line of an HTTP
+    /// request ("GET /path?key=value HTTP/1.1"), decoding the query
+    /// parameters.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `head` - Request head
+    ///
+    /// # Returns
+    /// Parsed request, or None if the request line is malformed
+    pub fn parse(head: &str) -> Option<Self> {
+        let mut parts = head.lines().next()?.split_whitespace();
+        let method = parts.next()?.to_string();
+        let target = parts.next()?;
+        if !parts.next()?.starts_with("HTTP/") {
+            return None;
+        }
+
+        let (path, query) = target.split_once('?').unwrap_or((target, ""));
+        let query = query
+            .split('&')
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| {
+                let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+                (percent_decode(key), percent_decode(value))
+            })
+            .collect();
+
+        Some(Self {
+            method,
+            path: percent_decode(path),
+            query,
+        })
+    }
+}
+
+/// Decode a URL component (%XX escapes, '+' as space)
+fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).ok();
+                match hex.and_then(|hex| u8::from_str_radix(hex, 16).ok()) {
+                    Some(byte) => {
+                        decoded.push(byte);
+                        i += 2;
+                    }
+                    None => decoded.push(b'%'),
+                }
+            }
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// ID SRS: SRS-MOD-HTTP-002
+/// Title: HttpResponse
+///
+/// Description: VRConnect shall answer HTTP requests with a status code, a
+/// content type and a body.
+///
+/// Version: V1.0
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub content_type: String,
+    pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+    /// ID SRS: SRS-FN-HTTP-002
+    /// Title: text
+    ///
+    /// Description: VRConnect shall create a plain text response.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `status` - HTTP status code
+    /// * `content_type` - Content type
+    /// * `body` - Response body
+    ///
+    /// # Returns
+    /// New HttpResponse instance
+    pub fn text(status: u16, content_type: &str, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            content_type: content_type.to_string(),
+            body: body.into().into_bytes(),
+        }
+    }
+
+    /// ID SRS: SRS-FN-HTTP-003
+    /// Title: json
+    ///
+    /// Description: VRConnect shall create a JSON response from a
+    /// serializable value.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `status` - HTTP status code
+    /// * `value` - Value serialized as the body
+    ///
+    /// # Returns
+    /// New HttpResponse instance (500 if serialization fails)
+    pub fn json<T: Serialize>(status: u16, value: &T) -> Self {
+        match serde_json::to_vec(value) {
+            Ok(body) => Self {
+                status,
+                content_type: "application/json".to_string(),
+                body,
+            },
+            Err(e) => Self::error(500, &format!("Serialization failed: {}", e)),
+        }
+    }
+
+    /// ID SRS: SRS-FN-HTTP-004
+    /// Title: error
+    ///
+    /// Description: VRConnect shall create a JSON error response
+    /// ({"error": message}).
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `status` - HTTP status code
+    /// * `message` - Error message
+    ///
+    /// # Returns
+    /// New HttpResponse instance
+    pub fn error(status: u16, message: &str) -> Self {
+        Self {
+            status,
+            content_type: "application/json".to_string(),
+            body: serde_json::json!({ "error": message }).to_string().into_bytes(),
+        }
+    }
+
+    /// Serialize the status line, headers and body
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            self.status,
+            reason(self.status),
+            self.content_type,
+            self.body.len()
+        )
+        .into_bytes();
+        bytes.extend_from_slice(&self.body);
+        bytes
+    }
+}
+
+/// Reason phrase of a status code
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        500 => "Internal Server Error",
+        503 => "Service Unavailable",
+        _ => "",
+    }
+}
+
+/// ID SRS: SRS-MOD-HTTP-003
+/// Title: HttpHandler
+///
+/// Description: VRConnect shall serve every HTTP endpoint (metrics, health,
+/// API) through a common route handler interface.
+///
+/// Version: V1.0
+#[async_trait]
+pub trait HttpHandler: Send + Sync {
+    /// Response to a GET request, or None if the path is not handled
+    async fn handle(&self, request: &HttpRequest) -> Option<HttpResponse>;
+}
+
+/// ID SRS: SRS-MOD-HTTP-004
+/// Title: HttpServer
+///
+/// Description: VRConnect shall serve the enabled HTTP endpoints on a
+/// dedicated port, one request per connection.
+///
+/// Version: V1.0
+pub struct HttpServer {
+    host: String,
+    port: u16,
+    handlers: Vec<Arc<dyn HttpHandler>>,
+}
+
+impl HttpServer {
+    /// ID SRS: SRS-FN-HTTP-005
+    /// Title: new
+    ///
+    /// Description: VRConnect shall construct an HttpServer without routes.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `host` - Listening address
+    /// * `port` - Listening port
+    ///
+    /// # Returns
+    /// New HttpServer instance
+    pub fn new(host: String, port: u16) -> Self {
+        Self {
+            host,
+            port,
+            handlers: Vec::new(),
+        }
+    }
+
+    /// ID SRS: SRS-FN-HTTP-006
+    /// Title: with_handler
+    ///
+    /// Description: VRConnect shall add a route handler, tried after the
+    /// handlers already added.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `handler` - Route handler
+    ///
+    /// # Returns
+    /// HttpServer with the handler
+    pub fn with_handler(mut self, handler: Arc<dyn HttpHandler>) -> Self {
+        self.handlers.push(handler);
+        self
+    }
+
+    /// ID SRS: SRS-FN-HTTP-007
+    /// Title: run
+    ///
+    /// Description: VRConnect shall accept HTTP connections until shutdown,
+    /// then wait for the requests in progress.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `shutdown` - Shutdown token
+    ///
+    /// # Returns
+    /// Result indicating success or error (bind failure)
+    pub async fn run(self: Arc<Self>, shutdown: CancellationToken) -> Result<()> {
+        let addr = format!("{}:{}", self.host, self.port);
+        let listener = TcpListener::bind(&addr).await.map_err(VitalError::Io)?;
+        log::info!("✓ HTTP server listening on {}", addr);
+
+        let connections = TaskTracker::new();
+        loop {
+            let accepted = tokio::select! {
+                accepted = listener.accept() => accepted,
+                _ = shutdown.cancelled() => break,
+            };
+
+            match accepted {
+                Ok((stream, addr)) => {
+                    let server = self.clone();
+                    connections.spawn(async move {
+                        if let Err(e) = server.handle_connection(stream).await {
+                            log::debug!("HTTP connection error from {}: {}", addr, e);
+                        }
+                    });
+                }
+                Err(e) => log::error!("Failed to accept HTTP connection: {}", e),
+            }
+        }
+
+        connections.close();
+        connections.wait().await;
+        log::info!("HTTP server stopped");
+        Ok(())
+    }
+
+    /// ID SRS: SRS-FN-HTTP-008
+    /// Title: respond
+    ///
+    /// Description: VRConnect shall route a request to the first handler
+    /// answering it: 404 if none does, 405 for methods other than GET.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `request` - Parsed request
+    ///
+    /// # Returns
+    /// Response
+    pub async fn respond(&self, request: &HttpRequest) -> HttpResponse {
+        if request.method != "GET" {
+            return HttpResponse::error(405, "Only GET is supported");
+        }
+        for handler in &self.handlers {
+            if let Some(response) = handler.handle(request).await {
+                return response;
+            }
+        }
+        HttpResponse::error(404, &format!("No route for {}", request.path))
+    }
+
+    /// ID SRS: SRS-FN-HTTP-009
+    /// Title: handle_connection
+    ///
+    /// Description: VRConnect shall read the head of one request (bounded in
+    /// size and time), answer it and close the connection.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `stream` - Client connection
+    ///
+    /// # Returns
+    /// Result indicating success or error
+    async fn handle_connection(&self, mut stream: TcpStream) -> Result<()> {
+        let mut head = Vec::new();
+        let mut buffer = [0u8; 1024];
+        let read = tokio::time::timeout(std::time::Duration::from_secs(REQUEST_TIMEOUT_SECS), async {
+            while !head.windows(4).any(|window| window == b"\r\n\r\n") && head.len() < MAX_REQUEST_BYTES {
+                let count = stream.read(&mut buffer).await?;
+                if count == 0 {
+                    break;
+                }
+                head.extend_from_slice(&buffer[..count]);
+            }
+            Ok::<_, std::io::Error>(())
+        })
+        .await;
+
+        let response = match read {
+            Ok(Ok(())) => match HttpRequest::parse(&String::from_utf8_lossy(&head)) {
+                Some(request) => self.respond(&request).await,
+                None => HttpResponse::error(400, "Malformed request"),
+            },
+            Ok(Err(e)) => return Err(VitalError::Io(e)),
+            Err(_) => HttpResponse::error(400, "Request timeout"),
+        };
+
+        stream.write_all(&response.to_bytes()).await?;
+        stream.shutdown().await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_request_line() {
+        let request = HttpRequest::parse("GET /api/devices/VR%201/history?track=HR&max_points=100 HTTP/1.1\r\nHost: x\r\n\r\n")
+            .unwrap();
+        assert_eq!(request.method, "GET");
+        assert_eq!(request.path, "/api/devices/VR 1/history");
+        assert_eq!(request.query.get("track").map(String::as_str), Some("HR"));
+        assert_eq!(request.query.get("max_points").map(String::as_str), Some("100"));
+
+        assert!(HttpRequest::parse("garbage\r\n\r\n").is_none());
+        assert_eq!(percent_decode("a+b%2Fc%"), "a b/c%");
+    }
+}
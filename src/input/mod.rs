@@ -4,5 +4,8 @@
 
 pub mod decompressor;
 pub mod socketio_server;
+pub mod source;
 
-pub use socketio_server::SocketIOServer;
+pub use decompressor::VitalDataDecompressor;
+pub use socketio_server::{SocketIOServer, SocketIOStatus};
+pub use source::InputSource;
@@ -4,16 +4,85 @@
 
 use crate::domain::ProcessedData;
 use crate::error::{Result, VitalError};
-use crate::input::decompressor::VitalDataDecompressor;
-use crate::processor::{VitalDataCleaner, VitalDataTransformer};
+use crate::input::InputSource;
+use crate::metrics::metrics;
+use crate::pipeline::RawPipeline;
+use crate::processor::VitalDataTransformer;
+use async_trait::async_trait;
+use futures_util::stream::SplitSink;
 use futures_util::{SinkExt, StreamExt};
 use std::fs::File;
 use std::io::Write;
 use std::net::SocketAddr;
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering};
 use std::sync::Arc;
 use tokio::net::{TcpListener, TcpStream};
 use tokio::sync::{mpsc, RwLock};
-use tokio_tungstenite::{accept_async, tungstenite::Message};
+use tokio_tungstenite::{accept_async, tungstenite::Message, WebSocketStream};
+use tokio_util::sync::CancellationToken;
+use tokio_util::task::TaskTracker;
+
+/// Number of dropped frames between two input queue warnings
+const DROP_WARNING_INTERVAL: u64 = 100;
+
+/// Hands processed frames to the bounded processing queue without waiting
+struct FrameSender {
+    tx: mpsc::Sender<ProcessedData>,
+    dropped: AtomicU64,
+}
+
+impl FrameSender {
+    /// ID SRS: SRS-FN-SOCKETIO-005
+    /// Title: send
+    ///
+    /// Description: VRConnect shall queue a processed frame for processing
+    /// without blocking the connection reader, dropping and counting the frame
+    /// when the processing queue is full.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `data` - Processed frame
+    fn send(&self, data: ProcessedData) {
+        match self.tx.try_send(data) {
+            Ok(()) => {}
+            Err(mpsc::error::TrySendError::Full(_)) => {
+                metrics().frames_dropped.with_label("socketio").inc();
+                let dropped = self.dropped.fetch_add(1, Ordering::Relaxed) + 1;
+                if dropped == 1 || dropped.is_multiple_of(DROP_WARNING_INTERVAL) {
+                    log::warn!("⚠️  Processing queue full: {} input frames dropped", dropped);
+                }
+            }
+            Err(e) => log::error!("Failed to send processed data: {}", e),
+        }
+    }
+}
+
+/// ID SRS: SRS-MOD-SOCKETIO-002
+/// Title: SocketIOStatus
+///
+/// Description: VRConnect shall expose whether a Socket.IO server is
+/// listening and how many devices are connected to it, for the health report
+/// of its own processor.
+///
+/// Version: V1.0
+#[derive(Debug, Default)]
+pub struct SocketIOStatus {
+    pub(crate) listening: AtomicBool,
+    pub(crate) connections: AtomicI64,
+}
+
+impl SocketIOStatus {
+    /// Whether the server is bound and accepting connections
+    pub fn listening(&self) -> bool {
+        self.listening.load(Ordering::Relaxed)
+    }
+
+    /// Number of connected devices
+    pub fn connections(&self) -> i64 {
+        self.connections.load(Ordering::Relaxed)
+    }
+}
 
 /// ID SRS: SRS-MOD-SOCKETIO-001
 /// Title: SocketIOServer
@@ -27,9 +96,9 @@ pub struct SocketIOServer {
     port: u16,
     debug_enabled: bool,
     debug_file: Arc<RwLock<Option<File>>>,
-    decompressor: VitalDataDecompressor,
-    cleaner: VitalDataCleaner,
+    raw_pipeline: Arc<RawPipeline>,
     transformer: VitalDataTransformer,
+    status: Arc<SocketIOStatus>,
 }
 
 impl SocketIOServer {
@@ -60,65 +129,119 @@ impl SocketIOServer {
             port,
             debug_enabled,
             debug_file,
-            decompressor: VitalDataDecompressor::new(),
-            cleaner: VitalDataCleaner::new(),
+            raw_pipeline: Arc::new(RawPipeline::new()),
             transformer: VitalDataTransformer::new(),
+            status: Arc::new(SocketIOStatus::default()),
         }
     }
 
+    /// ID SRS: SRS-FN-SOCKETIO-007
+    /// Title: with_raw_pipeline
+    ///
+    /// Description: VRConnect shall apply the given pre-parse stages to the
+    /// received frames instead of the default decompress and clean stages.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `raw_pipeline` - Pre-parse stages
+    ///
+    /// # Returns
+    /// SocketIOServer with the raw pipeline
+    pub fn with_raw_pipeline(mut self, raw_pipeline: RawPipeline) -> Self {
+        self.raw_pipeline = Arc::new(raw_pipeline);
+        self
+    }
+
+    /// ID SRS: SRS-FN-SOCKETIO-008
+    /// Title: with_status
+    ///
+    /// Description: VRConnect shall report the listening state and connected
+    /// devices of the server in the given status, in addition to the
+    /// process-wide metrics.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `status` - Status shared with the health report
+    ///
+    /// # Returns
+    /// SocketIOServer reporting to the status
+    pub fn with_status(mut self, status: Arc<SocketIOStatus>) -> Self {
+        self.status = status;
+        self
+    }
+
     /// ID SRS: SRS-FN-SOCKETIO-002
     /// Title: start
     ///
     /// Description: VRConnect shall start the Socket.IO WebSocket server,
-    /// accepting connections and processing incoming vital data.
+    /// accepting connections and processing incoming vital data until
+    /// shutdown, then close the connections and wait for their handlers.
     ///
     /// Version: V1.0
     ///
     /// # Arguments
-    /// * `tx` - Channel sender for processed data
+    /// * `tx` - Bounded channel sender for processed data
+    /// * `shutdown` - Shutdown token
     ///
     /// # Returns
     /// Result indicating success or error
-    pub async fn start(&self, tx: mpsc::UnboundedSender<ProcessedData>) -> Result<()> {
+    pub async fn start(&self, tx: mpsc::Sender<ProcessedData>, shutdown: CancellationToken) -> Result<()> {
         let addr = format!("{}:{}", self.host, self.port);
         let listener = TcpListener::bind(&addr)
             .await
             .map_err(|e| VitalError::Io(e))?;
 
         log::info!("Socket.IO v4 WebSocket server listening on {}", addr);
+        metrics().socketio_listening.set(1);
+        self.status.listening.store(true, Ordering::Relaxed);
         log::info!("✓ Socket.IO server started");
 
-        let tx = Arc::new(tx);
-        let decompressor = Arc::new(self.decompressor.clone());
-        let cleaner = Arc::new(self.cleaner.clone());
+        let tx = Arc::new(FrameSender {
+            tx,
+            dropped: AtomicU64::new(0),
+        });
+        let raw_pipeline = self.raw_pipeline.clone();
         let transformer = Arc::new(self.transformer.clone());
         let debug_file = self.debug_file.clone();
         let debug_enabled = self.debug_enabled;
+        let connections = TaskTracker::new();
 
         loop {
-            match listener.accept().await {
+            let accepted = tokio::select! {
+                accepted = listener.accept() => accepted,
+                _ = shutdown.cancelled() => break,
+            };
+
+            match accepted {
                 Ok((stream, addr)) => {
                     let tx = tx.clone();
-                    let decompressor = decompressor.clone();
-                    let cleaner = cleaner.clone();
+                    let raw_pipeline = raw_pipeline.clone();
                     let transformer = transformer.clone();
                     let debug_file = debug_file.clone();
+                    let shutdown = shutdown.clone();
+                    let status = self.status.clone();
 
-                    tokio::spawn(async move {
+                    connections.spawn(async move {
+                        metrics().connected_devices.inc();
+                        status.connections.fetch_add(1, Ordering::Relaxed);
                         if let Err(e) = Self::handle_connection(
                             stream,
                             addr,
                             tx,
-                            decompressor,
-                            cleaner,
+                            raw_pipeline,
                             transformer,
                             debug_enabled,
                             debug_file,
+                            shutdown,
                         )
                         .await
                         {
                             log::error!("Connection error from {}: {}", addr, e);
                         }
+                        metrics().connected_devices.dec();
+                        status.connections.fetch_sub(1, Ordering::Relaxed);
                     });
                 }
                 Err(e) => {
@@ -126,6 +249,17 @@ impl SocketIOServer {
                 }
             }
         }
+
+        drop(listener);
+        metrics().socketio_listening.set(0);
+        self.status.listening.store(false, Ordering::Relaxed);
+        log::info!("Socket.IO server stopped accepting connections");
+
+        connections.close();
+        connections.wait().await;
+        log::info!("✓ Socket.IO connections closed");
+
+        Ok(())
     }
 
     /// ID SRS: SRS-FN-SOCKETIO-003
@@ -140,23 +274,23 @@ impl SocketIOServer {
     /// * `stream` - TCP stream
     /// * `addr` - Client address
     /// * `tx` - Data channel sender
-    /// * `decompressor` - Decompressor instance
-    /// * `cleaner` - Data cleaner instance
+    /// * `raw_pipeline` - Pre-parse stages
     /// * `transformer` - Data transformer instance
     /// * `debug_enabled` - Debug mode flag
     /// * `debug_file` - Debug file handle
+    /// * `shutdown` - Shutdown token
     ///
     /// # Returns
     /// Result indicating success or error
     async fn handle_connection(
         stream: TcpStream,
         addr: SocketAddr,
-        tx: Arc<mpsc::UnboundedSender<ProcessedData>>,
-        decompressor: Arc<VitalDataDecompressor>,
-        cleaner: Arc<VitalDataCleaner>,
+        tx: Arc<FrameSender>,
+        raw_pipeline: Arc<RawPipeline>,
         transformer: Arc<VitalDataTransformer>,
         debug_enabled: bool,
         debug_file: Arc<RwLock<Option<File>>>,
+        shutdown: CancellationToken,
     ) -> Result<()> {
         log::info!("New Socket.IO v4 connection from {}", addr);
 
@@ -193,7 +327,19 @@ impl SocketIOServer {
 
         let mut pending_binary_event: Option<String> = None;
 
-        while let Some(msg) = read.next().await {
+        loop {
+            // A frame being decoded is completed before the connection closes
+            let msg = tokio::select! {
+                msg = read.next() => match msg {
+                    Some(msg) => msg,
+                    None => break,
+                },
+                _ = shutdown.cancelled() => {
+                    Self::close_connection(&mut write, addr).await;
+                    break;
+                }
+            };
+
             match msg {
                 Ok(Message::Text(text)) => {
                     log::debug!("Received text message from {}: {}", addr, text);
@@ -255,10 +401,10 @@ impl SocketIOServer {
                     }
 
                     if pending_binary_event.take().is_some() {
+                        metrics().frames_received.with_label("socketio").inc();
                         match Self::process_data(
-                            &data,
-                            &decompressor,
-                            &cleaner,
+                            data,
+                            &raw_pipeline,
                             &transformer,
                             debug_enabled,
                             &debug_file,
@@ -272,9 +418,7 @@ impl SocketIOServer {
                                     processed_data.all_tracks.len()
                                 );
 
-                                if let Err(e) = tx.send(processed_data) {
-                                    log::error!("Failed to send processed data: {}", e);
-                                }
+                                tx.send(processed_data);
                             }
                             Err(e) => {
                                 log::error!("Error processing data from {}: {}", addr, e);
@@ -305,18 +449,42 @@ impl SocketIOServer {
         Ok(())
     }
 
+    /// ID SRS: SRS-FN-SOCKETIO-006
+    /// Title: close_connection
+    ///
+    /// Description: VRConnect shall close a connection on shutdown with a
+    /// Socket.IO disconnect packet, an Engine.IO close packet and a WebSocket
+    /// close frame, so that VitalRecorder reconnects instead of timing out.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `write` - WebSocket sink of the connection
+    /// * `addr` - Client address
+    async fn close_connection(write: &mut SplitSink<WebSocketStream<TcpStream>, Message>, addr: SocketAddr) {
+        log::info!("Closing Socket.IO connection {} (shutdown)", addr);
+
+        for packet in ["41", "1"] {
+            if let Err(e) = write.send(Message::Text(packet.to_string())).await {
+                log::debug!("Failed to send close packet to {}: {}", addr, e);
+                return;
+            }
+        }
+        let _ = write.send(Message::Close(None)).await;
+    }
+
     /// ID SRS: SRS-FN-SOCKETIO-004
     /// Title: process_data
     ///
-    /// Description: VRConnect shall process binary data through decompression,
-    /// cleaning, and transformation pipeline, with optional debug logging.
+    /// Description: VRConnect shall process binary data through the pre-parse
+    /// stages (by default decompression and cleaning), parsing and
+    /// transformation, with optional debug logging after every stage.
     ///
     /// Version: V1.0
     ///
     /// # Arguments
     /// * `data` - Raw binary data
-    /// * `decompressor` - Decompressor instance
-    /// * `cleaner` - Data cleaner instance
+    /// * `raw_pipeline` - Pre-parse stages
     /// * `transformer` - Data transformer instance
     /// * `debug_enabled` - Debug mode flag
     /// * `debug_file` - Debug file handle
@@ -324,54 +492,41 @@ impl SocketIOServer {
     /// # Returns
     /// Processed vital data or error
     async fn process_data(
-        data: &[u8],
-        decompressor: &VitalDataDecompressor,
-        cleaner: &VitalDataCleaner,
+        data: Vec<u8>,
+        raw_pipeline: &RawPipeline,
         transformer: &VitalDataTransformer,
         debug_enabled: bool,
         debug_file: &Arc<RwLock<Option<File>>>,
     ) -> Result<ProcessedData> {
-        // Step 1: Decompress
-        let decompressed = decompressor.decompress(data)?;
-        log::debug!("Decompressed data length: {}", decompressed.len());
-
-        // Debug log decompressed
-        if debug_enabled {
-            if let Some(ref mut file) = *debug_file.write().await {
-                let _ = writeln!(
-                    file,
-                    "\n=== DECOMPRESSED DATA ===\nLength: {} bytes\n",
-                    decompressed.len()
-                );
-            }
-        }
-
-        // Step 2: Convert to string
-        let json_str = String::from_utf8(decompressed)
-            .map_err(|e| VitalError::Processing(format!("UTF-8 conversion failed: {}", e)))?;
-
-        // Debug log raw JSON
-        if debug_enabled {
-            if let Some(ref mut file) = *debug_file.write().await {
-                let _ = writeln!(file, "\n=== RAW JSON ===\n{}\n", json_str);
-            }
-        }
-
-        // Step 3: Clean JSON
-        let cleaned_json = cleaner.clean(&json_str)?;
-
-        // Debug log cleaned JSON
-        if debug_enabled {
-            if let Some(ref mut file) = *debug_file.write().await {
-                let _ = writeln!(file, "\n=== CLEANED JSON ===\n{}\n", cleaned_json);
+        // Steps 1..n: Pre-parse stages (decompress, clean, user-defined)
+        let mut data = data;
+        for stage in raw_pipeline.stages() {
+            data = stage.process(data)?;
+            log::debug!("Raw stage '{}' output length: {}", stage.name(), data.len());
+
+            // Debug log stage output
+            if debug_enabled {
+                if let Some(ref mut file) = *debug_file.write().await {
+                    let _ = writeln!(
+                        file,
+                        "\n=== RAW STAGE {} ===\nLength: {} bytes\n{}\n",
+                        stage.name().to_uppercase(),
+                        data.len(),
+                        String::from_utf8_lossy(&data)
+                    );
+                }
             }
         }
 
-        // Step 4: Parse to VitalData
-        let vital_data: crate::domain::VitalData = serde_json::from_str(&cleaned_json)?;
+        // Parse to VitalData
+        let vital_data: crate::domain::VitalData = serde_json::from_slice(&data).inspect_err(|_| {
+            metrics().parse_failures.inc();
+        })?;
 
-        // Step 5: Transform to ProcessedData
+        // Transform to ProcessedData
+        let started = std::time::Instant::now();
         let processed_data = transformer.transform(vital_data);
+        metrics().transform_duration.observe(started.elapsed().as_secs_f64());
 
         // Debug log processed structure
         if debug_enabled {
@@ -390,6 +545,17 @@ impl SocketIOServer {
     }
 }
 
+#[async_trait]
+impl InputSource for SocketIOServer {
+    fn name(&self) -> &str {
+        "socketio"
+    }
+
+    async fn run(&self, tx: mpsc::Sender<ProcessedData>, shutdown: CancellationToken) -> Result<()> {
+        self.start(tx, shutdown).await
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,27 @@
+// /src/input/source.rs
+// Module: input.source
+// Purpose: Common interface of the inputs feeding processed data to the processor
+
+use crate::domain::ProcessedData;
+use crate::error::Result;
+use async_trait::async_trait;
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+
+/// ID SRS: SRS-MOD-SOURCE-001
+/// Title: InputSource
+///
+/// Description: VRConnect shall receive processed data from every input
+/// through a common interface, so that inputs other than the Socket.IO
+/// server (replay, in-process producers) can feed the processor.
+///
+/// Version: V1.0
+#[async_trait]
+pub trait InputSource: Send + Sync {
+    /// Input name used in logs and supervision reports
+    fn name(&self) -> &str;
+
+    /// Receive data into the processing queue until shutdown; an error or an
+    /// early return makes the supervisor restart the input
+    async fn run(&self, tx: mpsc::Sender<ProcessedData>, shutdown: CancellationToken) -> Result<()>;
+}
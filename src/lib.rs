@@ -0,0 +1,28 @@
+// /src/lib.rs
+// Module: vrconnect
+// Purpose: Library exposing the VRConnect pipeline for embedding in other applications
+
+pub mod alarm;
+pub mod config;
+pub mod core;
+pub mod derived;
+pub mod domain;
+pub mod error;
+pub mod ffi;
+pub mod http;
+pub mod input;
+pub mod metrics;
+pub mod output;
+pub mod pipeline;
+pub mod processor;
+pub mod signal;
+pub mod utils;
+
+pub use crate::config::Config;
+pub use crate::core::{VitalProcessor, VitalProcessorBuilder};
+pub use crate::domain::{AlarmEvent, ProcessedData, ProcessedRoom, ProcessedTrack, VitalData};
+pub use crate::error::{Result, VitalError};
+pub use crate::input::{InputSource, SocketIOServer, VitalDataDecompressor};
+pub use crate::output::{OutputMode, OutputSink, SinkHealth};
+pub use crate::pipeline::{PipelineStage, RawStage, StagePosition};
+pub use crate::processor::{VitalDataCleaner, VitalDataTransformer};
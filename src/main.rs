@@ -2,18 +2,8 @@
 // Module: main
 // Purpose: Application entry point with initialization and lifecycle management
 
-mod config;
-mod core;
-mod domain;
-mod error;
-mod input;
-mod output;
-mod processor;
-mod utils;
-
-use crate::config::Config;
-use crate::core::VitalProcessor;
-use crate::utils::logger::Logger;
+use vrconnect::utils::logger::Logger;
+use vrconnect::{Config, VitalProcessor};
 
 /// ID SRS: SRS-MAIN-001
 /// Title: main
@@ -50,6 +40,7 @@ async fn main() {
     }
 
     log::info!("VRConnect stopped gracefully");
+    log::logger().flush();
 }
 
 /// ID SRS: SRS-UTIL-001
@@ -67,11 +58,21 @@ fn print_banner(config: &Config) {
     println!("  VRConnect - Medical Vital Data Middleware v1.0.0");
     println!("{}", "═".repeat(70));
     println!("  Socket.IO Server: {}:{}", config.socketio_host, config.socketio_port);
+    println!("  Queues:           input {}, output {}", config.input_queue_capacity, config.output_queue_capacity);
+    println!("  Critical:         {}", config.supervisor_critical);
     println!("  Console Output:   {}", if config.output_console_enabled { "Enabled" } else { "Disabled" });
     
     if config.output_console_enabled {
         println!("    └─ Verbose:     {}", config.output_console_verbose);
         println!("    └─ Colorized:   {}", config.output_console_colorized);
+        if !config.output_console_waveform_decimation.is_empty() {
+            println!("    └─ Decimation:  {}", config.output_console_waveform_decimation);
+        }
+        if !config.output_console_devices.is_empty() {
+            println!("    └─ Devices:     {}", config.output_console_devices);
+        }
+        println!("    └─ Mode:        {}", config.output_console_mode);
+        println!("    └─ Overflow:    {}", config.output_console_overflow);
     }
     
     println!("  BLE Output:       {}", if config.output_ble_enabled { "Enabled" } else { "Disabled" });
@@ -79,9 +80,101 @@ fn print_banner(config: &Config) {
     if config.output_ble_enabled {
         println!("    └─ Device Name: {}", config.output_ble_device_name);
         println!("    └─ Service UUID: {}", config.output_ble_service_uuid);
-        println!("    └─ ⚠️  Waveforms excluded (MTU limit)");
+        if config.output_ble_waveform_decimation.is_empty() {
+            println!("    └─ ⚠️  Waveforms excluded (MTU limit)");
+        } else {
+            println!("    └─ Waveforms:   {}", config.output_ble_waveform_decimation);
+        }
+        if !config.output_ble_devices.is_empty() {
+            println!("    └─ Devices:     {}", config.output_ble_devices);
+        }
+        println!("    └─ Mode:        {}", config.output_ble_mode);
+        println!("    └─ Overflow:    {}", config.output_ble_overflow);
     }
     
+    println!("  Alarms:           {}", if config.alarm_enabled { "Enabled" } else { "Disabled" });
+
+    if config.alarm_enabled {
+        println!(
+            "    └─ Limits:      {}",
+            config.alarm_limits_path.as_deref().unwrap_or("built-in defaults")
+        );
+    }
+
+    println!("  Watchdog:         {}", if config.watchdog_enabled { "Enabled" } else { "Disabled" });
+
+    if config.watchdog_enabled {
+        println!("    └─ Device Timeout: {}s", config.watchdog_device_timeout_secs);
+        println!("    └─ Track Timeout:  {}s", config.watchdog_track_timeout_secs);
+    }
+
+    println!("  Sequencing:       {}", if config.sequencing_enabled { "Enabled" } else { "Disabled" });
+
+    if config.sequencing_enabled {
+        println!("    └─ Jitter:      {}ms", config.sequencing_jitter_ms);
+    }
+
+    println!("  Clock Sync:       {}", if config.clock_sync_enabled { "Enabled" } else { "Disabled" });
+
+    if config.clock_sync_enabled {
+        println!("    └─ Threshold:   {}ms", config.clock_drift_threshold_ms);
+        println!("    └─ Correction:  {}", if config.clock_correction_enabled { "Enabled" } else { "Disabled" });
+    }
+
+    println!(
+        "  Early Warning:    {}",
+        if config.early_warning_scores.trim().is_empty() { "Disabled" } else { &config.early_warning_scores }
+    );
+
+    println!("  Signal Quality:   {}", if config.signal_quality_enabled { "Enabled" } else { "Disabled" });
+
+    println!("  Extended Stats:   {}", if config.waveform_extended_stats { "Enabled" } else { "Disabled" });
+
+    println!(
+        "  Waveform Filters: {}",
+        if config.waveform_filters.trim().is_empty() { "Disabled" } else { &config.waveform_filters }
+    );
+
+    println!("  ECG QRS:          {}", if config.qrs_detection_enabled { "Enabled" } else { "Disabled" });
+
+    println!("  Trends:           {}", if config.trend_enabled { "Enabled" } else { "Disabled" });
+
+    if config.trend_enabled {
+        println!("    └─ Window:      {}s", config.trend_window_secs);
+    }
+
+    println!("  Pipeline:         {}", config.pipeline_stages);
+
+    if !config.unit_conversions.trim().is_empty() {
+        println!("    └─ Units:       {}", config.unit_conversions);
+    }
+
+    println!("  De-identify:      {}", if config.deidentify_enabled { "Enabled" } else { "Disabled" });
+
+    println!("  Metrics:          {}", if config.metrics_enabled { "Enabled" } else { "Disabled" });
+
+    if config.metrics_enabled {
+        println!("    └─ Endpoint:    http://{}:{}/metrics", config.http_host, config.http_port);
+    }
+
+    println!("  Health:           {}", if config.health_enabled { "Enabled" } else { "Disabled" });
+
+    if config.health_enabled {
+        println!("    └─ Endpoints:   http://{}:{}/healthz, /readyz", config.http_host, config.http_port);
+    }
+
+    println!("  REST API:         {}", if config.api_enabled { "Enabled" } else { "Disabled" });
+
+    if config.api_enabled {
+        println!("    └─ Endpoint:    http://{}:{}/api", config.http_host, config.http_port);
+    }
+
+    println!("  History:          {}", if config.history_enabled { "Enabled" } else { "Disabled" });
+
+    if config.history_enabled {
+        println!("    └─ Retention:   {}s", config.history_retention_secs);
+    }
+
     println!("  Debug Mode:       {}", if config.debug_enabled { "Enabled" } else { "Disabled" });
     
     if config.debug_enabled {
@@ -91,7 +184,7 @@ fn print_banner(config: &Config) {
     println!("  Log Level:        {}", config.log_level);
     println!("  Log Directory:    {}", config.log_dir);
     println!("{}", "═".repeat(70));
-    println!("  Press Ctrl+C to stop");
+    println!("  Press Ctrl+C to stop (shutdown timeout {}s)", config.shutdown_timeout_secs);
     println!("{}\n", "═".repeat(70));
 }
 
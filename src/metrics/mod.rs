@@ -0,0 +1,158 @@
+// /src/metrics/mod.rs
+// Module: metrics
+// Purpose: Processing metrics shared by the pipeline components, exported in the Prometheus format
+
+pub mod registry;
+
+pub use registry::{Counter, Family, Gauge, Histogram, TextEncoder};
+
+use std::sync::OnceLock;
+
+/// ID SRS: SRS-MOD-METRICS-001
+/// Title: Metrics
+///
+/// Description: VRConnect shall count and time the processing of frames
+/// across the pipeline (inputs, decompression, cleaning, parsing,
+/// transformation, outputs) and track the connected devices and BLE
+/// subscribers, process-wide.
+///
+/// Version: V1.0
+#[derive(Debug)]
+pub struct Metrics {
+    pub frames_received: Family<Counter>,
+    pub frames_dropped: Family<Counter>,
+    pub decompression_bytes_in: Counter,
+    pub decompression_bytes_out: Counter,
+    pub cleaning_repairs: Family<Counter>,
+    pub parse_failures: Counter,
+    pub transform_duration: Histogram,
+    pub sink_duration: Family<Histogram>,
+    pub sink_dropped: Family<Counter>,
+    pub connected_devices: Gauge,
+    pub socketio_listening: Gauge,
+    pub ble_subscribers: Family<Gauge>,
+}
+
+impl Metrics {
+    /// ID SRS: SRS-FN-METRICS-001
+    /// Title: new
+    ///
+    /// Description: VRConnect shall construct a set of metrics at zero.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// New Metrics instance
+    pub fn new() -> Self {
+        Self {
+            frames_received: Family::new("input"),
+            frames_dropped: Family::new("input"),
+            decompression_bytes_in: Counter::default(),
+            decompression_bytes_out: Counter::default(),
+            cleaning_repairs: Family::new("kind"),
+            parse_failures: Counter::default(),
+            transform_duration: Histogram::default(),
+            sink_duration: Family::new("sink"),
+            sink_dropped: Family::new("sink"),
+            connected_devices: Gauge::default(),
+            socketio_listening: Gauge::default(),
+            ble_subscribers: Family::new("characteristic"),
+        }
+    }
+
+    /// ID SRS: SRS-FN-METRICS-002
+    /// Title: render
+    ///
+    /// Description: VRConnect shall render the metrics in the Prometheus text
+    /// exposition format.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Metrics text
+    pub fn render(&self) -> String {
+        let mut encoder = TextEncoder::default();
+        encoder.counter_family(
+            "vrconnect_frames_received_total",
+            "Frames received per input",
+            &self.frames_received,
+        );
+        encoder.counter_family(
+            "vrconnect_frames_dropped_total",
+            "Frames dropped per input because the processing queue was full",
+            &self.frames_dropped,
+        );
+        encoder.counter(
+            "vrconnect_decompression_bytes_in_total",
+            "Bytes entering decompression",
+            &self.decompression_bytes_in,
+        );
+        encoder.counter(
+            "vrconnect_decompression_bytes_out_total",
+            "Bytes leaving decompression",
+            &self.decompression_bytes_out,
+        );
+        encoder.counter_family(
+            "vrconnect_cleaning_repairs_total",
+            "Frames repaired by JSON cleaning, per repair kind",
+            &self.cleaning_repairs,
+        );
+        encoder.counter(
+            "vrconnect_parse_failures_total",
+            "Frames that failed JSON parsing",
+            &self.parse_failures,
+        );
+        encoder.histogram(
+            "vrconnect_transform_duration_seconds",
+            "Time to transform a parsed frame into processed data",
+            &self.transform_duration,
+        );
+        encoder.histogram_family(
+            "vrconnect_sink_duration_seconds",
+            "Time for an output to handle a message",
+            &self.sink_duration,
+        );
+        encoder.counter_family(
+            "vrconnect_sink_dropped_total",
+            "Messages dropped by an output queue",
+            &self.sink_dropped,
+        );
+        encoder.gauge(
+            "vrconnect_connected_devices",
+            "VitalRecorder instances connected to the Socket.IO server",
+            &self.connected_devices,
+        );
+        encoder.gauge(
+            "vrconnect_socketio_listening",
+            "Whether the Socket.IO server is bound to its port (0 or 1)",
+            &self.socketio_listening,
+        );
+        encoder.gauge_family(
+            "vrconnect_ble_subscribers",
+            "BLE clients subscribed to notifications, per characteristic",
+            &self.ble_subscribers,
+        );
+        encoder.finish()
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// ID SRS: SRS-FN-METRICS-003
+/// Title: metrics
+///
+/// Description: VRConnect shall provide the process-wide metrics, updated by
+/// every component.
+///
+/// Version: V1.0
+///
+/// # Returns
+/// Shared metrics
+pub fn metrics() -> &'static Metrics {
+    static METRICS: OnceLock<Metrics> = OnceLock::new();
+    METRICS.get_or_init(Metrics::new)
+}
@@ -0,0 +1,324 @@
+// /src/metrics/registry.rs
+// Module: metrics.registry
+// Purpose: Counters, gauges and histograms rendered in the Prometheus text format
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Latency buckets (seconds), from 100 µs to 2.5 s
+pub const LATENCY_BUCKETS: &[f64] = &[
+    0.0001, 0.00025, 0.0005, 0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5,
+];
+
+/// ID SRS: SRS-MOD-REGISTRY-001
+/// Title: Counter
+///
+/// Description: VRConnect shall count events with a monotonic counter.
+///
+/// Version: V1.0
+#[derive(Debug, Default)]
+pub struct Counter(AtomicU64);
+
+impl Counter {
+    /// Add one to the counter
+    pub fn inc(&self) {
+        self.add(1);
+    }
+
+    /// Add a number of events to the counter
+    pub fn add(&self, count: u64) {
+        self.0.fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// Current count
+    pub fn get(&self) -> u64 {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// ID SRS: SRS-MOD-REGISTRY-002
+/// Title: Gauge
+///
+/// Description: VRConnect shall track a value that goes up and down with a
+/// gauge.
+///
+/// Version: V1.0
+#[derive(Debug, Default)]
+pub struct Gauge(AtomicI64);
+
+impl Gauge {
+    /// Add one to the gauge
+    pub fn inc(&self) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Remove one from the gauge
+    pub fn dec(&self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    /// Set the gauge value
+    pub fn set(&self, value: i64) {
+        self.0.store(value, Ordering::Relaxed);
+    }
+
+    /// Current value
+    pub fn get(&self) -> i64 {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// ID SRS: SRS-MOD-REGISTRY-003
+/// Title: Histogram
+///
+/// Description: VRConnect shall record the distribution of observed values
+/// (latencies) in cumulative buckets, with their sum and count.
+///
+/// Version: V1.0
+#[derive(Debug)]
+pub struct Histogram {
+    bounds: &'static [f64],
+    buckets: Vec<AtomicU64>,
+    count: AtomicU64,
+    sum_bits: AtomicU64,
+}
+
+impl Histogram {
+    /// ID SRS: SRS-FN-REGISTRY-001
+    /// Title: new
+    ///
+    /// Description: VRConnect shall construct an empty histogram.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `bounds` - Upper bounds of the buckets, ascending (+Inf implied)
+    ///
+    /// # Returns
+    /// New Histogram instance
+    pub fn new(bounds: &'static [f64]) -> Self {
+        Self {
+            bounds,
+            buckets: bounds.iter().map(|_| AtomicU64::new(0)).collect(),
+            count: AtomicU64::new(0),
+            sum_bits: AtomicU64::new(0f64.to_bits()),
+        }
+    }
+
+    /// ID SRS: SRS-FN-REGISTRY-002
+    /// Title: observe
+    ///
+    /// Description: VRConnect shall record an observed value in the
+    /// histogram.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `value` - Observed value
+    pub fn observe(&self, value: f64) {
+        if let Some(index) = self.bounds.iter().position(|bound| value <= *bound) {
+            self.buckets[index].fetch_add(1, Ordering::Relaxed);
+        }
+        self.count.fetch_add(1, Ordering::Relaxed);
+        let _ = self.sum_bits.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |bits| {
+            Some((f64::from_bits(bits) + value).to_bits())
+        });
+    }
+
+    /// Number of observed values
+    pub fn count(&self) -> u64 {
+        self.count.load(Ordering::Relaxed)
+    }
+
+    /// Sum of the observed values
+    pub fn sum(&self) -> f64 {
+        f64::from_bits(self.sum_bits.load(Ordering::Relaxed))
+    }
+}
+
+impl Default for Histogram {
+    fn default() -> Self {
+        Self::new(LATENCY_BUCKETS)
+    }
+}
+
+/// ID SRS: SRS-MOD-REGISTRY-004
+/// Title: Family
+///
+/// Description: VRConnect shall keep one metric per value of a label (sink
+/// name, input name, repair kind), created on first use.
+///
+/// Version: V1.0
+#[derive(Debug)]
+pub struct Family<M> {
+    label: &'static str,
+    metrics: Mutex<BTreeMap<String, Arc<M>>>,
+}
+
+impl<M: Default> Family<M> {
+    /// ID SRS: SRS-FN-REGISTRY-003
+    /// Title: new
+    ///
+    /// Description: VRConnect shall construct an empty metric family.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `label` - Label name
+    ///
+    /// # Returns
+    /// New Family instance
+    pub fn new(label: &'static str) -> Self {
+        Self {
+            label,
+            metrics: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// ID SRS: SRS-FN-REGISTRY-004
+    /// Title: with_label
+    ///
+    /// Description: VRConnect shall return the metric of a label value,
+    /// creating it if needed.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `value` - Label value
+    ///
+    /// # Returns
+    /// Shared metric
+    pub fn with_label(&self, value: &str) -> Arc<M> {
+        let mut metrics = self.metrics.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        metrics.entry(value.to_string()).or_default().clone()
+    }
+
+    /// Metrics sorted by label value
+    fn entries(&self) -> Vec<(String, Arc<M>)> {
+        let metrics = self.metrics.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        metrics.iter().map(|(value, metric)| (value.clone(), metric.clone())).collect()
+    }
+}
+
+/// Escape a label value (backslash, double quote, newline)
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+/// ID SRS: SRS-MOD-REGISTRY-005
+/// Title: TextEncoder
+///
+/// Description: VRConnect shall write metrics in the Prometheus text
+/// exposition format (version 0.0.4).
+///
+/// Version: V1.0
+#[derive(Debug, Default)]
+pub struct TextEncoder {
+    output: String,
+}
+
+impl TextEncoder {
+    /// Write the HELP and TYPE lines of a metric
+    fn header(&mut self, name: &str, help: &str, kind: &str) {
+        let _ = writeln!(self.output, "# HELP {} {}", name, help);
+        let _ = writeln!(self.output, "# TYPE {} {}", name, kind);
+    }
+
+    /// Write a counter
+    pub fn counter(&mut self, name: &str, help: &str, counter: &Counter) {
+        self.header(name, help, "counter");
+        let _ = writeln!(self.output, "{} {}", name, counter.get());
+    }
+
+    /// Write a gauge
+    pub fn gauge(&mut self, name: &str, help: &str, gauge: &Gauge) {
+        self.header(name, help, "gauge");
+        let _ = writeln!(self.output, "{} {}", name, gauge.get());
+    }
+
+    /// Write a gauge from label values (computed at scrape time)
+    pub fn gauge_values(&mut self, name: &str, help: &str, label: &str, values: &[(String, f64)]) {
+        self.header(name, help, "gauge");
+        for (value, sample) in values {
+            let _ = writeln!(self.output, "{}{{{}=\"{}\"}} {}", name, label, escape(value), sample);
+        }
+    }
+
+    /// Write a counter family
+    pub fn counter_family(&mut self, name: &str, help: &str, family: &Family<Counter>) {
+        self.header(name, help, "counter");
+        for (value, counter) in family.entries() {
+            let _ = writeln!(self.output, "{}{{{}=\"{}\"}} {}", name, family.label, escape(&value), counter.get());
+        }
+    }
+
+    /// Write a gauge family
+    pub fn gauge_family(&mut self, name: &str, help: &str, family: &Family<Gauge>) {
+        self.header(name, help, "gauge");
+        for (value, gauge) in family.entries() {
+            let _ = writeln!(self.output, "{}{{{}=\"{}\"}} {}", name, family.label, escape(&value), gauge.get());
+        }
+    }
+
+    /// Write a histogram
+    pub fn histogram(&mut self, name: &str, help: &str, histogram: &Histogram) {
+        self.header(name, help, "histogram");
+        self.histogram_samples(name, "", histogram);
+    }
+
+    /// Write a histogram family
+    pub fn histogram_family(&mut self, name: &str, help: &str, family: &Family<Histogram>) {
+        self.header(name, help, "histogram");
+        for (value, histogram) in family.entries() {
+            let label = format!("{}=\"{}\",", family.label, escape(&value));
+            self.histogram_samples(name, &label, &histogram);
+        }
+    }
+
+    /// Write the bucket, sum and count samples of a histogram
+    fn histogram_samples(&mut self, name: &str, label: &str, histogram: &Histogram) {
+        let mut cumulative = 0;
+        for (bound, bucket) in histogram.bounds.iter().zip(&histogram.buckets) {
+            cumulative += bucket.load(Ordering::Relaxed);
+            let _ = writeln!(self.output, "{}_bucket{{{}le=\"{}\"}} {}", name, label, bound, cumulative);
+        }
+        let count = histogram.count();
+        let _ = writeln!(self.output, "{}_bucket{{{}le=\"+Inf\"}} {}", name, label, count);
+
+        let label = label.trim_end_matches(',');
+        let labels = if label.is_empty() { String::new() } else { format!("{{{}}}", label) };
+        let _ = writeln!(self.output, "{}_sum{} {}", name, labels, histogram.sum());
+        let _ = writeln!(self.output, "{}_count{} {}", name, labels, count);
+    }
+
+    /// Rendered metrics
+    pub fn finish(self) -> String {
+        self.output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_histogram_rendering() {
+        let family: Family<Histogram> = Family::new("sink");
+        family.with_label("console").observe(0.0003);
+        family.with_label("console").observe(5.0);
+
+        let mut encoder = TextEncoder::default();
+        encoder.histogram_family("latency_seconds", "Latency", &family);
+        let text = encoder.finish();
+
+        assert!(text.contains("# TYPE latency_seconds histogram\n"));
+        assert!(text.contains("latency_seconds_bucket{sink=\"console\",le=\"0.00025\"} 0\n"));
+        assert!(text.contains("latency_seconds_bucket{sink=\"console\",le=\"0.0005\"} 1\n"));
+        assert!(text.contains("latency_seconds_bucket{sink=\"console\",le=\"2.5\"} 1\n"));
+        assert!(text.contains("latency_seconds_bucket{sink=\"console\",le=\"+Inf\"} 2\n"));
+        assert!(text.contains("latency_seconds_sum{sink=\"console\"} 5.0003\n"));
+        assert!(text.contains("latency_seconds_count{sink=\"console\"} 2\n"));
+    }
+}
@@ -1,19 +1,27 @@
 // /src/output/ble.rs
 // Module: output.ble
-// Purpose: BLE GATT server output for non-waveform tracks
+// Purpose: BLE GATT server output for non-waveform and decimated waveform tracks
 
-use crate::domain::{ProcessedData, ProcessedTrack, TrackType};
+use crate::domain::{
+    AlarmAction, AlarmCondition, AlarmEvent, AlarmPriority, ArtifactFlag, ProcessedData,
+    ProcessedTrack, SignalStatus, TrackType, TrendDirection,
+};
 use crate::error::{Result, VitalError};
+use crate::metrics::metrics;
+use crate::output::{OutputMode, OutputRoute, OutputSink, SinkHealth};
+use crate::signal::Decimation;
+use async_trait::async_trait;
 use bluer::{
-    adv::Advertisement,
+    adv::{Advertisement, AdvertisementHandle},
     gatt::local::{
-        Application, Characteristic, CharacteristicNotify, CharacteristicNotifyMethod,
-        CharacteristicRead, Service,
+        Application, ApplicationHandle, Characteristic, CharacteristicNotify,
+        CharacteristicNotifyMethod, CharacteristicRead, Service,
     },
 };
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::sync::{Mutex, RwLock};
 use uuid::Uuid;
 
 const MAX_BLE_PAYLOAD: usize = 500;
@@ -22,21 +30,40 @@ const MAX_BLE_PAYLOAD: usize = 500;
 /// Title: BleOutput
 ///
 /// Description: VRConnect shall provide BLE GATT server output transmitting
-/// non-waveform tracks only via notification characteristic.
+/// non-waveform tracks (plus decimated waveforms when configured) and alarm
+/// events via notification characteristics.
 ///
 /// Version: V1.0
 pub struct BleOutput {
     device_name: String,
     service_uuid: Uuid,
+    waveform_decimation: Option<Decimation>,
+    route: OutputRoute,
+    mode: OutputMode,
     data_buffer: Arc<RwLock<Option<Vec<u8>>>>,
+    alarm_buffer: Arc<RwLock<Option<Vec<u8>>>>,
+    server: Mutex<Option<BleServer>>,
+    health: std::sync::RwLock<SinkHealth>,
 }
 
-/// BLE output JSON structure (non-waveform tracks only)
+/// Interval between two adapter checks of a running server
+const MONITOR_INTERVAL_SECS: u64 = 5;
+
+/// Running GATT server: dropping it unregisters the application and stops advertising
+struct BleServer {
+    _session: bluer::Session,
+    adapter: bluer::Adapter,
+    _application: ApplicationHandle,
+    _advertisement: AdvertisementHandle,
+}
+
+/// BLE output JSON structure (non-waveform tracks, decimated waveforms if enabled)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct BleMessage {
     version: String,
     device_id: String,
     timestamp: String,
+    stale: bool,
     track_count: usize,
     tracks: Vec<BleTrack>,
 }
@@ -50,12 +77,41 @@ struct BleTrack {
     unit: String,
     timestamp: String,
     value: BleValue,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    quality: Option<f64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    artifacts: Vec<ArtifactFlag>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trend: Option<TrendDirection>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    age_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<SignalStatus>,
+}
+
+/// BLE alarm JSON structure (latest alarm event)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BleAlarm {
+    version: String,
+    alarm_id: String,
+    device_id: String,
+    room: String,
+    parameter: String,
+    condition: AlarmCondition,
+    action: AlarmAction,
+    priority: AlarmPriority,
+    value: Option<f64>,
+    limit: Option<f64>,
+    silenced: bool,
+    timestamp: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 enum BleValue {
     Number { value: f64, display: String },
+    Composite { components: BTreeMap<String, f64>, display: String },
+    Waveform { points: Vec<f64>, sample_rate: Option<f64> },
     Text { value: String },
     Other { value: String },
 }
@@ -65,32 +121,95 @@ impl BleOutput {
     /// Title: new
     ///
     /// Description: VRConnect shall construct a BleOutput instance with device
-    /// name, service UUID, and initialize data buffer for notifications.
+    /// name, service UUID, waveform decimation, and initialize data and alarm
+    /// buffers for notifications.
     ///
     /// Version: V1.0
     ///
     /// # Arguments
     /// * `device_name` - BLE advertising name
     /// * `service_uuid_str` - Service UUID string
+    /// * `waveform_decimation` - Waveform decimation (None to exclude waveforms)
     ///
     /// # Returns
     /// New BleOutput instance or error
-    pub async fn new(device_name: String, service_uuid_str: String) -> Result<Self> {
+    pub async fn new(
+        device_name: String,
+        service_uuid_str: String,
+        waveform_decimation: Option<Decimation>,
+    ) -> Result<Self> {
         let service_uuid = Uuid::parse_str(&service_uuid_str)
             .map_err(|e| VitalError::Config(format!("Invalid BLE service UUID: {}", e)))?;
 
         Ok(Self {
             device_name,
             service_uuid,
+            waveform_decimation,
+            route: OutputRoute::default(),
+            mode: OutputMode::default(),
             data_buffer: Arc::new(RwLock::new(None)),
+            alarm_buffer: Arc::new(RwLock::new(None)),
+            server: Mutex::new(None),
+            health: std::sync::RwLock::new(SinkHealth::Down("not started".to_string())),
         })
     }
 
+    /// ID SRS: SRS-FN-BLE-009
+    /// Title: with_route
+    ///
+    /// Description: VRConnect shall restrict the BLE output to the devices and
+    /// rooms of a route, so that the single data characteristic is not shared
+    /// by unrelated devices.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `route` - Devices and rooms transmitted
+    ///
+    /// # Returns
+    /// BleOutput bound to the route
+    pub fn with_route(mut self, route: OutputRoute) -> Self {
+        self.route = route;
+        self
+    }
+
+    /// ID SRS: SRS-FN-BLE-010
+    /// Title: with_mode
+    ///
+    /// Description: VRConnect shall set whether the BLE output receives
+    /// updates as received or the merged device snapshot.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `mode` - Delta or snapshot delivery
+    ///
+    /// # Returns
+    /// BleOutput with the delivery mode
+    pub fn with_mode(mut self, mode: OutputMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// ID SRS: SRS-FN-BLE-011
+    /// Title: mode
+    ///
+    /// Description: VRConnect shall return the delivery mode of the BLE output.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Delta or snapshot delivery
+    pub fn mode(&self) -> OutputMode {
+        self.mode
+    }
+
     /// ID SRS: SRS-FN-BLE-002
     /// Title: start
     ///
     /// Description: VRConnect shall start BLE GATT server, register service and
-    /// characteristic, and begin advertising with configured name and UUID.
+    /// characteristic, and begin advertising with configured name and UUID; the
+    /// server runs until shutdown. Starting again replaces a failed server.
     ///
     /// Version: V1.0
     ///
@@ -100,8 +219,35 @@ impl BleOutput {
         log::info!("Starting BLE GATT server...");
         log::info!("  Device Name: {}", self.device_name);
         log::info!("  Service UUID: {}", self.service_uuid);
-        log::info!("  ⚠️  Waveform tracks excluded from transmission");
+        match self.waveform_decimation {
+            Some(decimation) => log::info!("  Waveform tracks decimated: {:?}", decimation),
+            None => log::info!("  ⚠️  Waveform tracks excluded from transmission"),
+        }
+
+        match self.serve().await {
+            Ok(server) => {
+                *self.server.lock().await = Some(server);
+                self.set_health(SinkHealth::Healthy);
+                Ok(())
+            }
+            Err(e) => {
+                self.set_health(SinkHealth::Down(e.to_string()));
+                Err(e)
+            }
+        }
+    }
 
+    /// ID SRS: SRS-FN-BLE-012
+    /// Title: serve
+    ///
+    /// Description: VRConnect shall power the default adapter, register the
+    /// GATT application and advertise the service.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Running GATT server or error
+    async fn serve(&self) -> Result<BleServer> {
         let session = bluer::Session::new().await?;
         let adapter = session.default_adapter().await?;
 
@@ -115,7 +261,7 @@ impl BleOutput {
         );
 
         let app = self.create_application().await?;
-        let app_handle = adapter.serve_gatt_application(app).await?;
+        let application = adapter.serve_gatt_application(app).await?;
         log::info!("✓ GATT application registered");
 
         let adv = Advertisement {
@@ -125,47 +271,163 @@ impl BleOutput {
             ..Default::default()
         };
 
-        let adv_handle = adapter.advertise(adv).await?;
+        let advertisement = adapter.advertise(adv).await?;
         log::info!("✓ BLE advertising started");
         log::info!("✓ Push notifications enabled");
 
-        tokio::signal::ctrl_c()
-            .await
-            .map_err(|e| VitalError::Io(e))?;
+        Ok(BleServer {
+            _session: session,
+            adapter,
+            _application: application,
+            _advertisement: advertisement,
+        })
+    }
 
-        drop(adv_handle);
-        drop(app_handle);
-        log::info!("BLE GATT server stopped");
+    /// ID SRS: SRS-FN-BLE-013
+    /// Title: shutdown
+    ///
+    /// Description: VRConnect shall stop advertising and unregister the GATT
+    /// application.
+    ///
+    /// Version: V1.0
+    pub async fn shutdown(&self) {
+        if self.server.lock().await.take().is_some() {
+            log::info!("BLE GATT server stopped");
+        }
+        self.set_health(SinkHealth::Down("stopped".to_string()));
+    }
 
-        Ok(())
+    /// ID SRS: SRS-FN-BLE-015
+    /// Title: monitor
+    ///
+    /// Description: VRConnect shall periodically check that the adapter of
+    /// the running server is still reachable and powered (bluetoothd may
+    /// restart or the adapter disappear).
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Error when the server is no longer usable
+    pub async fn monitor(&self) -> Result<()> {
+        let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(MONITOR_INTERVAL_SECS));
+        loop {
+            interval.tick().await;
+
+            let powered = match self.server.lock().await.as_ref() {
+                Some(server) => server.adapter.is_powered().await,
+                None => Ok(false),
+            };
+            let failure = match powered {
+                Ok(true) => continue,
+                Ok(false) => VitalError::Processing("BLE adapter not powered or server stopped".to_string()),
+                Err(e) => VitalError::Bluetooth(e),
+            };
+
+            self.set_health(SinkHealth::Down(failure.to_string()));
+            return Err(failure);
+        }
+    }
+
+    /// ID SRS: SRS-FN-BLE-014
+    /// Title: set_health
+    ///
+    /// Description: VRConnect shall record the health of the BLE output.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `health` - Current health
+    fn set_health(&self, health: SinkHealth) {
+        if let Ok(mut current) = self.health.write() {
+            *current = health;
+        }
     }
 
     /// ID SRS: SRS-FN-BLE-003
     /// Title: create_application
     ///
     /// Description: VRConnect shall create GATT application with service and
-    /// characteristic supporting read and notify operations.
+    /// data and alarm characteristics supporting read and notify operations.
     ///
     /// Version: V1.0
     ///
     /// # Returns
     /// GATT Application structure
     async fn create_application(&self) -> Result<Application> {
-        let data_buffer = self.data_buffer.clone();
-        let data_buffer_notify = self.data_buffer.clone();
+        let data_char = Self::create_characteristic(
+            self.characteristic_uuid(1),
+            self.data_buffer.clone(),
+            "data",
+        );
+        let alarm_char = Self::create_characteristic(
+            self.characteristic_uuid(2),
+            self.alarm_buffer.clone(),
+            "alarm",
+        );
+
+        let service = Service {
+            uuid: self.service_uuid,
+            primary: true,
+            characteristics: vec![data_char, alarm_char],
+            ..Default::default()
+        };
 
-        let char_uuid = Uuid::parse_str(&format!(
+        Ok(Application {
+            services: vec![service],
+            ..Default::default()
+        })
+    }
+
+    /// ID SRS: SRS-FN-BLE-006
+    /// Title: characteristic_uuid
+    ///
+    /// Description: VRConnect shall derive a characteristic UUID from the
+    /// service UUID by adding an offset to its node field.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `offset` - Characteristic offset (1 = data, 2 = alarm)
+    ///
+    /// # Returns
+    /// Characteristic UUID
+    fn characteristic_uuid(&self, offset: u128) -> Uuid {
+        Uuid::parse_str(&format!(
             "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
             self.service_uuid.as_u128() >> 96,
             (self.service_uuid.as_u128() >> 80) & 0xFFFF,
             (self.service_uuid.as_u128() >> 64) & 0xFFFF,
             (self.service_uuid.as_u128() >> 48) & 0xFFFF,
-            (self.service_uuid.as_u128() & 0xFFFFFFFFFFFF) + 1
+            (self.service_uuid.as_u128() & 0xFFFFFFFFFFFF) + offset
         ))
-        .unwrap();
+        .unwrap()
+    }
 
-        let data_char = Characteristic {
-            uuid: char_uuid,
+    /// ID SRS: SRS-FN-BLE-007
+    /// Title: create_characteristic
+    ///
+    /// Description: VRConnect shall create a read + notify characteristic
+    /// serving the content of a shared buffer, notifying subscribers on change.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `uuid` - Characteristic UUID
+    /// * `buffer` - Shared payload buffer
+    /// * `label` - Characteristic label for logging
+    ///
+    /// # Returns
+    /// GATT Characteristic structure
+    fn create_characteristic(
+        uuid: Uuid,
+        buffer: Arc<RwLock<Option<Vec<u8>>>>,
+        label: &'static str,
+    ) -> Characteristic {
+        let data_buffer = buffer.clone();
+        let data_buffer_notify = buffer;
+
+        Characteristic {
+            uuid,
             read: Some(CharacteristicRead {
                 read: true,
                 fun: Box::new(move |_req| {
@@ -174,11 +436,11 @@ impl BleOutput {
                         let buffer = data_buffer.read().await;
                         match buffer.as_ref() {
                             Some(data) => {
-                                log::debug!("BLE read: {} bytes", data.len());
+                                log::debug!("BLE {} read: {} bytes", label, data.len());
                                 Ok(data.clone())
                             }
                             None => {
-                                log::debug!("BLE read: no data available");
+                                log::debug!("BLE {} read: no data available", label);
                                 Ok(Vec::new())
                             }
                         }
@@ -191,7 +453,8 @@ impl BleOutput {
                 method: CharacteristicNotifyMethod::Fun(Box::new(move |mut notifier| {
                     let data_buffer = data_buffer_notify.clone();
                     Box::pin(async move {
-                        log::info!("✓ Client subscribed to notifications");
+                        log::info!("✓ Client subscribed to {} notifications", label);
+                        metrics().ble_subscribers.with_label(label).inc();
 
                         let mut last_data: Option<Vec<u8>> = None;
                         let mut interval =
@@ -218,32 +481,22 @@ impl BleOutput {
                             }
                         }
 
-                        log::info!("Client unsubscribed from notifications");
+                        metrics().ble_subscribers.with_label(label).dec();
+                        log::info!("Client unsubscribed from {} notifications", label);
                     })
                 })),
                 ..Default::default()
             }),
             ..Default::default()
-        };
-
-        let service = Service {
-            uuid: self.service_uuid,
-            primary: true,
-            characteristics: vec![data_char],
-            ..Default::default()
-        };
-
-        Ok(Application {
-            services: vec![service],
-            ..Default::default()
-        })
+        }
     }
 
     /// ID SRS: SRS-FN-BLE-004
     /// Title: output
     ///
-    /// Description: VRConnect shall filter non-waveform tracks, serialize to JSON,
-    /// and update data buffer for BLE notification transmission.
+    /// Description: VRConnect shall select non-waveform tracks (and decimated
+    /// waveform tracks when waveform decimation is configured), serialize to
+    /// JSON, and update data buffer for BLE notification transmission.
     ///
     /// Version: V1.0
     ///
@@ -253,16 +506,25 @@ impl BleOutput {
     /// # Returns
     /// Result indicating success or error
     pub async fn output(&self, data: &ProcessedData) -> Result<()> {
-        // Filter non-waveform tracks
-        let non_waveform_tracks = data.get_non_waveform_tracks();
+        let Some(data) = self.route.select(data) else {
+            return Ok(());
+        };
+        let data = data.as_ref();
+
+        // Filter non-waveform tracks, or decimate waveforms when enabled
+        let decimated = self.waveform_decimation.map(|decimation| decimation.apply(data));
+        let selected_tracks: Vec<&ProcessedTrack> = match &decimated {
+            Some(decimated) => decimated.all_tracks.iter().collect(),
+            None => data.get_non_waveform_tracks(),
+        };
 
-        if non_waveform_tracks.is_empty() {
-            log::debug!("No non-waveform tracks to transmit via BLE");
+        if selected_tracks.is_empty() {
+            log::debug!("No tracks to transmit via BLE");
             return Ok(());
         }
 
         // Convert to BLE format
-        let ble_tracks: Vec<BleTrack> = non_waveform_tracks
+        let ble_tracks: Vec<BleTrack> = selected_tracks
             .iter()
             .map(|track| self.convert_track(track))
             .collect();
@@ -271,6 +533,7 @@ impl BleOutput {
             version: "1.0".to_string(),
             device_id: data.device_id.clone(),
             timestamp: data.timestamp.to_rfc3339(),
+            stale: false,
             track_count: ble_tracks.len(),
             tracks: ble_tracks,
         };
@@ -296,11 +559,74 @@ impl BleOutput {
         Ok(())
     }
 
+    /// ID SRS: SRS-FN-BLE-008
+    /// Title: output_alarm
+    ///
+    /// Description: VRConnect shall serialize an alarm event to JSON and update
+    /// the alarm buffer for BLE notification transmission; when a device's data
+    /// becomes stale, the data characteristic is cleared and flagged stale.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `event` - Alarm event
+    ///
+    /// # Returns
+    /// Result indicating success or error
+    pub async fn output_alarm(&self, event: &AlarmEvent) -> Result<()> {
+        if !self.route.accepts_event(event) {
+            return Ok(());
+        }
+
+        if event.condition == AlarmCondition::DataStale && event.action == AlarmAction::Raised {
+            let stale_message = BleMessage {
+                version: "1.0".to_string(),
+                device_id: event.device_id.clone(),
+                timestamp: event.timestamp.to_rfc3339(),
+                stale: true,
+                track_count: 0,
+                tracks: Vec::new(),
+            };
+
+            let json_bytes = serde_json::to_vec(&stale_message)
+                .map_err(|e| VitalError::Processing(format!("JSON serialization failed: {}", e)))?;
+
+            log::warn!("BLE data cleared: device {} is stale", event.device_id);
+            *self.data_buffer.write().await = Some(json_bytes);
+        }
+
+        let alarm = BleAlarm {
+            version: "1.0".to_string(),
+            alarm_id: event.alarm_id.clone(),
+            device_id: event.device_id.clone(),
+            room: event.room_name.clone(),
+            parameter: event.parameter.clone(),
+            condition: event.condition,
+            action: event.action,
+            priority: event.priority,
+            value: event.value,
+            limit: event.limit,
+            silenced: event.silenced,
+            timestamp: event.timestamp.to_rfc3339(),
+        };
+
+        let json_bytes = serde_json::to_vec(&alarm)
+            .map_err(|e| VitalError::Processing(format!("JSON serialization failed: {}", e)))?;
+
+        log::debug!("BLE alarm payload: {} bytes", json_bytes.len());
+        *self.alarm_buffer.write().await = Some(json_bytes);
+
+        Ok(())
+    }
+
     /// ID SRS: SRS-FN-BLE-005
     /// Title: convert_track
     ///
     /// Description: VRConnect shall convert ProcessedTrack to BleTrack format
-    /// with appropriate value structure based on track type.
+    /// with appropriate value structure based on track type, sending parsed
+    /// numeric components for composite string values, the quality score and
+    /// artifact flags when assessed, the trend direction, and the stale or
+    /// sensor-off status.
     ///
     /// Version: V1.0
     ///
@@ -314,15 +640,32 @@ impl BleOutput {
             TrackType::Number => "number",
             TrackType::String => "string",
             TrackType::Other => "other",
-            TrackType::Waveform => "waveform", // Should not occur due to filtering
+            TrackType::Waveform => "waveform", // Only with waveform decimation
         };
 
-        let value = match track.track_type {
-            TrackType::Number => BleValue::Number {
+        let value = match (&track.track_type, &track.components) {
+            (TrackType::Number, _) => BleValue::Number {
                 value: track.raw_value.unwrap_or(0.0),
                 display: track.display_value.clone(),
             },
-            TrackType::String => BleValue::Text {
+            (TrackType::String, Some(components)) => BleValue::Composite {
+                components: components
+                    .iter()
+                    .map(|c| (c.name.clone(), c.value))
+                    .collect(),
+                display: track.display_value.clone(),
+            },
+            (TrackType::Waveform, _) => BleValue::Waveform {
+                // Three decimals keep payloads small
+                points: track
+                    .waveform_points
+                    .iter()
+                    .flatten()
+                    .map(|point| (point * 1000.0).round() / 1000.0)
+                    .collect(),
+                sample_rate: track.sample_rate,
+            },
+            (TrackType::String, None) => BleValue::Text {
                 value: track.display_value.clone(),
             },
             _ => BleValue::Other {
@@ -337,10 +680,58 @@ impl BleOutput {
             unit: track.unit.clone(),
             timestamp: track.timestamp.to_rfc3339(),
             value,
+            quality: track.quality.as_ref().map(|quality| quality.score),
+            artifacts: track
+                .quality
+                .as_ref()
+                .map(|quality| quality.artifacts.clone())
+                .unwrap_or_default(),
+            trend: track.trend.as_ref().map(|trend| trend.direction),
+            age_ms: track.age_ms,
+            status: track.signal_status,
         }
     }
 }
 
+
+#[async_trait]
+impl OutputSink for BleOutput {
+    fn name(&self) -> &str {
+        "ble"
+    }
+
+    fn mode(&self) -> OutputMode {
+        BleOutput::mode(self)
+    }
+
+    async fn start(&self) -> Result<()> {
+        BleOutput::start(self).await
+    }
+
+    async fn monitor(&self) -> Result<()> {
+        BleOutput::monitor(self).await
+    }
+
+    async fn handle_data(&self, data: &ProcessedData) -> Result<()> {
+        self.output(data).await
+    }
+
+    async fn handle_event(&self, event: &AlarmEvent) -> Result<()> {
+        self.output_alarm(event).await
+    }
+
+    async fn shutdown(&self) -> Result<()> {
+        BleOutput::shutdown(self).await;
+        Ok(())
+    }
+
+    fn health(&self) -> SinkHealth {
+        self.health
+            .read()
+            .map(|health| health.clone())
+            .unwrap_or_else(|_| SinkHealth::Down("health unavailable".to_string()))
+    }
+}
 #[cfg(test)]
 mod tests {
     use super::*;
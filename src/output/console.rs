@@ -2,18 +2,25 @@
 // Module: output.console
 // Purpose: Console output with compact and verbose modes
 
-use crate::domain::{ProcessedData, ProcessedTrack, TrackType};
+use crate::domain::{AlarmEvent, ProcessedData, ProcessedTrack, TrackType};
+use crate::error::Result;
+use crate::output::{OutputMode, OutputRoute, OutputSink, SinkHealth};
+use crate::signal::Decimation;
+use async_trait::async_trait;
 
 /// ID SRS: SRS-MOD-CONSOLE-001
 /// Title: ConsoleOutput
 ///
 /// Description: VRConnect shall provide console output with compact and verbose
-/// modes, with optional colorization for improved readability.
+/// modes, with optional colorization and waveform decimation.
 ///
 /// Version: V1.0
 pub struct ConsoleOutput {
     verbose: bool,
     _colorized: bool, // Keep for future use
+    decimation: Option<Decimation>,
+    route: OutputRoute,
+    mode: OutputMode,
 }
 
 impl ConsoleOutput {
@@ -21,34 +28,95 @@ impl ConsoleOutput {
     /// Title: new
     ///
     /// Description: VRConnect shall construct a ConsoleOutput instance with
-    /// verbosity and colorization configuration.
+    /// verbosity, colorization and waveform decimation configuration.
     ///
     /// Version: V1.0
     ///
     /// # Arguments
     /// * `verbose` - Enable verbose mode
     /// * `colorized` - Enable color output
+    /// * `decimation` - Waveform decimation (None for full resolution)
     ///
     /// # Returns
     /// New ConsoleOutput instance
-    pub fn new(verbose: bool, colorized: bool) -> Self {
+    pub fn new(verbose: bool, colorized: bool, decimation: Option<Decimation>) -> Self {
         Self { 
             verbose,
             _colorized: colorized,
+            decimation,
+            route: OutputRoute::default(),
+            mode: OutputMode::default(),
         }
     }
 
+    /// ID SRS: SRS-FN-CONSOLE-009
+    /// Title: with_route
+    ///
+    /// Description: VRConnect shall restrict the console output to the devices
+    /// and rooms of a route.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `route` - Devices and rooms displayed
+    ///
+    /// # Returns
+    /// ConsoleOutput bound to the route
+    pub fn with_route(mut self, route: OutputRoute) -> Self {
+        self.route = route;
+        self
+    }
+
+    /// ID SRS: SRS-FN-CONSOLE-010
+    /// Title: with_mode
+    ///
+    /// Description: VRConnect shall set whether the console output receives
+    /// updates as received or the merged device snapshot.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `mode` - Delta or snapshot delivery
+    ///
+    /// # Returns
+    /// ConsoleOutput with the delivery mode
+    pub fn with_mode(mut self, mode: OutputMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// ID SRS: SRS-FN-CONSOLE-011
+    /// Title: mode
+    ///
+    /// Description: VRConnect shall return the delivery mode of the console output.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Delta or snapshot delivery
+    pub fn mode(&self) -> OutputMode {
+        self.mode
+    }
+
     /// ID SRS: SRS-FN-CONSOLE-002
     /// Title: output
     ///
     /// Description: VRConnect shall output ProcessedData to console in either
-    /// compact or verbose format based on configuration.
+    /// compact or verbose format based on configuration, decimating waveforms
+    /// when configured.
     ///
     /// Version: V1.0
     ///
     /// # Arguments
     /// * `data` - Processed vital data to display
     pub async fn output(&self, data: &ProcessedData) {
+        let Some(data) = self.route.select(data) else {
+            return;
+        };
+        let data = data.as_ref();
+        let decimated = self.decimation.map(|decimation| decimation.apply(data));
+        let data = decimated.as_ref().unwrap_or(data);
+
         if self.verbose {
             self.output_verbose(data);
         } else {
@@ -56,6 +124,33 @@ impl ConsoleOutput {
         }
     }
 
+    /// ID SRS: SRS-FN-CONSOLE-007
+    /// Title: output_alarm
+    ///
+    /// Description: VRConnect shall display an alarm event (clinical or
+    /// technical) on a single line with action, priority, device, room and message.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `event` - Alarm event to display
+    pub async fn output_alarm(&self, event: &AlarmEvent) {
+        if !self.route.accepts_event(event) {
+            return;
+        }
+
+        println!(
+            "[{}] 🚨 ALARM {} [{}] {}/{}: {}{}",
+            event.timestamp.format("%Y-%m-%dT%H:%M:%S%.3f"),
+            format!("{:?}", event.action).to_uppercase(),
+            format!("{:?}", event.priority).to_uppercase(),
+            event.device_id,
+            event.room_name,
+            event.message,
+            if event.silenced { " (silenced)" } else { "" }
+        );
+    }
+
     /// ID SRS: SRS-FN-CONSOLE-003
     /// Title: output_compact
     ///
@@ -129,19 +224,68 @@ impl ConsoleOutput {
     /// Title: print_track_compact
     ///
     /// Description: VRConnect shall print single track in compact format:
-    /// name, value, unit, and room.
+    /// name, value, trend arrow, unit, room, age if known, stale or sensor-off
+    /// status, and detected artifacts if any.
     ///
     /// Version: V1.0
     ///
     /// # Arguments
     /// * `track` - Track to display
     fn print_track_compact(&self, track: &ProcessedTrack) {
+        let arrow = track
+            .trend
+            .as_ref()
+            .map(|trend| format!(" {}", trend.direction.arrow()))
+            .unwrap_or_default();
+
+        // Values older than a second (snapshot mode) show their age
+        let age = track
+            .age_ms
+            .filter(|age| *age >= 1000)
+            .map(|age| format!(" [{}s ago]", age / 1000))
+            .unwrap_or_default();
+
+        let status = track
+            .signal_status
+            .map(|status| format!(" [{}]", status.label()))
+            .unwrap_or_default();
+
         println!(
-            "  {}: {} {} ({})",
-            track.name, track.display_value, track.unit, track.room_name
+            "  {}: {}{} {} ({}){}{}{}",
+            track.name,
+            track.display_value,
+            arrow,
+            track.unit,
+            track.room_name,
+            age,
+            status,
+            Self::artifact_suffix(track)
         );
     }
 
+    /// ID SRS: SRS-FN-CONSOLE-008
+    /// Title: artifact_suffix
+    ///
+    /// Description: VRConnect shall format the artifacts of a track as a
+    /// warning suffix marking the value as unreliable.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `track` - Track to display
+    ///
+    /// # Returns
+    /// Suffix such as " ⚠️ noise, clipping", or an empty string
+    fn artifact_suffix(track: &ProcessedTrack) -> String {
+        match &track.quality {
+            Some(quality) if !quality.artifacts.is_empty() => {
+                let labels: Vec<&str> = quality.artifacts.iter().map(|flag| flag.label()).collect();
+                format!(" ⚠️ {}", labels.join(", "))
+            }
+            _ => String::new(),
+        }
+    }
+
     /// ID SRS: SRS-FN-CONSOLE-006
     /// Title: print_track_verbose
     ///
@@ -164,11 +308,58 @@ impl ConsoleOutput {
             track.timestamp.format("%H:%M:%S%.3f")
         );
 
+        if let Some(components) = &track.components {
+            let formatted: Vec<String> = components
+                .iter()
+                .map(|c| format!("{}={}", c.name, c.value))
+                .collect();
+            println!("{}  Components: {}", indent, formatted.join(", "));
+        }
+
+        if let Some(trend) = &track.trend {
+            println!(
+                "{}  Trend: {} {:?} (avg={:.3}, slope={:+.3}/min, min={:.3}, max={:.3}, n={})",
+                indent,
+                trend.direction.arrow(),
+                trend.direction,
+                trend.moving_average,
+                trend.slope_per_min,
+                trend.min,
+                trend.max,
+                trend.samples
+            );
+        }
+
+        if let Some(quality) = &track.quality {
+            println!("{}  Quality: {:.2}{}", indent, quality.score, Self::artifact_suffix(track));
+        }
+
         if let Some(stats) = &track.waveform_stats {
             println!(
                 "{}  Stats: min={:.3}, max={:.3}, avg={:.3}, count={}",
                 indent, stats.min, stats.max, stats.avg, stats.count
             );
+
+            if let Some(extended) = &stats.extended {
+                let percentiles: Vec<String> = extended
+                    .percentiles
+                    .iter()
+                    .map(|p| format!("p{}={:.3}", p.percentile, p.value))
+                    .collect();
+                println!(
+                    "{}  Extended: std={:.3}, rms={:.3}, p2p={:.3}, zcr={:.3}, f0={}, {}",
+                    indent,
+                    extended.std_dev,
+                    extended.rms,
+                    extended.peak_to_peak,
+                    extended.zero_crossing_rate,
+                    extended
+                        .dominant_frequency
+                        .map(|f| format!("{:.2}Hz", f))
+                        .unwrap_or_else(|| "N/A".to_string()),
+                    percentiles.join(", ")
+                );
+            }
         }
 
         // Print ALL waveform points in verbose mode
@@ -194,6 +385,31 @@ impl ConsoleOutput {
     }
 }
 
+#[async_trait]
+impl OutputSink for ConsoleOutput {
+    fn name(&self) -> &str {
+        "console"
+    }
+
+    fn mode(&self) -> OutputMode {
+        ConsoleOutput::mode(self)
+    }
+
+    async fn handle_data(&self, data: &ProcessedData) -> Result<()> {
+        self.output(data).await;
+        Ok(())
+    }
+
+    async fn handle_event(&self, event: &AlarmEvent) -> Result<()> {
+        self.output_alarm(event).await;
+        Ok(())
+    }
+
+    fn health(&self) -> SinkHealth {
+        SinkHealth::Healthy
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,9 +1,17 @@
 // /src/output/mod.rs
 // Module: output
-// Purpose: Output modules for console and BLE
+// Purpose: Output modules for console and BLE behind a common sink interface
 
 pub mod console;
 pub mod ble;
+pub mod mode;
+pub mod queue;
+pub mod route;
+pub mod sink;
 
 pub use console::ConsoleOutput;
 pub use ble::BleOutput;
+pub use mode::OutputMode;
+pub use queue::{OverflowPolicy, QueueStats, QueuedSink};
+pub use route::OutputRoute;
+pub use sink::{build_sinks, OutputSink, SinkHealth};
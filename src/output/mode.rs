@@ -0,0 +1,53 @@
+// /src/output/mode.rs
+// Module: output.mode
+// Purpose: Delta or full-snapshot delivery of vital data to an output
+
+/// ID SRS: SRS-MOD-OUTPUTMODE-001
+/// Title: OutputMode
+///
+/// Description: VRConnect shall deliver to each output either every update as
+/// received (delta: only the tracks of the update) or the device snapshot
+/// (latest value of every track, with age) after each update.
+///
+/// Version: V1.0
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputMode {
+    #[default]
+    Delta,
+    Snapshot,
+}
+
+impl OutputMode {
+    /// ID SRS: SRS-FN-OUTPUTMODE-001
+    /// Title: parse
+    ///
+    /// Description: VRConnect shall parse an output mode ("delta" or
+    /// "snapshot", case-insensitive).
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `mode` - Output mode setting
+    ///
+    /// # Returns
+    /// Parsed mode or error message
+    pub fn parse(mode: &str) -> Result<Self, String> {
+        match mode.trim().to_lowercase().as_str() {
+            "delta" => Ok(OutputMode::Delta),
+            "snapshot" => Ok(OutputMode::Snapshot),
+            other => Err(format!("Unknown output mode: {} (expected delta or snapshot)", other)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(OutputMode::parse("delta").unwrap(), OutputMode::Delta);
+        assert_eq!(OutputMode::parse(" Snapshot ").unwrap(), OutputMode::Snapshot);
+        assert!(OutputMode::parse("full").is_err());
+    }
+}
@@ -0,0 +1,590 @@
+// /src/output/queue.rs
+// Module: output.queue
+// Purpose: Bounded per-output queues with overflow policies, isolating slow outputs
+
+use crate::domain::{AlarmEvent, ProcessedData};
+use crate::error::Result;
+use crate::metrics::{metrics, Counter};
+use crate::output::{OutputMode, OutputSink, SinkHealth};
+use async_trait::async_trait;
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::sync::Notify;
+use tokio::task::JoinHandle;
+
+/// Number of dropped messages between two overflow warnings
+const DROP_WARNING_INTERVAL: u64 = 100;
+
+/// ID SRS: SRS-MOD-QUEUE-001
+/// Title: OverflowPolicy
+///
+/// Description: VRConnect shall apply the configured policy when the queue of
+/// an output is full: drop the oldest message, drop the incoming message,
+/// coalesce the queued data of the device into the incoming (latest) one, or
+/// block the processing until the output catches up. Alarm events are only
+/// dropped when the queue holds no data message.
+///
+/// Version: V1.0
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    DropOldest,
+    DropNewest,
+    Coalesce,
+    Block,
+}
+
+impl OverflowPolicy {
+    /// ID SRS: SRS-FN-QUEUE-001
+    /// Title: parse
+    ///
+    /// Description: VRConnect shall parse an overflow policy ("drop_oldest",
+    /// "drop_newest", "coalesce" or "block", case-insensitive).
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `policy` - Overflow policy setting
+    ///
+    /// # Returns
+    /// Parsed policy or error message
+    pub fn parse(policy: &str) -> std::result::Result<Self, String> {
+        match policy.trim().to_lowercase().as_str() {
+            "drop_oldest" => Ok(OverflowPolicy::DropOldest),
+            "drop_newest" => Ok(OverflowPolicy::DropNewest),
+            "coalesce" => Ok(OverflowPolicy::Coalesce),
+            "block" => Ok(OverflowPolicy::Block),
+            other => Err(format!(
+                "Unknown overflow policy: {} (expected drop_oldest, drop_newest, coalesce or block)",
+                other
+            )),
+        }
+    }
+}
+
+/// ID SRS: SRS-MOD-QUEUE-002
+/// Title: SinkMessage
+///
+/// Description: VRConnect shall queue processed data and alarm events for an
+/// output in arrival order.
+///
+/// Version: V1.0
+#[derive(Debug, Clone)]
+pub enum SinkMessage {
+    Data(Arc<ProcessedData>),
+    Event(AlarmEvent),
+}
+
+impl SinkMessage {
+    /// Whether the message carries processed data (evicted before alarm events)
+    fn is_data(&self) -> bool {
+        matches!(self, SinkMessage::Data(_))
+    }
+}
+
+/// ID SRS: SRS-MOD-QUEUE-003
+/// Title: QueueStats
+///
+/// Description: VRConnect shall report the depth, capacity and dropped
+/// message count of an output queue.
+///
+/// Version: V1.0
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct QueueStats {
+    pub depth: usize,
+    pub capacity: usize,
+    pub dropped: u64,
+}
+
+/// Queued messages and closing flag
+#[derive(Default)]
+struct QueueState {
+    messages: VecDeque<SinkMessage>,
+    closed: bool,
+}
+
+/// ID SRS: SRS-MOD-QUEUE-004
+/// Title: SinkQueue
+///
+/// Description: VRConnect shall buffer the messages of an output in a
+/// bounded queue, applying the overflow policy when full and counting the
+/// dropped messages.
+///
+/// Version: V1.0
+pub struct SinkQueue {
+    capacity: usize,
+    policy: OverflowPolicy,
+    state: Mutex<QueueState>,
+    pushed: Notify,
+    popped: Notify,
+    dropped: AtomicU64,
+    drop_counter: Option<Arc<Counter>>,
+}
+
+impl SinkQueue {
+    /// ID SRS: SRS-FN-QUEUE-002
+    /// Title: new
+    ///
+    /// Description: VRConnect shall construct an empty SinkQueue.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `capacity` - Maximum number of queued messages (at least 1)
+    /// * `policy` - Policy applied when the queue is full
+    ///
+    /// # Returns
+    /// New SinkQueue instance
+    pub fn new(capacity: usize, policy: OverflowPolicy) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            policy,
+            state: Mutex::new(QueueState::default()),
+            pushed: Notify::new(),
+            popped: Notify::new(),
+            dropped: AtomicU64::new(0),
+            drop_counter: None,
+        }
+    }
+
+    /// ID SRS: SRS-FN-QUEUE-011
+    /// Title: with_drop_counter
+    ///
+    /// Description: VRConnect shall also count the dropped messages in a
+    /// processing metric.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `counter` - Metric counting the dropped messages
+    ///
+    /// # Returns
+    /// SinkQueue with the metric
+    pub fn with_drop_counter(mut self, counter: Arc<Counter>) -> Self {
+        self.drop_counter = Some(counter);
+        self
+    }
+
+    /// ID SRS: SRS-FN-QUEUE-003
+    /// Title: push
+    ///
+    /// Description: VRConnect shall append a message to the queue, applying
+    /// the overflow policy when full; only the block policy waits for space.
+    /// Messages pushed to a closed queue are dropped.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `message` - Data or alarm event for the output
+    pub async fn push(&self, message: SinkMessage) {
+        loop {
+            let space = self.popped.notified();
+            {
+                let mut state = self.lock();
+                if state.closed {
+                    self.count_dropped(1);
+                    return;
+                }
+
+                if state.messages.len() < self.capacity {
+                    state.messages.push_back(message);
+                    self.pushed.notify_one();
+                    return;
+                }
+
+                if self.policy != OverflowPolicy::Block {
+                    self.overflow(&mut state.messages, message);
+                    self.pushed.notify_one();
+                    return;
+                }
+            }
+            space.await;
+        }
+    }
+
+    /// ID SRS: SRS-FN-QUEUE-004
+    /// Title: pop
+    ///
+    /// Description: VRConnect shall take the oldest message of the queue,
+    /// waiting for one if empty.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Oldest message, or None once the queue is closed and drained
+    pub async fn pop(&self) -> Option<SinkMessage> {
+        loop {
+            let available = self.pushed.notified();
+            {
+                let mut state = self.lock();
+                if let Some(message) = state.messages.pop_front() {
+                    self.popped.notify_one();
+                    return Some(message);
+                }
+                if state.closed {
+                    return None;
+                }
+            }
+            available.await;
+        }
+    }
+
+    /// ID SRS: SRS-FN-QUEUE-005
+    /// Title: close
+    ///
+    /// Description: VRConnect shall close the queue: queued messages are still
+    /// delivered (drained on shutdown), new ones are dropped.
+    ///
+    /// Version: V1.0
+    pub fn close(&self) {
+        self.lock().closed = true;
+        self.pushed.notify_one();
+        self.popped.notify_one();
+    }
+
+    /// ID SRS: SRS-FN-QUEUE-006
+    /// Title: stats
+    ///
+    /// Description: VRConnect shall return the queue depth, capacity and
+    /// dropped message count.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Queue statistics
+    pub fn stats(&self) -> QueueStats {
+        QueueStats {
+            depth: self.lock().messages.len(),
+            capacity: self.capacity,
+            dropped: self.dropped.load(Ordering::Relaxed),
+        }
+    }
+
+    /// ID SRS: SRS-FN-QUEUE-007
+    /// Title: overflow
+    ///
+    /// Description: VRConnect shall make room for, or discard, a message
+    /// pushed to a full queue according to the overflow policy, evicting data
+    /// messages before alarm events.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `messages` - Queued messages (full)
+    /// * `message` - Incoming message
+    fn overflow(&self, messages: &mut VecDeque<SinkMessage>, message: SinkMessage) {
+        match (self.policy, &message) {
+            (OverflowPolicy::DropNewest, SinkMessage::Data(_)) => {
+                self.count_dropped(1);
+                return;
+            }
+            (OverflowPolicy::Coalesce, SinkMessage::Data(data)) => {
+                // The incoming data supersedes the queued data of its device
+                let before = messages.len();
+                messages.retain(|queued| {
+                    !matches!(queued, SinkMessage::Data(queued) if queued.device_id == data.device_id)
+                });
+                self.count_dropped((before - messages.len()) as u64);
+            }
+            _ => {}
+        }
+
+        if messages.len() >= self.capacity {
+            let victim = messages.iter().position(SinkMessage::is_data).unwrap_or(0);
+            messages.remove(victim);
+            self.count_dropped(1);
+        }
+        messages.push_back(message);
+    }
+
+    /// ID SRS: SRS-FN-QUEUE-008
+    /// Title: count_dropped
+    ///
+    /// Description: VRConnect shall count dropped messages, warning
+    /// periodically.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `count` - Number of messages dropped
+    fn count_dropped(&self, count: u64) {
+        if count == 0 {
+            return;
+        }
+        let before = self.dropped.fetch_add(count, Ordering::Relaxed);
+        if let Some(ref counter) = self.drop_counter {
+            counter.add(count);
+        }
+        if before / DROP_WARNING_INTERVAL != (before + count) / DROP_WARNING_INTERVAL || before == 0 {
+            log::warn!("⚠️  Output queue full ({:?}): {} messages dropped", self.policy, before + count);
+        }
+    }
+
+    /// Lock the queue state (a poisoned lock still holds consistent state)
+    fn lock(&self) -> std::sync::MutexGuard<'_, QueueState> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// ID SRS: SRS-MOD-QUEUE-005
+/// Title: QueuedSink
+///
+/// Description: VRConnect shall deliver to every output through its own
+/// bounded queue drained by a dedicated task, so that a slow or stuck output
+/// neither delays the other outputs nor the processing.
+///
+/// Version: V1.0
+pub struct QueuedSink {
+    inner: Arc<dyn OutputSink>,
+    queue: Arc<SinkQueue>,
+    worker: tokio::sync::Mutex<Option<JoinHandle<()>>>,
+}
+
+impl QueuedSink {
+    /// ID SRS: SRS-FN-QUEUE-009
+    /// Title: new
+    ///
+    /// Description: VRConnect shall wrap an output with a bounded queue.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `inner` - Output receiving the queued messages
+    /// * `capacity` - Maximum number of queued messages
+    /// * `policy` - Policy applied when the queue is full
+    ///
+    /// # Returns
+    /// New QueuedSink instance
+    pub fn new(inner: Arc<dyn OutputSink>, capacity: usize, policy: OverflowPolicy) -> Self {
+        let dropped = metrics().sink_dropped.with_label(inner.name());
+        Self {
+            inner,
+            queue: Arc::new(SinkQueue::new(capacity, policy).with_drop_counter(dropped)),
+            worker: tokio::sync::Mutex::new(None),
+        }
+    }
+
+    /// ID SRS: SRS-FN-QUEUE-010
+    /// Title: drain
+    ///
+    /// Description: VRConnect shall deliver the queued messages to the output
+    /// until the queue is closed and empty.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `inner` - Output receiving the messages
+    /// * `queue` - Output queue
+    async fn drain(inner: Arc<dyn OutputSink>, queue: Arc<SinkQueue>) {
+        let duration = metrics().sink_duration.with_label(inner.name());
+        while let Some(message) = queue.pop().await {
+            let started = std::time::Instant::now();
+            let result = match message {
+                SinkMessage::Data(data) => inner.handle_data(&data).await,
+                SinkMessage::Event(event) => inner.handle_event(&event).await,
+            };
+            duration.observe(started.elapsed().as_secs_f64());
+            if let Err(e) = result {
+                log::error!("{} output error: {}", inner.name(), e);
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl OutputSink for QueuedSink {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn mode(&self) -> OutputMode {
+        self.inner.mode()
+    }
+
+    async fn start(&self) -> Result<()> {
+        // One worker across restarts of the output
+        self.worker
+            .lock()
+            .await
+            .get_or_insert_with(|| tokio::spawn(Self::drain(self.inner.clone(), self.queue.clone())));
+        self.inner.start().await
+    }
+
+    async fn monitor(&self) -> Result<()> {
+        self.inner.monitor().await
+    }
+
+    async fn handle_data(&self, data: &ProcessedData) -> Result<()> {
+        self.queue.push(SinkMessage::Data(Arc::new(data.clone()))).await;
+        Ok(())
+    }
+
+    async fn handle_event(&self, event: &AlarmEvent) -> Result<()> {
+        self.queue.push(SinkMessage::Event(event.clone())).await;
+        Ok(())
+    }
+
+    async fn shutdown(&self) -> Result<()> {
+        // Deliver the queued messages before stopping the output
+        self.queue.close();
+        if let Some(worker) = self.worker.lock().await.take() {
+            if let Err(e) = worker.await {
+                log::error!("{} output task failed: {}", self.inner.name(), e);
+            }
+        }
+        self.inner.shutdown().await
+    }
+
+    fn health(&self) -> SinkHealth {
+        let stats = self.queue.stats();
+        match self.inner.health() {
+            SinkHealth::Healthy if stats.depth >= stats.capacity => {
+                SinkHealth::Degraded(format!("queue full ({} dropped)", stats.dropped))
+            }
+            health => health,
+        }
+    }
+
+    fn queue_stats(&self) -> Option<QueueStats> {
+        Some(self.queue.stats())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{AlarmAction, AlarmCondition, AlarmPriority};
+    use chrono::Utc;
+
+    fn data(device_id: &str) -> SinkMessage {
+        SinkMessage::Data(Arc::new(ProcessedData::new(device_id.to_string(), Vec::new())))
+    }
+
+    fn event() -> SinkMessage {
+        SinkMessage::Event(AlarmEvent {
+            alarm_id: AlarmEvent::alarm_id_for("VR1", "OR-1", "HR"),
+            device_id: "VR1".to_string(),
+            room_name: "OR-1".to_string(),
+            parameter: "HR".to_string(),
+            condition: AlarmCondition::High,
+            action: AlarmAction::Raised,
+            priority: AlarmPriority::High,
+            value: None,
+            limit: None,
+            silenced: false,
+            message: String::new(),
+            timestamp: Utc::now(),
+        })
+    }
+
+    async fn contents(queue: &SinkQueue) -> Vec<String> {
+        queue.close();
+        let mut contents = Vec::new();
+        while let Some(message) = queue.pop().await {
+            contents.push(match message {
+                SinkMessage::Data(data) => data.device_id.clone(),
+                SinkMessage::Event(_) => "event".to_string(),
+            });
+        }
+        contents
+    }
+
+    /// Output recording the devices it received, slowly
+    #[derive(Default)]
+    struct SlowSink {
+        received: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl OutputSink for SlowSink {
+        fn name(&self) -> &str {
+            "slow"
+        }
+
+        fn mode(&self) -> OutputMode {
+            OutputMode::Delta
+        }
+
+        async fn handle_data(&self, data: &ProcessedData) -> Result<()> {
+            tokio::time::sleep(tokio::time::Duration::from_millis(5)).await;
+            self.received.lock().unwrap().push(data.device_id.clone());
+            Ok(())
+        }
+
+        async fn handle_event(&self, _event: &AlarmEvent) -> Result<()> {
+            Ok(())
+        }
+
+        fn health(&self) -> SinkHealth {
+            SinkHealth::Healthy
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(OverflowPolicy::parse("Drop_Oldest").unwrap(), OverflowPolicy::DropOldest);
+        assert_eq!(OverflowPolicy::parse("coalesce").unwrap(), OverflowPolicy::Coalesce);
+        assert!(OverflowPolicy::parse("drop").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_drop_policies_keep_events() {
+        let queue = SinkQueue::new(2, OverflowPolicy::DropOldest);
+        for message in [event(), data("VR1"), data("VR2"), data("VR3")] {
+            queue.push(message).await;
+        }
+        assert_eq!(queue.stats().dropped, 2);
+        assert_eq!(contents(&queue).await, vec!["event", "VR3"]);
+
+        let queue = SinkQueue::new(2, OverflowPolicy::DropNewest);
+        for message in [data("VR1"), data("VR2"), data("VR3"), event()] {
+            queue.push(message).await;
+        }
+        assert_eq!(contents(&queue).await, vec!["VR2", "event"]);
+    }
+
+    #[tokio::test]
+    async fn test_coalesce() {
+        let queue = SinkQueue::new(3, OverflowPolicy::Coalesce);
+        for message in [data("VR1"), data("VR2"), data("VR1"), data("VR1")] {
+            queue.push(message).await;
+        }
+        assert_eq!(queue.stats().dropped, 2);
+        assert_eq!(contents(&queue).await, vec!["VR2", "VR1"]);
+    }
+
+    #[tokio::test]
+    async fn test_block_waits_for_space() {
+        let queue = Arc::new(SinkQueue::new(1, OverflowPolicy::Block));
+        queue.push(data("VR1")).await;
+
+        let producer = {
+            let queue = queue.clone();
+            tokio::spawn(async move { queue.push(data("VR2")).await })
+        };
+        tokio::task::yield_now().await;
+        assert!(!producer.is_finished());
+
+        assert!(queue.pop().await.is_some());
+        producer.await.unwrap();
+        assert_eq!(queue.stats(), QueueStats { depth: 1, capacity: 1, dropped: 0 });
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_drains_queue() {
+        let inner = Arc::new(SlowSink::default());
+        let sink = QueuedSink::new(inner.clone(), 8, OverflowPolicy::Block);
+        sink.start().await.unwrap();
+
+        let frame = ProcessedData::new("VR1".to_string(), Vec::new());
+        for _ in 0..3 {
+            sink.handle_data(&frame).await.unwrap();
+        }
+        sink.shutdown().await.unwrap();
+
+        assert_eq!(inner.received.lock().unwrap().len(), 3);
+        sink.handle_data(&frame).await.unwrap();
+        assert_eq!(sink.queue_stats().unwrap().dropped, 1);
+    }
+}
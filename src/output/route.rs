@@ -0,0 +1,255 @@
+// /src/output/route.rs
+// Module: output.route
+// Purpose: Binding of outputs to specific devices and rooms
+
+use crate::domain::{AlarmEvent, ProcessedData};
+use std::borrow::Cow;
+
+/// Wildcard matching any device or room
+const WILDCARD: &str = "*";
+
+/// ID SRS: SRS-MOD-ROUTE-001
+/// Title: RouteFilter
+///
+/// Description: VRConnect shall match a device and optionally a room by name;
+/// textual form "DEVICE" or "DEVICE/ROOM", "*" matching any.
+///
+/// Version: V1.0
+#[derive(Debug, Clone, PartialEq)]
+pub struct RouteFilter {
+    device: Option<String>,
+    room: Option<String>,
+}
+
+impl RouteFilter {
+    /// ID SRS: SRS-FN-ROUTE-001
+    /// Title: parse
+    ///
+    /// Description: VRConnect shall parse a route filter.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `spec` - Filter such as "VR1", "VR1/OR-3" or "*/OR-3"
+    ///
+    /// # Returns
+    /// Parsed filter or error message
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let (device, room) = match spec.split_once('/') {
+            Some((device, room)) => (device.trim(), Some(room.trim())),
+            None => (spec.trim(), None),
+        };
+
+        if device.is_empty() || room.is_some_and(str::is_empty) {
+            return Err(format!("Invalid output route '{}', expected DEVICE or DEVICE/ROOM", spec));
+        }
+
+        let name = |part: &str| (part != WILDCARD).then(|| part.to_string());
+        Ok(Self {
+            device: name(device),
+            room: room.and_then(name),
+        })
+    }
+
+    /// ID SRS: SRS-FN-ROUTE-002
+    /// Title: matches
+    ///
+    /// Description: VRConnect shall tell whether a device and room match the
+    /// filter; a room of None (device-level condition) matches any room filter.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `device_id` - VitalRecorder device identifier
+    /// * `room_name` - Room name, or None for the whole device
+    ///
+    /// # Returns
+    /// true if matching
+    pub fn matches(&self, device_id: &str, room_name: Option<&str>) -> bool {
+        self.device.as_deref().is_none_or(|device| device == device_id)
+            && match (self.room.as_deref(), room_name) {
+                (Some(room), Some(room_name)) => room == room_name,
+                _ => true,
+            }
+    }
+}
+
+/// ID SRS: SRS-MOD-ROUTE-002
+/// Title: OutputRoute
+///
+/// Description: VRConnect shall bind an output to a set of devices and rooms:
+/// only the rooms matching one of the filters are delivered to the output.
+/// Without filters, the output receives every device.
+///
+/// Version: V1.0
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OutputRoute {
+    filters: Vec<RouteFilter>,
+}
+
+impl OutputRoute {
+    /// ID SRS: SRS-FN-ROUTE-003
+    /// Title: parse
+    ///
+    /// Description: VRConnect shall parse a comma-separated list of route
+    /// filters; an empty list or "*" routes every device to the output.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `spec` - Filter list such as "VR1,VR2/OR-3"
+    ///
+    /// # Returns
+    /// Parsed route or error message
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let filters = spec
+            .split(',')
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+            .map(RouteFilter::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let all = filters.iter().any(|filter| filter.device.is_none() && filter.room.is_none());
+        Ok(Self {
+            filters: if all { Vec::new() } else { filters },
+        })
+    }
+
+    /// ID SRS: SRS-FN-ROUTE-006
+    /// Title: scopes
+    ///
+    /// Description: VRConnect shall list the device and room named by each
+    /// filter (None for "*").
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// (device, room) of every filter
+    pub fn scopes(&self) -> impl Iterator<Item = (Option<&str>, Option<&str>)> {
+        self.filters
+            .iter()
+            .map(|filter| (filter.device.as_deref(), filter.room.as_deref()))
+    }
+
+    /// ID SRS: SRS-FN-ROUTE-004
+    /// Title: select
+    ///
+    /// Description: VRConnect shall return the part of the processed data
+    /// routed to the output: unchanged when every room matches, restricted to
+    /// the matching rooms otherwise.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `data` - Processed vital data
+    ///
+    /// # Returns
+    /// Routed data, or None if no room matches
+    pub fn select<'a>(&self, data: &'a ProcessedData) -> Option<Cow<'a, ProcessedData>> {
+        if self.filters.is_empty() {
+            return Some(Cow::Borrowed(data));
+        }
+
+        let matches = |room_name: &str| {
+            self.filters
+                .iter()
+                .any(|filter| filter.matches(&data.device_id, Some(room_name)))
+        };
+
+        if data.rooms.iter().all(|room| matches(&room.room_name)) {
+            return (!data.rooms.is_empty()).then_some(Cow::Borrowed(data));
+        }
+
+        let mut routed = data.clone();
+        routed.rooms.retain(|room| matches(&room.room_name));
+        if routed.rooms.is_empty() {
+            return None;
+        }
+        routed.refresh_all_tracks();
+
+        Some(Cow::Owned(routed))
+    }
+
+    /// ID SRS: SRS-FN-ROUTE-005
+    /// Title: accepts_event
+    ///
+    /// Description: VRConnect shall tell whether an alarm event is routed to
+    /// the output; device-level events ("*" room) follow the device.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `event` - Alarm event
+    ///
+    /// # Returns
+    /// true if the event is delivered to the output
+    pub fn accepts_event(&self, event: &AlarmEvent) -> bool {
+        let room = (event.room_name != WILDCARD).then_some(event.room_name.as_str());
+        self.filters.is_empty() || self.filters.iter().any(|filter| filter.matches(&event.device_id, room))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{AlarmAction, AlarmCondition, AlarmPriority, ProcessedRoom, ProcessedTrack};
+    use chrono::Utc;
+
+    fn data(device_id: &str) -> ProcessedData {
+        let room = |index: i32, name: &str| ProcessedRoom {
+            room_index: index,
+            room_name: name.to_string(),
+            tracks: vec![ProcessedTrack::synthetic("HR", Some(70.0), "bpm", index, name, Utc::now())],
+        };
+        ProcessedData::new(device_id.to_string(), vec![room(0, "OR-1"), room(1, "OR-3")])
+    }
+
+    fn event(device_id: &str, room_name: &str) -> AlarmEvent {
+        AlarmEvent {
+            alarm_id: AlarmEvent::alarm_id_for(device_id, room_name, "HR"),
+            device_id: device_id.to_string(),
+            room_name: room_name.to_string(),
+            parameter: "HR".to_string(),
+            condition: AlarmCondition::High,
+            action: AlarmAction::Raised,
+            priority: AlarmPriority::Medium,
+            value: None,
+            limit: None,
+            silenced: false,
+            message: String::new(),
+            timestamp: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(OutputRoute::parse("").unwrap(), OutputRoute::default());
+        assert_eq!(OutputRoute::parse("VR1, *").unwrap(), OutputRoute::default());
+        assert_eq!(OutputRoute::parse("VR1,*/OR-3").unwrap().filters.len(), 2);
+        assert!(OutputRoute::parse("VR1/").is_err());
+        assert!(OutputRoute::parse("/OR-3").is_err());
+    }
+
+    #[test]
+    fn test_select_rooms() {
+        let route = OutputRoute::parse("*/OR-3").unwrap();
+        let frame = data("VR1");
+        let routed = route.select(&frame).unwrap();
+        assert!(matches!(routed, Cow::Owned(_)));
+        assert_eq!(routed.rooms.len(), 1);
+        assert_eq!(routed.all_tracks[0].room_name, "OR-3");
+
+        let route = OutputRoute::parse("VR2").unwrap();
+        assert!(route.select(&data("VR1")).is_none());
+        assert!(matches!(route.select(&data("VR2")), Some(Cow::Borrowed(_))));
+    }
+
+    #[test]
+    fn test_accepts_event() {
+        let route = OutputRoute::parse("VR1/OR-3").unwrap();
+        assert!(route.accepts_event(&event("VR1", "OR-3")));
+        assert!(route.accepts_event(&event("VR1", "*")));
+        assert!(!route.accepts_event(&event("VR1", "OR-1")));
+        assert!(!route.accepts_event(&event("VR2", "*")));
+    }
+}
@@ -0,0 +1,166 @@
+// /src/output/sink.rs
+// Module: output.sink
+// Purpose: Common interface of outputs and construction of the enabled outputs
+
+use crate::config::Config;
+use crate::domain::{AlarmEvent, ProcessedData};
+use crate::error::{Result, VitalError};
+use crate::output::{BleOutput, ConsoleOutput, OutputMode, OutputRoute, OverflowPolicy, QueueStats, QueuedSink};
+use crate::signal::Decimation;
+use async_trait::async_trait;
+use serde::Serialize;
+use std::sync::Arc;
+
+/// ID SRS: SRS-MOD-SINK-001
+/// Title: SinkHealth
+///
+/// Description: VRConnect shall report the health of every output: healthy,
+/// degraded (delivering with errors) or down (not delivering), with a reason.
+///
+/// Version: V1.0
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "status", content = "detail", rename_all = "snake_case")]
+pub enum SinkHealth {
+    Healthy,
+    Degraded(String),
+    Down(String),
+}
+
+impl SinkHealth {
+    /// ID SRS: SRS-FN-SINK-001
+    /// Title: is_healthy
+    ///
+    /// Description: VRConnect shall tell whether an output is fully healthy.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// true if healthy
+    pub fn is_healthy(&self) -> bool {
+        *self == SinkHealth::Healthy
+    }
+}
+
+/// ID SRS: SRS-MOD-SINK-002
+/// Title: OutputSink
+///
+/// Description: VRConnect shall drive every output through a common
+/// interface: start, failure monitoring, delivery of processed data and alarm
+/// events, shutdown and health, so that the processing loop does not depend
+/// on the concrete outputs.
+///
+/// Version: V1.0
+#[async_trait]
+pub trait OutputSink: Send + Sync {
+    /// Output name used in logs and health reports
+    fn name(&self) -> &str;
+
+    /// Delta or snapshot delivery of processed data
+    fn mode(&self) -> OutputMode;
+
+    /// Start (or restart) the output (servers, connections); returns once started
+    async fn start(&self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Watch the started output; returns an error when it fails (never by default)
+    async fn monitor(&self) -> Result<()> {
+        std::future::pending().await
+    }
+
+    /// Deliver processed data
+    async fn handle_data(&self, data: &ProcessedData) -> Result<()>;
+
+    /// Deliver an alarm event
+    async fn handle_event(&self, event: &AlarmEvent) -> Result<()>;
+
+    /// Stop the output and release its resources
+    async fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Current health of the output
+    fn health(&self) -> SinkHealth;
+
+    /// Statistics of the output queue, if the output is queued
+    fn queue_stats(&self) -> Option<QueueStats> {
+        None
+    }
+}
+
+/// ID SRS: SRS-FN-SINK-002
+/// Title: build_sinks
+///
+/// Description: VRConnect shall create the outputs enabled in the
+/// configuration with their decimation, route and delivery mode, each behind
+/// its own bounded queue.
+///
+/// Version: V1.0
+///
+/// # Arguments
+/// * `config` - Application configuration
+///
+/// # Returns
+/// Enabled outputs, or configuration error
+pub async fn build_sinks(config: &Config) -> Result<Vec<Arc<dyn OutputSink>>> {
+    let mut sinks: Vec<Arc<dyn OutputSink>> = Vec::new();
+
+    if config.output_console_enabled {
+        let console = Arc::new(
+            ConsoleOutput::new(
+                config.output_console_verbose,
+                config.output_console_colorized,
+                Decimation::parse(&config.output_console_waveform_decimation).map_err(VitalError::Config)?,
+            )
+            .with_route(OutputRoute::parse(&config.output_console_devices).map_err(VitalError::Config)?)
+            .with_mode(OutputMode::parse(&config.output_console_mode).map_err(VitalError::Config)?),
+        );
+        let policy = OverflowPolicy::parse(&config.output_console_overflow).map_err(VitalError::Config)?;
+        sinks.push(Arc::new(QueuedSink::new(console, config.output_queue_capacity, policy)));
+    }
+
+    if config.output_ble_enabled {
+        let decimation = Decimation::parse(&config.output_ble_waveform_decimation).map_err(VitalError::Config)?;
+        if decimation.is_none() {
+            log::warn!("⚠️  BLE Output: Waveform tracks excluded (MTU limit)");
+        }
+        let ble = Arc::new(
+            BleOutput::new(
+                config.output_ble_device_name.clone(),
+                config.output_ble_service_uuid.clone(),
+                decimation,
+            )
+            .await?
+            .with_route(OutputRoute::parse(&config.output_ble_devices).map_err(VitalError::Config)?)
+            .with_mode(OutputMode::parse(&config.output_ble_mode).map_err(VitalError::Config)?),
+        );
+        let policy = OverflowPolicy::parse(&config.output_ble_overflow).map_err(VitalError::Config)?;
+        sinks.push(Arc::new(QueuedSink::new(ble, config.output_queue_capacity, policy)));
+    }
+
+    Ok(sinks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn test_build_sinks_from_config() {
+        let mut config = Config::parse_from(["vrconnect"]);
+        config.output_ble_enabled = true;
+
+        let sinks = build_sinks(&config).await.unwrap();
+        let names: Vec<&str> = sinks.iter().map(|sink| sink.name()).collect();
+        assert_eq!(names, vec!["console", "ble"]);
+        assert_eq!(sinks[0].mode(), OutputMode::Delta);
+        assert_eq!(sinks[1].mode(), OutputMode::Snapshot);
+        assert!(sinks[0].health().is_healthy());
+        assert!(matches!(sinks[1].health(), SinkHealth::Down(_)));
+        assert_eq!(sinks[0].queue_stats().unwrap().capacity, config.output_queue_capacity);
+
+        config.output_console_mode = "full".to_string();
+        assert!(build_sinks(&config).await.is_err());
+    }
+}
@@ -0,0 +1,256 @@
+// /src/pipeline/builtin.rs
+// Module: pipeline.builtin
+// Purpose: Built-in processing stages and pipeline construction from configuration
+
+use crate::config::Config;
+use crate::core::{ClockSync, Sequencer, TrendTracker};
+use crate::derived::{EarlyWarningCalculator, EarlyWarningScore, HeartRateDetector};
+use crate::domain::{AlarmEvent, ProcessedData};
+use crate::pipeline::stage::parse_order;
+use crate::pipeline::{Deidentifier, Pipeline, PipelineStage, UnitConversion, UnitConverter};
+use crate::signal::{FilterRule, QualityAssessor, WaveformFilter, WaveformStatistics};
+
+impl PipelineStage for Sequencer {
+    fn name(&self) -> &str {
+        "sequencing"
+    }
+
+    fn process(&mut self, data: &mut ProcessedData) -> Vec<AlarmEvent> {
+        self.apply(data);
+        Vec::new()
+    }
+}
+
+impl PipelineStage for ClockSync {
+    fn name(&self) -> &str {
+        "clock"
+    }
+
+    fn process(&mut self, data: &mut ProcessedData) -> Vec<AlarmEvent> {
+        self.apply(data)
+    }
+}
+
+impl PipelineStage for QualityAssessor {
+    fn name(&self) -> &str {
+        "quality"
+    }
+
+    fn process(&mut self, data: &mut ProcessedData) -> Vec<AlarmEvent> {
+        self.apply(data);
+        Vec::new()
+    }
+}
+
+impl PipelineStage for WaveformFilter {
+    fn name(&self) -> &str {
+        "filter"
+    }
+
+    fn process(&mut self, data: &mut ProcessedData) -> Vec<AlarmEvent> {
+        self.apply(data);
+        Vec::new()
+    }
+}
+
+impl PipelineStage for WaveformStatistics {
+    fn name(&self) -> &str {
+        "statistics"
+    }
+
+    fn process(&mut self, data: &mut ProcessedData) -> Vec<AlarmEvent> {
+        self.apply(data);
+        Vec::new()
+    }
+}
+
+impl PipelineStage for HeartRateDetector {
+    fn name(&self) -> &str {
+        "heart_rate"
+    }
+
+    fn process(&mut self, data: &mut ProcessedData) -> Vec<AlarmEvent> {
+        self.apply(data);
+        Vec::new()
+    }
+}
+
+impl PipelineStage for EarlyWarningCalculator {
+    fn name(&self) -> &str {
+        "early_warning"
+    }
+
+    fn process(&mut self, data: &mut ProcessedData) -> Vec<AlarmEvent> {
+        self.apply(data);
+        Vec::new()
+    }
+}
+
+impl PipelineStage for TrendTracker {
+    fn name(&self) -> &str {
+        "trend"
+    }
+
+    fn process(&mut self, data: &mut ProcessedData) -> Vec<AlarmEvent> {
+        self.apply(data);
+        Vec::new()
+    }
+}
+
+impl PipelineStage for UnitConverter {
+    fn name(&self) -> &str {
+        "units"
+    }
+
+    fn process(&mut self, data: &mut ProcessedData) -> Vec<AlarmEvent> {
+        self.apply(data);
+        Vec::new()
+    }
+}
+
+impl PipelineStage for Deidentifier {
+    fn name(&self) -> &str {
+        "deidentify"
+    }
+
+    fn process(&mut self, data: &mut ProcessedData) -> Vec<AlarmEvent> {
+        self.apply(data);
+        Vec::new()
+    }
+
+    fn process_event(&mut self, event: &mut AlarmEvent) {
+        self.apply_event(event);
+    }
+}
+
+/// ID SRS: SRS-FN-BUILTIN-001
+/// Title: build_stage
+///
+/// Description: VRConnect shall create a built-in stage from the
+/// configuration, or none when the stage is disabled.
+///
+/// Version: V1.0
+///
+/// # Arguments
+/// * `name` - Built-in stage name
+/// * `config` - Application configuration
+///
+/// # Returns
+/// Stage if enabled, or error message
+fn build_stage(name: &str, config: &Config) -> Result<Option<Box<dyn PipelineStage>>, String> {
+    let stage: Option<Box<dyn PipelineStage>> = match name {
+        "sequencing" => config.sequencing_enabled.then(|| {
+            Box::new(Sequencer::new(config.sequencing_jitter_ms, config.sequencing_max_skew_secs)) as Box<dyn PipelineStage>
+        }),
+        "clock" => config.clock_sync_enabled.then(|| {
+            Box::new(ClockSync::new(config.clock_drift_threshold_ms, config.clock_correction_enabled)) as Box<dyn PipelineStage>
+        }),
+        "quality" => config
+            .signal_quality_enabled
+            .then(|| Box::new(QualityAssessor::new()) as Box<dyn PipelineStage>),
+        "filter" => {
+            let rules = FilterRule::parse_list(&config.waveform_filters)?;
+            (!rules.is_empty()).then(|| Box::new(WaveformFilter::new(rules)) as Box<dyn PipelineStage>)
+        }
+        "statistics" => {
+            if config.waveform_extended_stats {
+                let percentiles = WaveformStatistics::parse_percentiles(&config.waveform_stats_percentiles)?;
+                Some(Box::new(WaveformStatistics::new(percentiles)))
+            } else {
+                None
+            }
+        }
+        "heart_rate" => config
+            .qrs_detection_enabled
+            .then(|| Box::new(HeartRateDetector::new(config.qrs_window_secs)) as Box<dyn PipelineStage>),
+        "early_warning" => {
+            let scores = EarlyWarningScore::parse_list(&config.early_warning_scores)?;
+            (!scores.is_empty()).then(|| {
+                Box::new(EarlyWarningCalculator::new(scores, config.early_warning_max_input_age_secs))
+                    as Box<dyn PipelineStage>
+            })
+        }
+        "trend" => config.trend_enabled.then(|| {
+            Box::new(TrendTracker::new(config.trend_window_secs, config.trend_stable_percent)) as Box<dyn PipelineStage>
+        }),
+        "units" => {
+            let conversions = UnitConversion::parse_list(&config.unit_conversions)?;
+            (!conversions.is_empty()).then(|| Box::new(UnitConverter::new(conversions)) as Box<dyn PipelineStage>)
+        }
+        "deidentify" => config
+            .deidentify_enabled
+            .then(|| Box::new(Deidentifier::new(&config.deidentify_salt)) as Box<dyn PipelineStage>),
+        _ => return Err(format!("Unknown pipeline stage '{}'", name)),
+    };
+    Ok(stage)
+}
+
+/// ID SRS: SRS-FN-BUILTIN-002
+/// Title: build_pipeline
+///
+/// Description: VRConnect shall create the pipeline of the built-in stages
+/// enabled in the configuration, in the configured stage order.
+///
+/// Version: V1.0
+///
+/// # Arguments
+/// * `config` - Application configuration
+///
+/// # Returns
+/// Pipeline, or error message
+pub fn build_pipeline(config: &Config) -> Result<Pipeline, String> {
+    let mut pipeline = Pipeline::new();
+    for name in parse_order(&config.pipeline_stages)? {
+        if let Some(stage) = build_stage(&name, config)? {
+            pipeline.push(stage);
+        }
+    }
+    Ok(pipeline)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{AlarmCondition, ProcessedTrack};
+    use chrono::{Duration, Utc};
+    use clap::Parser;
+
+    #[test]
+    fn test_build_pipeline_from_config() {
+        let mut config = Config::parse_from(["vrconnect"]);
+        config.pipeline_stages = "trend,early_warning,units,deidentify".to_string();
+        config.trend_enabled = true;
+        config.early_warning_scores = "news2".to_string();
+        config.unit_conversions = "°C=°F".to_string();
+        config.deidentify_enabled = false;
+
+        let pipeline = build_pipeline(&config).unwrap();
+        assert_eq!(pipeline.names(), vec!["trend", "early_warning", "units"]);
+
+        config.unit_conversions = "°C=kPa".to_string();
+        assert!(build_pipeline(&config).is_err());
+    }
+
+    #[test]
+    fn test_deidentify_events_of_earlier_stages() {
+        let mut config = Config::parse_from(["vrconnect"]);
+        config.pipeline_stages = "clock,deidentify".to_string();
+        config.clock_sync_enabled = true;
+        config.clock_drift_threshold_ms = 1000;
+        config.deidentify_enabled = true;
+        config.deidentify_salt = "secret".to_string();
+        let mut pipeline = build_pipeline(&config).unwrap();
+
+        // Device clock one minute behind
+        let mut data = ProcessedData::new("VR-BED-12".to_string(), Vec::new());
+        let track = ProcessedTrack::synthetic("HR", Some(60.0), "/min", 0, "OR-1", Utc::now() - Duration::seconds(60));
+        data.add_tracks(0, "OR-1", vec![track]);
+        let events = pipeline.process(&mut data);
+
+        let device = Deidentifier::new("secret").pseudonym("device", "VR-BED-12");
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].condition, AlarmCondition::ClockDrift);
+        assert_eq!(events[0].device_id, device);
+        assert_eq!(events[0].alarm_id, format!("{}/*/CLOCK_DRIFT", device));
+    }
+}
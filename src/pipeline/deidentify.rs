@@ -0,0 +1,174 @@
+// /src/pipeline/deidentify.rs
+// Module: pipeline.deidentify
+// Purpose: Replacement of device and room identifiers by stable pseudonyms
+
+use crate::core::watchdog::ALL_ROOMS;
+use crate::domain::{AlarmEvent, ProcessedData};
+use uuid::Uuid;
+
+/// ID SRS: SRS-MOD-DEIDENTIFY-001
+/// Title: Deidentifier
+///
+/// Description: VRConnect shall replace the device identifier and room names
+/// (bed or patient labels) of a record by pseudonyms derived from a salted
+/// hash, identical for the same name and salt across restarts and unrelated
+/// to the name without the salt.
+///
+/// Version: V1.0
+pub struct Deidentifier {
+    namespace: Uuid,
+}
+
+impl Deidentifier {
+    /// ID SRS: SRS-FN-DEIDENTIFY-001
+    /// Title: new
+    ///
+    /// Description: VRConnect shall construct a Deidentifier from a secret
+    /// salt.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `salt` - Secret salt of the pseudonyms
+    ///
+    /// # Returns
+    /// New Deidentifier instance
+    pub fn new(salt: &str) -> Self {
+        Self {
+            namespace: Uuid::new_v5(&Uuid::NAMESPACE_OID, salt.as_bytes()),
+        }
+    }
+
+    /// ID SRS: SRS-FN-DEIDENTIFY-002
+    /// Title: pseudonym
+    ///
+    /// Description: VRConnect shall derive the pseudonym of a name.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `kind` - Pseudonym prefix (device, room)
+    /// * `name` - Identifying name
+    ///
+    /// # Returns
+    /// Pseudonym such as "room-1f0c2a9b"
+    pub fn pseudonym(&self, kind: &str, name: &str) -> String {
+        let hash = Uuid::new_v5(&self.namespace, format!("{}:{}", kind, name).as_bytes());
+        format!("{}-{}", kind, &hash.simple().to_string()[..8])
+    }
+
+    /// ID SRS: SRS-FN-DEIDENTIFY-003
+    /// Title: apply
+    ///
+    /// Description: VRConnect shall pseudonymize the device identifier and
+    /// the room names of a record and of its tracks in place.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `data` - Processed vital data, modified in place
+    pub fn apply(&self, data: &mut ProcessedData) {
+        data.device_id = self.pseudonym("device", &data.device_id);
+
+        for room in data.rooms.iter_mut() {
+            room.room_name = self.pseudonym("room", &room.room_name);
+            for track in room.tracks.iter_mut() {
+                track.room_name = room.room_name.clone();
+            }
+        }
+
+        data.refresh_all_tracks();
+    }
+
+    /// ID SRS: SRS-FN-DEIDENTIFY-005
+    /// Title: apply_event
+    ///
+    /// Description: VRConnect shall pseudonymize the device identifier, the
+    /// room name and the alarm identifier of an event raised before
+    /// de-identification (e.g. clock drift), so that no output receives the
+    /// real identifiers.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `event` - Alarm event, modified in place
+    pub fn apply_event(&self, event: &mut AlarmEvent) {
+        event.device_id = self.pseudonym("device", &event.device_id);
+        if event.room_name != ALL_ROOMS {
+            event.room_name = self.pseudonym("room", &event.room_name);
+        }
+        event.alarm_id = AlarmEvent::alarm_id_for(&event.device_id, &event.room_name, &event.parameter);
+    }
+
+    /// ID SRS: SRS-FN-DEIDENTIFY-004
+    /// Title: check_scope
+    ///
+    /// Description: VRConnect shall check that a device or room named in
+    /// configuration (output route, alarm limit override) is given as its
+    /// pseudonym, since routing and limit resolution see pseudonymized data.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `what` - Configuration item, for the error message
+    /// * `device_id` - Device name, or None for any device
+    /// * `room_name` - Room name, or None for any room
+    ///
+    /// # Returns
+    /// Ok, or error message giving the expected pseudonym
+    pub fn check_scope(&self, what: &str, device_id: Option<&str>, room_name: Option<&str>) -> Result<(), String> {
+        for (kind, name) in [("device", device_id), ("room", room_name)] {
+            let Some(name) = name else { continue };
+            let is_pseudonym = name
+                .strip_prefix(kind)
+                .and_then(|rest| rest.strip_prefix('-'))
+                .is_some_and(|hash| hash.len() == 8 && hash.chars().all(|c| c.is_ascii_hexdigit()));
+            if !is_pseudonym {
+                return Err(format!(
+                    "{} {} '{}' must use its pseudonym '{}' with de-identification",
+                    what,
+                    kind,
+                    name,
+                    self.pseudonym(kind, name)
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::ProcessedTrack;
+    use chrono::Utc;
+
+    #[test]
+    fn test_stable_salted_pseudonyms() {
+        let deidentifier = Deidentifier::new("secret");
+        let mut data = ProcessedData::new("VR-BED-12".to_string(), Vec::new());
+        let track = ProcessedTrack::synthetic("HR", Some(60.0), "/min", 0, "DOE John", Utc::now());
+        data.add_tracks(0, "DOE John", vec![track]);
+
+        deidentifier.apply(&mut data);
+
+        let room = deidentifier.pseudonym("room", "DOE John");
+        assert_eq!(data.device_id, deidentifier.pseudonym("device", "VR-BED-12"));
+        assert!(data.device_id.starts_with("device-") && data.device_id.len() == 15);
+        assert_eq!(data.rooms[0].room_name, room);
+        assert_eq!(data.all_tracks[0].room_name, room);
+        assert_ne!(Deidentifier::new("other").pseudonym("room", "DOE John"), room);
+    }
+
+    #[test]
+    fn test_check_scope() {
+        let deidentifier = Deidentifier::new("secret");
+        let device = deidentifier.pseudonym("device", "VR-BED-12");
+        assert!(deidentifier.check_scope("Route", Some(&device), None).is_ok());
+        assert!(deidentifier.check_scope("Route", None, None).is_ok());
+
+        let error = deidentifier.check_scope("Route", Some("VR-BED-12"), None).unwrap_err();
+        assert!(error.contains(&device));
+        assert!(deidentifier.check_scope("Route", Some(&device), Some("OR-1")).is_err());
+    }
+}
@@ -0,0 +1,15 @@
+// /src/pipeline/mod.rs
+// Module: pipeline
+// Purpose: Ordered built-in and user-defined processing stages on raw frames and processed data
+
+pub mod builtin;
+pub mod deidentify;
+pub mod raw;
+pub mod stage;
+pub mod units;
+
+pub use builtin::build_pipeline;
+pub use deidentify::Deidentifier;
+pub use raw::{RawPipeline, RawStage};
+pub use stage::{Pipeline, PipelineStage, StagePosition};
+pub use units::{UnitConversion, UnitConverter};
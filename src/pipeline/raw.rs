@@ -0,0 +1,180 @@
+// /src/pipeline/raw.rs
+// Module: pipeline.raw
+// Purpose: Stages applied to the bytes of a received frame before parsing
+
+use crate::error::{Result, VitalError};
+use crate::input::decompressor::VitalDataDecompressor;
+use crate::metrics::metrics;
+use crate::pipeline::stage::{insert_at, StagePosition};
+use crate::processor::VitalDataCleaner;
+use std::sync::Arc;
+
+/// ID SRS: SRS-MOD-RAW-001
+/// Title: RawStage
+///
+/// Description: VRConnect shall apply pre-parse steps (decompression,
+/// cleaning, user-defined decoding) to the bytes of every received frame
+/// through a common interface.
+///
+/// Version: V1.0
+pub trait RawStage: Send + Sync {
+    /// Stage name used for ordering and in logs
+    fn name(&self) -> &str;
+
+    /// Transform the frame bytes
+    #[allow(clippy::result_large_err)]
+    fn process(&self, data: Vec<u8>) -> Result<Vec<u8>>;
+}
+
+impl RawStage for VitalDataDecompressor {
+    fn name(&self) -> &str {
+        "decompress"
+    }
+
+    fn process(&self, data: Vec<u8>) -> Result<Vec<u8>> {
+        metrics().decompression_bytes_in.add(data.len() as u64);
+        let decompressed = self.decompress(&data)?;
+        metrics().decompression_bytes_out.add(decompressed.len() as u64);
+        Ok(decompressed)
+    }
+}
+
+impl RawStage for VitalDataCleaner {
+    fn name(&self) -> &str {
+        "clean"
+    }
+
+    fn process(&self, data: Vec<u8>) -> Result<Vec<u8>> {
+        let json_str = String::from_utf8(data)
+            .map_err(|e| VitalError::Processing(format!("UTF-8 conversion failed: {}", e)))?;
+        Ok(self.clean(&json_str)?.into_bytes())
+    }
+}
+
+/// ID SRS: SRS-MOD-RAW-002
+/// Title: RawPipeline
+///
+/// Description: VRConnect shall run the raw stages in order on every received
+/// frame, by default decompression then JSON cleaning.
+///
+/// Version: V1.0
+#[derive(Clone)]
+pub struct RawPipeline {
+    stages: Vec<Arc<dyn RawStage>>,
+}
+
+impl RawPipeline {
+    /// ID SRS: SRS-FN-RAW-001
+    /// Title: new
+    ///
+    /// Description: VRConnect shall construct a RawPipeline with the built-in
+    /// decompress and clean stages.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// New RawPipeline instance
+    pub fn new() -> Self {
+        Self {
+            stages: vec![
+                Arc::new(VitalDataDecompressor::new()),
+                Arc::new(VitalDataCleaner::new()),
+            ],
+        }
+    }
+
+    /// ID SRS: SRS-FN-RAW-002
+    /// Title: insert
+    ///
+    /// Description: VRConnect shall insert a raw stage at a position of the
+    /// raw pipeline.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `position` - Insertion position
+    /// * `stage` - Stage to insert
+    ///
+    /// # Returns
+    /// Result, or error message if the referenced stage does not exist
+    pub fn insert(&mut self, position: StagePosition, stage: Arc<dyn RawStage>) -> std::result::Result<(), String> {
+        insert_at(&mut self.stages, position, stage, |stage| stage.name())
+    }
+
+    /// ID SRS: SRS-FN-RAW-003
+    /// Title: stages
+    ///
+    /// Description: VRConnect shall provide the raw stages in order.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Raw stages
+    pub fn stages(&self) -> &[Arc<dyn RawStage>] {
+        &self.stages
+    }
+
+    /// ID SRS: SRS-FN-RAW-004
+    /// Title: process
+    ///
+    /// Description: VRConnect shall apply every raw stage in order to the
+    /// bytes of a frame.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `data` - Received bytes
+    ///
+    /// # Returns
+    /// Bytes ready for parsing, or the error of the first failing stage
+    #[allow(clippy::result_large_err)]
+    pub fn process(&self, data: Vec<u8>) -> Result<Vec<u8>> {
+        self.stages.iter().try_fold(data, |data, stage| stage.process(data))
+    }
+}
+
+impl Default for RawPipeline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::write::ZlibEncoder;
+    use flate2::Compression;
+    use std::io::Write;
+
+    /// Strips a 2-byte vendor header before decompression
+    struct StripHeader;
+
+    impl RawStage for StripHeader {
+        fn name(&self) -> &str {
+            "strip_header"
+        }
+
+        fn process(&self, data: Vec<u8>) -> Result<Vec<u8>> {
+            Ok(data[2..].to_vec())
+        }
+    }
+
+    #[test]
+    fn test_raw_pipeline_with_user_stage() {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(br#"{"value": NaN}"#).unwrap();
+        let mut frame = vec![0xAB, 0xCD];
+        frame.extend(encoder.finish().unwrap());
+
+        let mut pipeline = RawPipeline::new();
+        pipeline
+            .insert(StagePosition::Before("decompress".to_string()), Arc::new(StripHeader))
+            .unwrap();
+        let names: Vec<&str> = pipeline.stages().iter().map(|stage| stage.name()).collect();
+        assert_eq!(names, vec!["strip_header", "decompress", "clean"]);
+
+        let json = String::from_utf8(pipeline.process(frame).unwrap()).unwrap();
+        assert_eq!(json, r#"{"value": null}"#);
+        assert!(RawPipeline::new().process(vec![0xFF, 0xFE]).is_err());
+    }
+}
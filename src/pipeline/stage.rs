@@ -0,0 +1,266 @@
+// /src/pipeline/stage.rs
+// Module: pipeline.stage
+// Purpose: Ordered processing stages applied to every processed record
+
+use crate::domain::{AlarmEvent, ProcessedData};
+
+/// Built-in stages in their default order
+pub const DEFAULT_STAGE_ORDER: [&str; 10] = [
+    "sequencing",
+    "clock",
+    "quality",
+    "filter",
+    "statistics",
+    "heart_rate",
+    "early_warning",
+    "trend",
+    "units",
+    "deidentify",
+];
+
+/// ID SRS: SRS-MOD-STAGE-001
+/// Title: PipelineStage
+///
+/// Description: VRConnect shall apply processing steps to every processed
+/// record through a common interface, so that built-in and user-defined
+/// steps (filtering, derived metrics, conversion, ...) can be ordered freely.
+///
+/// Version: V1.0
+pub trait PipelineStage: Send {
+    /// Stage name used for ordering and in logs
+    fn name(&self) -> &str;
+
+    /// Modify the record in place; returns the events raised by the stage
+    fn process(&mut self, data: &mut ProcessedData) -> Vec<AlarmEvent>;
+
+    /// Modify in place an event raised by an earlier stage for the same record
+    fn process_event(&mut self, _event: &mut AlarmEvent) {}
+}
+
+/// ID SRS: SRS-MOD-STAGE-002
+/// Title: StagePosition
+///
+/// Description: VRConnect shall insert a user-defined stage at the start or
+/// end of a pipeline, or before or after a named stage.
+///
+/// Version: V1.0
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StagePosition {
+    First,
+    Last,
+    Before(String),
+    After(String),
+}
+
+/// ID SRS: SRS-FN-STAGE-001
+/// Title: insert_at
+///
+/// Description: VRConnect shall insert an item into an ordered list of
+/// stages at the requested position.
+///
+/// Version: V1.0
+///
+/// # Arguments
+/// * `items` - Ordered stages
+/// * `position` - Insertion position
+/// * `item` - Stage to insert
+/// * `name_of` - Name of a stage
+///
+/// # Returns
+/// Result, or error message if the referenced stage is not in the list
+pub(crate) fn insert_at<T>(
+    items: &mut Vec<T>,
+    position: StagePosition,
+    item: T,
+    name_of: impl Fn(&T) -> &str,
+) -> Result<(), String> {
+    let index = match position {
+        StagePosition::First => 0,
+        StagePosition::Last => items.len(),
+        StagePosition::Before(ref name) | StagePosition::After(ref name) => {
+            let index = items
+                .iter()
+                .position(|existing| name_of(existing) == name)
+                .ok_or_else(|| format!("Pipeline stage '{}' is not active", name))?;
+            match position {
+                StagePosition::After(_) => index + 1,
+                _ => index,
+            }
+        }
+    };
+    items.insert(index, item);
+    Ok(())
+}
+
+/// ID SRS: SRS-MOD-STAGE-003
+/// Title: Pipeline
+///
+/// Description: VRConnect shall run the stages of the pipeline in order on
+/// every processed record, collecting the events they raise.
+///
+/// Version: V1.0
+#[derive(Default)]
+pub struct Pipeline {
+    stages: Vec<Box<dyn PipelineStage>>,
+}
+
+impl Pipeline {
+    /// ID SRS: SRS-FN-STAGE-002
+    /// Title: new
+    ///
+    /// Description: VRConnect shall construct an empty Pipeline.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// New Pipeline instance
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// ID SRS: SRS-FN-STAGE-003
+    /// Title: push
+    ///
+    /// Description: VRConnect shall append a stage to the pipeline.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `stage` - Stage to append
+    pub fn push(&mut self, stage: Box<dyn PipelineStage>) {
+        self.stages.push(stage);
+    }
+
+    /// ID SRS: SRS-FN-STAGE-004
+    /// Title: insert
+    ///
+    /// Description: VRConnect shall insert a stage at a position of the
+    /// pipeline.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `position` - Insertion position
+    /// * `stage` - Stage to insert
+    ///
+    /// # Returns
+    /// Result, or error message if the referenced stage is not active
+    pub fn insert(&mut self, position: StagePosition, stage: Box<dyn PipelineStage>) -> Result<(), String> {
+        insert_at(&mut self.stages, position, stage, |stage| stage.name())
+    }
+
+    /// ID SRS: SRS-FN-STAGE-005
+    /// Title: names
+    ///
+    /// Description: VRConnect shall list the stages of the pipeline in order.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// Stage names
+    pub fn names(&self) -> Vec<&str> {
+        self.stages.iter().map(|stage| stage.name()).collect()
+    }
+
+    /// ID SRS: SRS-FN-STAGE-006
+    /// Title: process
+    ///
+    /// Description: VRConnect shall apply every stage in order to a record,
+    /// and to the events raised by the stages before it.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `data` - Processed record, modified in place
+    ///
+    /// # Returns
+    /// Events raised by the stages
+    pub fn process(&mut self, data: &mut ProcessedData) -> Vec<AlarmEvent> {
+        let mut events = Vec::new();
+        for stage in self.stages.iter_mut() {
+            for event in events.iter_mut() {
+                stage.process_event(event);
+            }
+            events.extend(stage.process(data));
+        }
+        events
+    }
+}
+
+/// ID SRS: SRS-FN-STAGE-007
+/// Title: parse_order
+///
+/// Description: VRConnect shall parse the comma-separated order of the
+/// built-in stages. A built-in stage missing from the list is not run.
+///
+/// Version: V1.0
+///
+/// # Arguments
+/// * `spec` - Stage list such as "sequencing,clock,filter"
+///
+/// # Returns
+/// Stage names or error message
+pub fn parse_order(spec: &str) -> Result<Vec<String>, String> {
+    let mut order: Vec<String> = Vec::new();
+    for name in spec.split(',').map(|name| name.trim().to_lowercase()).filter(|name| !name.is_empty()) {
+        if !DEFAULT_STAGE_ORDER.contains(&name.as_str()) {
+            return Err(format!(
+                "Unknown pipeline stage '{}' (expected one of: {})",
+                name,
+                DEFAULT_STAGE_ORDER.join(", ")
+            ));
+        }
+        if order.contains(&name) {
+            return Err(format!("Pipeline stage '{}' listed twice", name));
+        }
+        order.push(name);
+    }
+    Ok(order)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::ProcessedTrack;
+    use chrono::Utc;
+
+    /// Appends a track named after the stage
+    struct Marker(&'static str);
+
+    impl PipelineStage for Marker {
+        fn name(&self) -> &str {
+            self.0
+        }
+
+        fn process(&mut self, data: &mut ProcessedData) -> Vec<AlarmEvent> {
+            let track = ProcessedTrack::synthetic(self.0, Some(1.0), "", 0, "OR-1", Utc::now());
+            data.add_tracks(0, "OR-1", vec![track]);
+            Vec::new()
+        }
+    }
+
+    #[test]
+    fn test_insert_and_process_in_order() {
+        let mut pipeline = Pipeline::new();
+        pipeline.push(Box::new(Marker("a")));
+        pipeline.push(Box::new(Marker("c")));
+        pipeline.insert(StagePosition::After("a".to_string()), Box::new(Marker("b"))).unwrap();
+        pipeline.insert(StagePosition::First, Box::new(Marker("start"))).unwrap();
+        pipeline.insert(StagePosition::Before("c".to_string()), Box::new(Marker("b2"))).unwrap();
+        assert!(pipeline.insert(StagePosition::After("x".to_string()), Box::new(Marker("d"))).is_err());
+        assert_eq!(pipeline.names(), vec!["start", "a", "b", "b2", "c"]);
+
+        let mut data = ProcessedData::new("VR-1".to_string(), Vec::new());
+        pipeline.process(&mut data);
+        let names: Vec<&str> = data.all_tracks.iter().map(|track| track.name.as_str()).collect();
+        assert_eq!(names, vec!["start", "a", "b", "b2", "c"]);
+    }
+
+    #[test]
+    fn test_parse_order() {
+        assert_eq!(parse_order(" Filter, sequencing ").unwrap(), vec!["filter", "sequencing"]);
+        assert!(parse_order("filter,filter").is_err());
+        assert!(parse_order("anonymize").is_err());
+        assert_eq!(parse_order(&DEFAULT_STAGE_ORDER.join(",")).unwrap().len(), 10);
+    }
+}
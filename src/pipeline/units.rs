@@ -0,0 +1,333 @@
+// /src/pipeline/units.rs
+// Module: pipeline.units
+// Purpose: Conversion of values, waveforms and statistics to other units
+
+use crate::domain::{ProcessedData, ProcessedTrack};
+
+/// Known unit: canonical name, accepted aliases, dimension, and
+/// `scale`/`offset` such that `base = value * scale + offset`
+struct UnitDef {
+    name: &'static str,
+    aliases: &'static [&'static str],
+    dimension: &'static str,
+    scale: f64,
+    offset: f64,
+}
+
+/// Supported units (base: °C, mmHg, kg)
+const UNITS: &[UnitDef] = &[
+    UnitDef { name: "°C", aliases: &["C", "degC", "celsius"], dimension: "temperature", scale: 1.0, offset: 0.0 },
+    UnitDef { name: "°F", aliases: &["F", "degF", "fahrenheit"], dimension: "temperature", scale: 5.0 / 9.0, offset: -160.0 / 9.0 },
+    UnitDef { name: "K", aliases: &["kelvin"], dimension: "temperature", scale: 1.0, offset: -273.15 },
+    UnitDef { name: "mmHg", aliases: &["torr"], dimension: "pressure", scale: 1.0, offset: 0.0 },
+    UnitDef { name: "kPa", aliases: &[], dimension: "pressure", scale: 7.500_617, offset: 0.0 },
+    UnitDef { name: "hPa", aliases: &["mbar"], dimension: "pressure", scale: 0.750_062, offset: 0.0 },
+    UnitDef { name: "cmH2O", aliases: &[], dimension: "pressure", scale: 0.735_559, offset: 0.0 },
+    UnitDef { name: "kg", aliases: &[], dimension: "mass", scale: 1.0, offset: 0.0 },
+    UnitDef { name: "g", aliases: &[], dimension: "mass", scale: 0.001, offset: 0.0 },
+    UnitDef { name: "lb", aliases: &["lbs"], dimension: "mass", scale: 0.453_592_37, offset: 0.0 },
+];
+
+/// Find a known unit by name or alias (case-insensitive)
+fn lookup(name: &str) -> Option<&'static UnitDef> {
+    let name = name.trim();
+    UNITS.iter().find(|unit| {
+        unit.name.eq_ignore_ascii_case(name) || unit.aliases.iter().any(|alias| alias.eq_ignore_ascii_case(name))
+    })
+}
+
+/// ID SRS: SRS-MOD-UNITS-001
+/// Title: UnitConversion
+///
+/// Description: VRConnect shall define a linear conversion between two units
+/// of the same dimension (temperature, pressure, mass).
+///
+/// Version: V1.0
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnitConversion {
+    pub from: &'static str,
+    pub to: &'static str,
+    /// Converted value = value * factor + offset
+    pub factor: f64,
+    pub offset: f64,
+}
+
+impl UnitConversion {
+    /// ID SRS: SRS-FN-UNITS-001
+    /// Title: parse_list
+    ///
+    /// Description: VRConnect shall parse unit conversions separated by ",",
+    /// each of the form "FROM=TO" (e.g. "°C=°F,mmHg=kPa"). An empty string
+    /// yields no conversions.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `spec` - Conversion list
+    ///
+    /// # Returns
+    /// Parsed conversions or error message
+    pub fn parse_list(spec: &str) -> Result<Vec<Self>, String> {
+        spec.split(',')
+            .map(str::trim)
+            .filter(|conversion| !conversion.is_empty())
+            .map(|conversion| {
+                let (from, to) = conversion
+                    .split_once('=')
+                    .ok_or_else(|| format!("Invalid unit conversion '{}', expected FROM=TO", conversion))?;
+                let from = lookup(from).ok_or_else(|| format!("Unknown unit '{}'", from.trim()))?;
+                let to = lookup(to).ok_or_else(|| format!("Unknown unit '{}'", to.trim()))?;
+                if from.dimension != to.dimension {
+                    return Err(format!("Cannot convert {} to {}", from.name, to.name));
+                }
+
+                Ok(UnitConversion {
+                    from: from.name,
+                    to: to.name,
+                    factor: from.scale / to.scale,
+                    offset: (from.offset - to.offset) / to.scale,
+                })
+            })
+            .collect()
+    }
+
+    /// ID SRS: SRS-FN-UNITS-002
+    /// Title: convert
+    ///
+    /// Description: VRConnect shall convert a value to the target unit.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `value` - Value in the source unit
+    ///
+    /// # Returns
+    /// Value in the target unit
+    pub fn convert(&self, value: f64) -> f64 {
+        value * self.factor + self.offset
+    }
+
+    /// ID SRS: SRS-FN-UNITS-006
+    /// Title: targets
+    ///
+    /// Description: VRConnect shall tell whether a unit is the target unit of
+    /// the conversion (aliases included).
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `unit` - Unit name
+    ///
+    /// # Returns
+    /// true if values in this unit result from the conversion
+    pub fn targets(&self, unit: &str) -> bool {
+        lookup(unit).is_some_and(|unit| unit.name == self.to)
+    }
+}
+
+/// ID SRS: SRS-MOD-UNITS-002
+/// Title: UnitConverter
+///
+/// Description: VRConnect shall convert the tracks whose unit matches a
+/// configured conversion: value, display value, components, waveform points,
+/// statistics and trend.
+///
+/// Version: V1.0
+pub struct UnitConverter {
+    conversions: Vec<UnitConversion>,
+}
+
+impl UnitConverter {
+    /// ID SRS: SRS-FN-UNITS-003
+    /// Title: new
+    ///
+    /// Description: VRConnect shall construct a UnitConverter.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `conversions` - Conversions, first match on the track unit wins
+    ///
+    /// # Returns
+    /// New UnitConverter instance
+    pub fn new(conversions: Vec<UnitConversion>) -> Self {
+        Self { conversions }
+    }
+
+    /// ID SRS: SRS-FN-UNITS-004
+    /// Title: apply
+    ///
+    /// Description: VRConnect shall convert every matching track of a record
+    /// in place.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `data` - Processed vital data, modified in place
+    pub fn apply(&self, data: &mut ProcessedData) {
+        let mut converted = false;
+
+        for room in data.rooms.iter_mut() {
+            for track in room.tracks.iter_mut() {
+                let Some(unit) = lookup(&track.unit) else { continue };
+                if let Some(conversion) = self.conversions.iter().find(|conversion| conversion.from == unit.name) {
+                    Self::convert_track(conversion, track);
+                    converted = true;
+                }
+            }
+        }
+
+        if converted {
+            data.refresh_all_tracks();
+        }
+    }
+
+    /// ID SRS: SRS-FN-UNITS-005
+    /// Title: convert_track
+    ///
+    /// Description: VRConnect shall convert one track. Dispersion measures
+    /// (standard deviation, peak-to-peak, slopes) are only scaled; the display
+    /// value keeps its precision, with one more decimal when the unit is
+    /// coarser.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `conversion` - Conversion to apply
+    /// * `track` - Track, modified in place
+    fn convert_track(conversion: &UnitConversion, track: &mut ProcessedTrack) {
+        let (factor, offset) = (conversion.factor, conversion.offset);
+
+        let decimals = track.display_value.split_once('.').map_or(0, |(_, fraction)| {
+            fraction.chars().take_while(char::is_ascii_digit).count()
+        }) + usize::from(factor.abs() < 0.5);
+
+        track.raw_value = track.raw_value.map(|value| conversion.convert(value));
+        if let Ok(display) = track.display_value.trim().parse::<f64>() {
+            track.display_value = format!("{:.*}", decimals, conversion.convert(display));
+        }
+
+        if let Some(ref mut components) = track.components {
+            for component in components.iter_mut() {
+                component.value = conversion.convert(component.value);
+            }
+
+            // Composite display rebuilt as "systolic/diastolic (mean)"
+            let mut display = components
+                .iter()
+                .filter(|component| component.name != "mean")
+                .map(|component| format!("{:.*}", decimals, component.value))
+                .collect::<Vec<_>>()
+                .join("/");
+            if let Some(mean) = components.iter().find(|component| component.name == "mean") {
+                display.push_str(&format!(" ({:.*})", decimals, mean.value));
+            }
+            track.display_value = display;
+        }
+
+        if let Some(ref mut points) = track.waveform_points {
+            for point in points.iter_mut() {
+                *point = conversion.convert(*point);
+            }
+        }
+
+        if let Some(ref mut stats) = track.waveform_stats {
+            if let Some(ref mut extended) = stats.extended {
+                // rms of a*x + b from the rms and mean of x
+                let rms_squared = factor * factor * extended.rms * extended.rms
+                    + 2.0 * factor * offset * stats.avg
+                    + offset * offset;
+                extended.rms = rms_squared.max(0.0).sqrt();
+                extended.std_dev *= factor.abs();
+                extended.peak_to_peak *= factor.abs();
+                for percentile in extended.percentiles.iter_mut() {
+                    percentile.value = conversion.convert(percentile.value);
+                }
+            }
+            stats.min = conversion.convert(stats.min);
+            stats.max = conversion.convert(stats.max);
+            stats.avg = conversion.convert(stats.avg);
+        }
+
+        if let Some(ref mut trend) = track.trend {
+            trend.moving_average = conversion.convert(trend.moving_average);
+            trend.min = conversion.convert(trend.min);
+            trend.max = conversion.convert(trend.max);
+            trend.slope_per_min *= factor;
+            trend.rate_of_change_per_min = trend.rate_of_change_per_min.map(|rate| rate * factor);
+        }
+
+        track.unit = conversion.to.to_string();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{ExtendedWaveformStats, ValueComponent, WaveformStats};
+    use chrono::Utc;
+
+    #[test]
+    fn test_parse_list() {
+        let conversions = UnitConversion::parse_list("degC=°F, mmHg=kPa").unwrap();
+        assert_eq!((conversions[0].from, conversions[0].to), ("°C", "°F"));
+        assert!((conversions[0].convert(37.0) - 98.6).abs() < 1e-9);
+        assert!((conversions[1].convert(120.0) - 15.999).abs() < 1e-3);
+        assert!(UnitConversion::parse_list("°C=kPa").is_err());
+        assert!(UnitConversion::parse_list("°C").is_err());
+        assert!(UnitConversion::parse_list("furlong=kg").is_err());
+        assert!(UnitConversion::parse_list("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_convert_tracks() {
+        let converter = UnitConverter::new(UnitConversion::parse_list("°C=°F,mmHg=kPa").unwrap());
+
+        let mut temperature = ProcessedTrack::synthetic("BT", Some(37.0), "°C", 0, "OR-1", Utc::now());
+        temperature.display_value = "37.0".to_string();
+        let mut pressure = ProcessedTrack::synthetic("NIBP", None, "mmHg", 0, "OR-1", Utc::now());
+        pressure.display_value = "120/80 (93)".to_string();
+        pressure.components = Some(vec![
+            ValueComponent::new("systolic", 120.0),
+            ValueComponent::new("diastolic", 80.0),
+            ValueComponent::new("mean", 93.0),
+        ]);
+        let mut waveform = ProcessedTrack::synthetic("TEMP", None, "C", 0, "OR-1", Utc::now());
+        waveform.waveform_points = Some(vec![36.0, 38.0]);
+        waveform.waveform_stats = Some(WaveformStats {
+            min: 36.0,
+            max: 38.0,
+            avg: 37.0,
+            count: 2,
+            extended: Some(ExtendedWaveformStats {
+                std_dev: 1.0,
+                rms: (36.0f64.powi(2) / 2.0 + 38.0f64.powi(2) / 2.0).sqrt(),
+                peak_to_peak: 2.0,
+                percentiles: Vec::new(),
+                zero_crossing_rate: 0.0,
+                dominant_frequency: None,
+            }),
+        });
+        let untouched = ProcessedTrack::synthetic("HR", Some(60.0), "/min", 0, "OR-1", Utc::now());
+
+        let mut data = ProcessedData::new("VR-1".to_string(), Vec::new());
+        data.add_tracks(0, "OR-1", vec![temperature, pressure, waveform, untouched]);
+        converter.apply(&mut data);
+
+        let tracks = &data.all_tracks;
+        assert_eq!((tracks[0].display_value.as_str(), tracks[0].unit.as_str()), ("98.6", "°F"));
+        assert_eq!((tracks[1].display_value.as_str(), tracks[1].unit.as_str()), ("16.0/10.7 (12.4)", "kPa"));
+        assert!((tracks[1].components.as_ref().unwrap()[0].value - 15.999).abs() < 1e-3);
+
+        let points = tracks[2].waveform_points.as_ref().unwrap();
+        let stats = tracks[2].waveform_stats.as_ref().unwrap();
+        let extended = stats.extended.as_ref().unwrap();
+        let expected_rms = (points.iter().map(|point| point * point).sum::<f64>() / 2.0).sqrt();
+        assert!((extended.rms - expected_rms).abs() < 1e-9);
+        assert!((points[1] - 100.4).abs() < 1e-9);
+        assert!((stats.max - points[1]).abs() < 1e-9);
+        assert!((extended.peak_to_peak - 3.6).abs() < 1e-9);
+
+        assert_eq!((tracks[3].raw_value, tracks[3].unit.as_str()), (Some(60.0), "/min"));
+    }
+}
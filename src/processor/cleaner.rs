@@ -3,6 +3,7 @@
 // Purpose: JSON data cleaning and sanitization
 
 use crate::error::Result;
+use crate::metrics::metrics;
 use fancy_regex::Regex;
 
 /// ID SRS: SRS-MOD-CLEANER-001
@@ -60,18 +61,27 @@ impl VitalDataCleaner {
 
         // Step 1: Remove control characters
         let mut cleaned = self.remove_control_chars(json_str);
+        Self::count_repair("control_chars", json_str, &cleaned);
 
         // Step 2: Replace NaN with null
-        cleaned = self.replace_nan(&cleaned);
+        let step = self.replace_nan(&cleaned);
+        Self::count_repair("nan", &cleaned, &step);
+        cleaned = step;
 
         // Step 3: Replace Infinity with null
-        cleaned = self.replace_infinity(&cleaned);
+        let step = self.replace_infinity(&cleaned);
+        Self::count_repair("infinity", &cleaned, &step);
+        cleaned = step;
 
         // Step 4: Fix decimal separators in objects
-        cleaned = self.fix_decimal_obj(&cleaned);
+        let step = self.fix_decimal_obj(&cleaned);
+        Self::count_repair("decimal_separator", &cleaned, &step);
+        cleaned = step;
 
         // Step 5: Fix decimal separators in arrays
-        cleaned = self.fix_decimal_arr(&cleaned);
+        let step = self.fix_decimal_arr(&cleaned);
+        Self::count_repair("decimal_separator", &cleaned, &step);
+        cleaned = step;
 
         log::debug!("JSON cleaned, final length: {}", cleaned.len());
 
@@ -162,6 +172,24 @@ impl VitalDataCleaner {
     fn fix_decimal_arr(&self, json_str: &str) -> String {
         self.decimal_arr.replace_all(json_str, "$1$2.$3").to_string()
     }
+
+    /// ID SRS: SRS-FN-CLEANER-008
+    /// Title: count_repair
+    ///
+    /// Description: VRConnect shall count the frames modified by a cleaning
+    /// step in the processing metrics.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `kind` - Repair kind
+    /// * `before` - JSON string before the step
+    /// * `after` - JSON string after the step
+    fn count_repair(kind: &str, before: &str, after: &str) {
+        if before != after {
+            metrics().cleaning_repairs.with_label(kind).inc();
+        }
+    }
 }
 
 impl Default for VitalDataCleaner {
@@ -1,9 +1,10 @@
 // /src/processor/mod.rs
 // Module: processor
-// Purpose: Data processing modules for cleaning and transformation
+// Purpose: Data processing modules for cleaning, transformation and value parsing
 
 pub mod cleaner;
 pub mod transformer;
+pub mod value_parser;
 
 pub use cleaner::VitalDataCleaner;
 pub use transformer::VitalDataTransformer;
@@ -3,17 +3,21 @@
 // Purpose: Transform VitalData to ProcessedData with type detection and statistics
 
 use crate::domain::*;
+use crate::processor::value_parser::VitalValueParser;
 use chrono::{TimeZone, Utc};
 
 /// ID SRS: SRS-MOD-TRANSFORMER-001
 /// Title: VitalDataTransformer
 ///
 /// Description: VRConnect shall transform raw VitalData into ProcessedData,
-/// detecting track types, computing waveform statistics, and organizing by rooms.
+/// detecting track types, computing waveform statistics, parsing composite
+/// string values, and organizing by rooms.
 ///
 /// Version: V1.0
 #[derive(Clone)]
-pub struct VitalDataTransformer;
+pub struct VitalDataTransformer {
+    value_parser: VitalValueParser,
+}
 
 impl VitalDataTransformer {
     /// ID SRS: SRS-FN-TRANSFORMER-001
@@ -26,7 +30,9 @@ impl VitalDataTransformer {
     /// # Returns
     /// New VitalDataTransformer instance
     pub fn new() -> Self {
-        Self
+        Self {
+            value_parser: VitalValueParser::new(),
+        }
     }
 
     /// ID SRS: SRS-FN-TRANSFORMER-002
@@ -103,7 +109,8 @@ impl VitalDataTransformer {
     /// Title: process_track
     ///
     /// Description: VRConnect shall process a single track record, extracting
-    /// metadata, detecting type, computing statistics, and creating ProcessedTrack.
+    /// metadata, detecting type, computing statistics, parsing composite string
+    /// values into numeric components, and creating ProcessedTrack.
     ///
     /// Version: V1.0
     ///
@@ -138,13 +145,21 @@ impl VitalDataTransformer {
         let (track_type, display_value, raw_value, waveform_stats, waveform_points) =
             self.process_value(&record.value, track_type_str);
 
-        let timestamp = record
+        let components = if track_type == TrackType::String {
+            self.value_parser.parse(&display_value)
+        } else {
+            None
+        };
+
+        let device_timestamp = record
             .get_effective_timestamp()
-            .and_then(|ts| Utc.timestamp_millis_opt(ts).single())
-            .unwrap_or_else(Utc::now);
+            .and_then(|ts| Utc.timestamp_millis_opt(ts).single());
+        let timestamp_flag = device_timestamp.is_none().then_some(TimestampFlag::Missing);
+        let timestamp = device_timestamp.unwrap_or_else(Utc::now);
 
         ProcessedTrack {
             name: track_name,
+            track_id: track.id.clone(),
             display_value,
             raw_value,
             unit,
@@ -156,6 +171,14 @@ impl VitalDataTransformer {
             track_type,
             waveform_stats,
             waveform_points,
+            sample_rate: track.sample_rate,
+            components,
+            quality: None,
+            trend: None,
+            timestamp_flag,
+            device_timestamp: None,
+            age_ms: None,
+            signal_status: None,
         }
     }
 
@@ -228,33 +251,11 @@ impl VitalDataTransformer {
     ) {
         let numbers: Vec<f64> = arr.iter().filter_map(|v| v.as_f64()).collect();
 
-        if numbers.is_empty() {
+        let Some(stats) = WaveformStats::from_points(&numbers) else {
             return (TrackType::Waveform, "0 points".to_string(), None, None, None);
-        }
-
-        let min = numbers
-            .iter()
-            .copied()
-            .fold(f64::INFINITY, f64::min);
-        let max = numbers
-            .iter()
-            .copied()
-            .fold(f64::NEG_INFINITY, f64::max);
-        let sum: f64 = numbers.iter().sum();
-        let avg = sum / numbers.len() as f64;
-        let count = numbers.len();
-
-        let stats = WaveformStats {
-            min,
-            max,
-            avg,
-            count,
         };
 
-        let display = format!(
-            "{} points ({:.3} to {:.3}, avg: {:.3})",
-            count, min, max, avg
-        );
+        let display = stats.describe();
 
         (
             TrackType::Waveform,
@@ -300,6 +301,39 @@ mod tests {
         assert!(true);
     }
 
+    #[test]
+    fn test_process_track_composite_string() {
+        let transformer = VitalDataTransformer::new();
+        let track = VitalTrack {
+            id: Some("1".to_string()),
+            name: Some("NIBP".to_string()),
+            track_type: Some("str".to_string()),
+            unit: Some("mmHg".to_string()),
+            mon_type: None,
+            display_name: None,
+            sample_rate: None,
+            records: Vec::new(),
+        };
+        let record = VitalRecord {
+            value: serde_json::json!("120/80 (93)"),
+            timestamp: Some(1_700_000_000_000),
+            time: None,
+        };
+
+        let processed = transformer.process_track(&track, &record, 0, "OR-1", 0, 0);
+
+        assert_eq!(processed.track_type, TrackType::String);
+        assert_eq!(processed.display_value, "120/80 (93)");
+        assert_eq!(
+            processed.components,
+            Some(vec![
+                ValueComponent::new("systolic", 120.0),
+                ValueComponent::new("diastolic", 80.0),
+                ValueComponent::new("mean", 93.0),
+            ])
+        );
+    }
+
     #[test]
     fn test_process_waveform_empty() {
         // TODO: Implement empty waveform test
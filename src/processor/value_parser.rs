@@ -0,0 +1,135 @@
+// /src/processor/value_parser.rs
+// Module: processor.value_parser
+// Purpose: Parse composite string values (e.g. blood pressure) into numeric components
+
+use crate::domain::ValueComponent;
+use fancy_regex::Regex;
+
+/// ID SRS: SRS-MOD-VALUEPARSER-001
+/// Title: VitalValueParser
+///
+/// Description: VRConnect shall recognise composite string values sent by
+/// monitors (e.g. NIBP "120/80 (93)") and extract their numeric components
+/// (systolic, diastolic, mean) so outputs can transmit numbers instead of text.
+///
+/// Version: V1.0
+#[derive(Clone)]
+pub struct VitalValueParser {
+    pressure_pattern: Regex,
+}
+
+impl VitalValueParser {
+    /// ID SRS: SRS-FN-VALUEPARSER-001
+    /// Title: new
+    ///
+    /// Description: VRConnect shall construct a VitalValueParser instance with
+    /// precompiled regex patterns for supported composite formats.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// New VitalValueParser instance
+    pub fn new() -> Self {
+        Self {
+            // Accepted forms: "120/80", "120/80 (93)", "120/80/93", "120 / 80 mmHg"
+            pressure_pattern: Regex::new(
+                r"(?i)^\s*(\d+(?:\.\d+)?)\s*/\s*(\d+(?:\.\d+)?)(?:\s*\(\s*(\d+(?:\.\d+)?)\s*\)|\s*/\s*(\d+(?:\.\d+)?))?\s*(?:mmhg|cmh2o|kpa)?\s*$",
+            )
+            .unwrap(),
+        }
+    }
+
+    /// ID SRS: SRS-FN-VALUEPARSER-002
+    /// Title: parse
+    ///
+    /// Description: VRConnect shall parse a string value into numeric components,
+    /// returning None when the text does not match any supported composite format.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `text` - Raw string value from the record
+    ///
+    /// # Returns
+    /// Ordered components (systolic, diastolic, optional mean) or None
+    pub fn parse(&self, text: &str) -> Option<Vec<ValueComponent>> {
+        let captures = self.pressure_pattern.captures(text).ok()??;
+
+        let number = |index: usize| -> Option<f64> {
+            captures
+                .get(index)
+                .and_then(|m| m.as_str().parse::<f64>().ok())
+        };
+
+        let systolic = number(1)?;
+        let diastolic = number(2)?;
+        let mean = number(3).or_else(|| number(4));
+
+        let mut components = vec![
+            ValueComponent::new("systolic", systolic),
+            ValueComponent::new("diastolic", diastolic),
+        ];
+
+        if let Some(mean) = mean {
+            components.push(ValueComponent::new("mean", mean));
+        }
+
+        log::debug!("Parsed composite value '{}' into {} components", text, components.len());
+
+        Some(components)
+    }
+}
+
+impl Default for VitalValueParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(components: &[ValueComponent]) -> Vec<(&str, f64)> {
+        components
+            .iter()
+            .map(|c| (c.name.as_str(), c.value))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_systolic_diastolic() {
+        let parser = VitalValueParser::new();
+        let components = parser.parse("120/80").unwrap();
+        assert_eq!(values(&components), vec![("systolic", 120.0), ("diastolic", 80.0)]);
+    }
+
+    #[test]
+    fn test_parse_with_mean_in_parentheses() {
+        let parser = VitalValueParser::new();
+        let components = parser.parse("120/80 (93)").unwrap();
+        assert_eq!(
+            values(&components),
+            vec![("systolic", 120.0), ("diastolic", 80.0), ("mean", 93.0)]
+        );
+    }
+
+    #[test]
+    fn test_parse_with_mean_slash_and_unit() {
+        let parser = VitalValueParser::new();
+        let components = parser.parse(" 118.5 / 76 / 90 mmHg ").unwrap();
+        assert_eq!(
+            values(&components),
+            vec![("systolic", 118.5), ("diastolic", 76.0), ("mean", 90.0)]
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_plain_text() {
+        let parser = VitalValueParser::new();
+        assert!(parser.parse("ALARM OFF").is_none());
+        assert!(parser.parse("---/---").is_none());
+        assert!(parser.parse("120/80 extra").is_none());
+        assert!(parser.parse("").is_none());
+    }
+}
@@ -0,0 +1,440 @@
+// /src/signal/decimation.rs
+// Module: signal.decimation
+// Purpose: Waveform downsampling for bandwidth-limited outputs
+
+use crate::domain::{ProcessedData, ProcessedTrack, TrackType};
+use crate::signal::Biquad;
+
+/// Anti-aliasing cut-off as a fraction of the target sample rate
+const ANTI_ALIAS_RATIO: f64 = 0.4;
+
+/// Quality factors of the two sections of a fourth-order Butterworth low-pass
+/// (1 / (2 cos(π/8)) and 1 / (2 cos(3π/8)))
+const BUTTERWORTH_4_Q: [f64; 2] = [0.541_196_100_146_197, 1.306_562_964_876_376_6];
+
+/// ID SRS: SRS-MOD-DECIMATION-001
+/// Title: Decimation
+///
+/// Description: VRConnect shall downsample waveform points either with a
+/// shape-preserving method bounded by a number of points per record (LTTB,
+/// min/max envelope) or by anti-aliased resampling to a target rate. Textual
+/// form: "lttb:100", "minmax:100", "resample:50".
+///
+/// Version: V1.0
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Decimation {
+    Lttb(usize),
+    MinMax(usize),
+    Resample(f64),
+}
+
+impl Decimation {
+    /// ID SRS: SRS-FN-DECIMATION-001
+    /// Title: parse
+    ///
+    /// Description: VRConnect shall parse a decimation setting; an empty string
+    /// or "none" disables decimation.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `spec` - Decimation setting
+    ///
+    /// # Returns
+    /// Parsed decimation (None if disabled) or error message
+    pub fn parse(spec: &str) -> Result<Option<Self>, String> {
+        let spec = spec.trim();
+        if spec.is_empty() || spec.eq_ignore_ascii_case("none") {
+            return Ok(None);
+        }
+
+        let (method, target) = spec
+            .split_once(':')
+            .ok_or_else(|| format!("Invalid decimation '{}', expected method:target", spec))?;
+        let target = target.trim();
+
+        let decimation = match method.trim().to_lowercase().as_str() {
+            "lttb" | "minmax" => {
+                let points: usize = target
+                    .parse()
+                    .ok()
+                    .filter(|points| *points >= 3)
+                    .ok_or_else(|| format!("Decimation '{}' needs at least 3 points", spec))?;
+                if method.trim().eq_ignore_ascii_case("lttb") {
+                    Decimation::Lttb(points)
+                } else {
+                    Decimation::MinMax(points)
+                }
+            }
+            "resample" => {
+                let rate: f64 = target
+                    .parse()
+                    .ok()
+                    .filter(|rate: &f64| rate.is_finite() && *rate > 0.0)
+                    .ok_or_else(|| format!("Invalid target rate in '{}'", spec))?;
+                Decimation::Resample(rate)
+            }
+            other => return Err(format!("Unknown decimation method: {}", other)),
+        };
+
+        Ok(Some(decimation))
+    }
+
+    /// ID SRS: SRS-FN-DECIMATION-002
+    /// Title: apply
+    ///
+    /// Description: VRConnect shall return a copy of the processed data whose
+    /// waveform tracks are decimated. Waveform statistics still describe the
+    /// full-resolution record.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `data` - Processed vital data
+    ///
+    /// # Returns
+    /// Processed data with decimated waveforms
+    pub fn apply(&self, data: &ProcessedData) -> ProcessedData {
+        let mut decimated = data.clone();
+
+        for room in decimated.rooms.iter_mut() {
+            for track in room.tracks.iter_mut() {
+                self.apply_track(track);
+            }
+        }
+        decimated.refresh_all_tracks();
+
+        decimated
+    }
+
+    /// ID SRS: SRS-FN-DECIMATION-003
+    /// Title: apply_track
+    ///
+    /// Description: VRConnect shall decimate the points of a waveform track in
+    /// place and update its sample rate (average rate for point-bounded methods).
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `track` - Track, modified in place when it is a waveform
+    pub fn apply_track(&self, track: &mut ProcessedTrack) {
+        if track.track_type != TrackType::Waveform {
+            return;
+        }
+        let Some(points) = track.waveform_points.as_ref() else {
+            return;
+        };
+
+        let (decimated, sample_rate) = self.apply_points(points, track.sample_rate);
+        track.waveform_points = Some(decimated);
+        track.sample_rate = sample_rate;
+    }
+
+    /// ID SRS: SRS-FN-DECIMATION-009
+    /// Title: apply_points
+    ///
+    /// Description: VRConnect shall decimate a waveform segment and return the
+    /// resulting sample rate (average rate for point-bounded methods). Without
+    /// a known rate, resampling falls back to a point budget of one second.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `points` - Uniformly sampled points
+    /// * `sample_rate` - Sample rate in Hz, if known
+    ///
+    /// # Returns
+    /// Decimated points and their sample rate
+    pub fn apply_points(&self, points: &[f64], sample_rate: Option<f64>) -> (Vec<f64>, Option<f64>) {
+        let original_len = points.len();
+        let decimated = match *self {
+            Decimation::Lttb(threshold) => lttb(points, threshold),
+            Decimation::MinMax(max_points) => min_max(points, max_points),
+            Decimation::Resample(target_rate) => match sample_rate {
+                Some(rate) if rate > 0.0 => resample(points, rate, target_rate),
+                _ => lttb(points, target_rate.ceil() as usize),
+            },
+        };
+
+        let sample_rate = if decimated.len() < original_len && original_len > 0 {
+            sample_rate.map(|rate| match *self {
+                Decimation::Resample(target_rate) => target_rate.min(rate),
+                _ => rate * decimated.len() as f64 / original_len as f64,
+            })
+        } else {
+            sample_rate
+        };
+
+        (decimated, sample_rate)
+    }
+}
+
+/// ID SRS: SRS-FN-DECIMATION-004
+/// Title: lttb
+///
+/// Description: VRConnect shall downsample points with the
+/// Largest-Triangle-Three-Buckets algorithm (Steinarsson, 2013), keeping the
+/// first and last points and the visually most significant point of each
+/// bucket.
+///
+/// Version: V1.0
+///
+/// # Arguments
+/// * `points` - Uniformly sampled points
+/// * `threshold` - Maximum number of output points
+///
+/// # Returns
+/// Downsampled points
+pub fn lttb(points: &[f64], threshold: usize) -> Vec<f64> {
+    lttb_indices(points, threshold).into_iter().map(|i| points[i]).collect()
+}
+
+/// ID SRS: SRS-FN-DECIMATION-007
+/// Title: lttb_indices
+///
+/// Description: VRConnect shall return the indices of the points selected by
+/// LTTB, so that callers can keep the matching timestamps.
+///
+/// Version: V1.0
+///
+/// # Arguments
+/// * `points` - Uniformly sampled points
+/// * `threshold` - Maximum number of output points
+///
+/// # Returns
+/// Increasing indices of the selected points
+pub fn lttb_indices(points: &[f64], threshold: usize) -> Vec<usize> {
+    if threshold >= points.len() || threshold < 3 {
+        return (0..points.len()).collect();
+    }
+
+    let bucket_size = (points.len() - 2) as f64 / (threshold - 2) as f64;
+    let mut sampled = Vec::with_capacity(threshold);
+    let mut selected = 0usize;
+    sampled.push(0);
+
+    for bucket in 0..threshold - 2 {
+        let start = (bucket as f64 * bucket_size) as usize + 1;
+        let end = ((bucket + 1) as f64 * bucket_size) as usize + 1;
+
+        // Average of the next bucket (the last point for the final bucket)
+        let next_start = end;
+        let next_end = (((bucket + 2) as f64 * bucket_size) as usize + 1).min(points.len());
+        let (avg_x, avg_y) = if next_start < next_end {
+            let count = (next_end - next_start) as f64;
+            (
+                (next_start..next_end).sum::<usize>() as f64 / count,
+                points[next_start..next_end].iter().sum::<f64>() / count,
+            )
+        } else {
+            ((points.len() - 1) as f64, points[points.len() - 1])
+        };
+
+        let (ax, ay) = (selected as f64, points[selected]);
+        let best = (start..end.min(points.len() - 1))
+            .max_by(|&a, &b| {
+                let area = |i: usize| ((ax - avg_x) * (points[i] - ay) - (ax - i as f64) * (avg_y - ay)).abs();
+                area(a).total_cmp(&area(b))
+            })
+            .unwrap_or(start);
+
+        sampled.push(best);
+        selected = best;
+    }
+
+    sampled.push(points.len() - 1);
+    sampled
+}
+
+/// ID SRS: SRS-FN-DECIMATION-005
+/// Title: min_max
+///
+/// Description: VRConnect shall downsample points to a min/max envelope: each
+/// bucket contributes its minimum and maximum in time order, so peaks are
+/// never lost.
+///
+/// Version: V1.0
+///
+/// # Arguments
+/// * `points` - Uniformly sampled points
+/// * `max_points` - Maximum number of output points
+///
+/// # Returns
+/// Downsampled points
+pub fn min_max(points: &[f64], max_points: usize) -> Vec<f64> {
+    min_max_indices(points, max_points).into_iter().map(|i| points[i]).collect()
+}
+
+/// ID SRS: SRS-FN-DECIMATION-008
+/// Title: min_max_indices
+///
+/// Description: VRConnect shall return the indices of the points selected by
+/// the min/max envelope, so that callers can keep the matching timestamps.
+///
+/// Version: V1.0
+///
+/// # Arguments
+/// * `points` - Uniformly sampled points
+/// * `max_points` - Maximum number of output points
+///
+/// # Returns
+/// Increasing indices of the selected points
+pub fn min_max_indices(points: &[f64], max_points: usize) -> Vec<usize> {
+    if max_points >= points.len() || max_points < 2 {
+        return (0..points.len()).collect();
+    }
+
+    let buckets = max_points / 2;
+    let bucket_size = points.len() as f64 / buckets as f64;
+    let mut sampled = Vec::with_capacity(buckets * 2);
+
+    for bucket in 0..buckets {
+        let start = (bucket as f64 * bucket_size) as usize;
+        let end = (((bucket + 1) as f64 * bucket_size) as usize).min(points.len());
+        let slice = &points[start..end];
+
+        let min = (0..slice.len()).min_by(|&a, &b| slice[a].total_cmp(&slice[b]));
+        let max = (0..slice.len()).max_by(|&a, &b| slice[a].total_cmp(&slice[b]));
+
+        if let (Some(min), Some(max)) = (min, max) {
+            let (first, second) = if min <= max { (min, max) } else { (max, min) };
+            sampled.push(start + first);
+            if second != first {
+                sampled.push(start + second);
+            }
+        }
+    }
+
+    sampled
+}
+
+/// ID SRS: SRS-FN-DECIMATION-006
+/// Title: resample
+///
+/// Description: VRConnect shall resample points to a lower rate: a
+/// fourth-order Butterworth low-pass at 0.4 × the target rate, applied
+/// forward then backward (zero phase, eighth-order attenuation, -6 dB at the
+/// cut-off) against aliasing, then linear interpolation at the target rate.
+/// Points are returned unchanged when the target rate is not lower.
+///
+/// Version: V1.0
+///
+/// # Arguments
+/// * `points` - Uniformly sampled points
+/// * `sample_rate` - Input sample rate in Hz
+/// * `target_rate` - Output sample rate in Hz
+///
+/// # Returns
+/// Resampled points
+pub fn resample(points: &[f64], sample_rate: f64, target_rate: f64) -> Vec<f64> {
+    if target_rate >= sample_rate || points.len() < 2 {
+        return points.to_vec();
+    }
+
+    let cutoff = ANTI_ALIAS_RATIO * target_rate;
+    let low_pass = |input: &mut Vec<f64>| {
+        let mut sections = BUTTERWORTH_4_Q.map(|q| Biquad::low_pass_q(sample_rate, cutoff, q));
+        sections.iter_mut().fold(input[0], |x, section| section.prime(x));
+        for sample in input.iter_mut() {
+            *sample = sections.iter_mut().fold(*sample, |x, section| section.process(x));
+        }
+    };
+
+    // Forward-backward filtering cancels the phase delay
+    let mut filtered = points.to_vec();
+    low_pass(&mut filtered);
+    filtered.reverse();
+    low_pass(&mut filtered);
+    filtered.reverse();
+
+    let step = sample_rate / target_rate;
+    let count = ((points.len() as f64) / step).floor().max(1.0) as usize;
+
+    (0..count)
+        .map(|k| {
+            let position = k as f64 * step;
+            let index = position.floor() as usize;
+            let fraction = position - index as f64;
+            match filtered.get(index + 1) {
+                Some(next) => filtered[index] + fraction * (next - filtered[index]),
+                None => filtered[index],
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Decimation::parse("").unwrap(), None);
+        assert_eq!(Decimation::parse("none").unwrap(), None);
+        assert_eq!(Decimation::parse("LTTB:100").unwrap(), Some(Decimation::Lttb(100)));
+        assert_eq!(Decimation::parse("minmax:20").unwrap(), Some(Decimation::MinMax(20)));
+        assert_eq!(Decimation::parse("resample:62.5").unwrap(), Some(Decimation::Resample(62.5)));
+        assert!(Decimation::parse("lttb:2").is_err());
+        assert!(Decimation::parse("resample:0").is_err());
+        assert!(Decimation::parse("median:10").is_err());
+    }
+
+    #[test]
+    fn test_lttb_keeps_spike_and_ends() {
+        let mut points = vec![0.0; 500];
+        points[237] = 5.0;
+        points[499] = 1.0;
+
+        let sampled = lttb(&points, 50);
+        assert_eq!(sampled.len(), 50);
+        assert_eq!(sampled[0], 0.0);
+        assert_eq!(sampled[49], 1.0);
+        assert!(sampled.contains(&5.0));
+    }
+
+    #[test]
+    fn test_min_max_envelope() {
+        let points: Vec<f64> = (0..1000).map(|i| (i as f64 / 10.0).sin()).collect();
+        let sampled = min_max(&points, 100);
+        assert_eq!(sampled.len(), 100);
+        let max = sampled.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let min = sampled.iter().copied().fold(f64::INFINITY, f64::min);
+        assert!(max > 0.99 && min < -0.99);
+    }
+
+    #[test]
+    fn test_resample_removes_aliasing() {
+        // 1 Hz tone plus 90 Hz interference, 500 Hz -> 50 Hz
+        let points: Vec<f64> = (0..1000)
+            .map(|i| {
+                let t = i as f64 / 500.0;
+                (2.0 * PI * t).sin() + 0.5 * (2.0 * PI * 90.0 * t).sin()
+            })
+            .collect();
+
+        let resampled = resample(&points, 500.0, 50.0);
+        assert_eq!(resampled.len(), 100);
+        for (k, value) in resampled.iter().enumerate().skip(10).take(80) {
+            let expected = (2.0 * PI * k as f64 / 50.0).sin();
+            assert!((value - expected).abs() < 0.05, "sample {}: {} vs {}", k, value, expected);
+        }
+    }
+
+    #[test]
+    fn test_apply_track_updates_rate() {
+        let mut track = ProcessedTrack::synthetic("ECG_II", None, "mV", 0, "OR-1", Utc::now());
+        track.track_type = TrackType::Waveform;
+        track.waveform_points = Some(vec![0.0; 500]);
+        track.sample_rate = Some(500.0);
+
+        Decimation::Resample(100.0).apply_track(&mut track);
+        assert_eq!(track.waveform_points.as_ref().map(Vec::len), Some(100));
+        assert_eq!(track.sample_rate, Some(100.0));
+
+        Decimation::Lttb(20).apply_track(&mut track);
+        assert_eq!(track.waveform_points.as_ref().map(Vec::len), Some(20));
+        assert_eq!(track.sample_rate, Some(20.0));
+    }
+}
@@ -0,0 +1,472 @@
+// /src/signal/filter.rs
+// Module: signal.filter
+// Purpose: Second-order IIR filter sections (biquads) and configurable filter chains
+
+use std::f64::consts::PI;
+
+/// Quality factor of notch filters (about 1.7 Hz bandwidth at 50 Hz)
+const NOTCH_Q: f64 = 30.0;
+
+/// ID SRS: SRS-MOD-BIQUAD-001
+/// Title: Biquad
+///
+/// Description: VRConnect shall provide second-order IIR filter sections
+/// (Butterworth response, RBJ cookbook coefficients) in transposed direct
+/// form II, keeping their state between successive calls.
+///
+/// Version: V1.0
+#[derive(Debug, Clone)]
+pub struct Biquad {
+    b0: f64,
+    b1: f64,
+    b2: f64,
+    a1: f64,
+    a2: f64,
+    z1: f64,
+    z2: f64,
+}
+
+impl Biquad {
+    /// ID SRS: SRS-FN-BIQUAD-001
+    /// Title: low_pass
+    ///
+    /// Description: VRConnect shall construct a second-order Butterworth
+    /// low-pass filter.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `sample_rate` - Sampling frequency in Hz
+    /// * `cutoff` - Cut-off frequency in Hz
+    ///
+    /// # Returns
+    /// New Biquad instance
+    pub fn low_pass(sample_rate: f64, cutoff: f64) -> Self {
+        Self::low_pass_q(sample_rate, cutoff, std::f64::consts::FRAC_1_SQRT_2)
+    }
+
+    /// ID SRS: SRS-FN-BIQUAD-008
+    /// Title: low_pass_q
+    ///
+    /// Description: VRConnect shall construct a second-order low-pass
+    /// section with a given quality factor, used as a section of a
+    /// higher-order Butterworth filter.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `sample_rate` - Sampling frequency in Hz
+    /// * `cutoff` - Cut-off frequency in Hz
+    /// * `q` - Quality factor of the section
+    ///
+    /// # Returns
+    /// New Biquad instance
+    pub fn low_pass_q(sample_rate: f64, cutoff: f64, q: f64) -> Self {
+        let (cos_w, alpha) = Self::prewarp(sample_rate, cutoff, q);
+        let b1 = 1.0 - cos_w;
+        Self::normalized(b1 / 2.0, b1, b1 / 2.0, 1.0 + alpha, -2.0 * cos_w, 1.0 - alpha)
+    }
+
+    /// ID SRS: SRS-FN-BIQUAD-002
+    /// Title: high_pass
+    ///
+    /// Description: VRConnect shall construct a second-order Butterworth
+    /// high-pass filter.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `sample_rate` - Sampling frequency in Hz
+    /// * `cutoff` - Cut-off frequency in Hz
+    ///
+    /// # Returns
+    /// New Biquad instance
+    pub fn high_pass(sample_rate: f64, cutoff: f64) -> Self {
+        let (cos_w, alpha) = Self::prewarp(sample_rate, cutoff, std::f64::consts::FRAC_1_SQRT_2);
+        let b0 = (1.0 + cos_w) / 2.0;
+        Self::normalized(b0, -(1.0 + cos_w), b0, 1.0 + alpha, -2.0 * cos_w, 1.0 - alpha)
+    }
+
+    /// ID SRS: SRS-FN-BIQUAD-006
+    /// Title: notch
+    ///
+    /// Description: VRConnect shall construct a narrow band-stop filter used to
+    /// remove mains interference (50/60 Hz).
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `sample_rate` - Sampling frequency in Hz
+    /// * `frequency` - Rejected frequency in Hz
+    ///
+    /// # Returns
+    /// New Biquad instance
+    pub fn notch(sample_rate: f64, frequency: f64) -> Self {
+        let (cos_w, alpha) = Self::prewarp(sample_rate, frequency, NOTCH_Q);
+        Self::normalized(1.0, -2.0 * cos_w, 1.0, 1.0 + alpha, -2.0 * cos_w, 1.0 - alpha)
+    }
+
+    /// ID SRS: SRS-FN-BIQUAD-007
+    /// Title: prime
+    ///
+    /// Description: VRConnect shall set the filter state to its steady state for
+    /// a constant input, avoiding the start-up transient of a signal offset.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `x` - Constant input value
+    ///
+    /// # Returns
+    /// Steady-state output for that input
+    pub fn prime(&mut self, x: f64) -> f64 {
+        let dc_gain = (self.b0 + self.b1 + self.b2) / (1.0 + self.a1 + self.a2);
+        let y = dc_gain * x;
+        self.z1 = y - self.b0 * x;
+        self.z2 = self.b2 * x - self.a2 * y;
+        y
+    }
+
+    /// ID SRS: SRS-FN-BIQUAD-003
+    /// Title: process
+    ///
+    /// Description: VRConnect shall filter one sample, updating the filter state.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `x` - Input sample
+    ///
+    /// # Returns
+    /// Filtered sample
+    pub fn process(&mut self, x: f64) -> f64 {
+        let y = self.b0 * x + self.z1;
+        self.z1 = self.b1 * x - self.a1 * y + self.z2;
+        self.z2 = self.b2 * x - self.a2 * y;
+        y
+    }
+
+    /// ID SRS: SRS-FN-BIQUAD-004
+    /// Title: prewarp
+    ///
+    /// Description: VRConnect shall compute the cosine of the normalized angular
+    /// frequency and the bandwidth term used by the RBJ coefficient formulas,
+    /// clamping the frequency below Nyquist.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `sample_rate` - Sampling frequency in Hz
+    /// * `frequency` - Characteristic frequency in Hz
+    /// * `q` - Quality factor
+    ///
+    /// # Returns
+    /// Tuple: (cos(w0), alpha)
+    fn prewarp(sample_rate: f64, frequency: f64, q: f64) -> (f64, f64) {
+        let frequency = frequency.clamp(1e-6, sample_rate * 0.499);
+        let w0 = 2.0 * PI * frequency / sample_rate;
+        (w0.cos(), w0.sin() / (2.0 * q))
+    }
+
+    /// ID SRS: SRS-FN-BIQUAD-005
+    /// Title: normalized
+    ///
+    /// Description: VRConnect shall build a Biquad from raw coefficients,
+    /// normalizing them by a0.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// New Biquad instance with cleared state
+    fn normalized(b0: f64, b1: f64, b2: f64, a0: f64, a1: f64, a2: f64) -> Self {
+        Self {
+            b0: b0 / a0,
+            b1: b1 / a0,
+            b2: b2 / a0,
+            a1: a1 / a0,
+            a2: a2 / a0,
+            z1: 0.0,
+            z2: 0.0,
+        }
+    }
+}
+
+/// ID SRS: SRS-MOD-FILTERSPEC-001
+/// Title: FilterSpec
+///
+/// Description: VRConnect shall describe one filter of a chain: mains notch,
+/// high-pass (baseline wander removal) or low-pass (smoothing), with its
+/// frequency in Hz. Textual form: "notch:50", "highpass:0.5", "lowpass:40".
+///
+/// Version: V1.0
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FilterSpec {
+    Notch(f64),
+    HighPass(f64),
+    LowPass(f64),
+}
+
+/// ID SRS: SRS-MOD-FILTERRULE-001
+/// Title: FilterRule
+///
+/// Description: VRConnect shall associate a filter chain with the waveform
+/// tracks whose name (ignoring any "Device/" prefix) starts with a pattern,
+/// case-insensitively (e.g. "ECG" matches "Solar8000/ECG_II").
+///
+/// Version: V1.0
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilterRule {
+    pub pattern: String,
+    pub filters: Vec<FilterSpec>,
+}
+
+/// ID SRS: SRS-MOD-FILTERCHAIN-001
+/// Title: FilterChain
+///
+/// Description: VRConnect shall apply a sequence of filters to consecutive
+/// segments of one signal, keeping the filter state between segments so that
+/// segment boundaries do not produce artifacts.
+///
+/// Version: V1.0
+#[derive(Debug, Clone)]
+pub struct FilterChain {
+    sections: Vec<Biquad>,
+    primed: bool,
+}
+
+impl FilterSpec {
+    /// ID SRS: SRS-FN-FILTERSPEC-001
+    /// Title: parse
+    ///
+    /// Description: VRConnect shall parse a filter from its textual form
+    /// "kind:frequency" with kind notch, highpass or lowpass.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `spec` - Filter specification
+    ///
+    /// # Returns
+    /// Parsed filter or error message
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let (kind, frequency) = spec
+            .split_once(':')
+            .ok_or_else(|| format!("Invalid filter '{}', expected kind:frequency", spec))?;
+
+        let frequency: f64 = frequency
+            .trim()
+            .parse()
+            .ok()
+            .filter(|f: &f64| f.is_finite() && *f > 0.0)
+            .ok_or_else(|| format!("Invalid filter frequency in '{}'", spec))?;
+
+        match kind.trim().to_lowercase().as_str() {
+            "notch" => Ok(FilterSpec::Notch(frequency)),
+            "highpass" => Ok(FilterSpec::HighPass(frequency)),
+            "lowpass" => Ok(FilterSpec::LowPass(frequency)),
+            other => Err(format!("Unknown filter kind: {}", other)),
+        }
+    }
+
+    /// ID SRS: SRS-FN-FILTERSPEC-002
+    /// Title: build
+    ///
+    /// Description: VRConnect shall build the filter section for a sampling rate.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `sample_rate` - Sampling frequency in Hz
+    ///
+    /// # Returns
+    /// New Biquad instance
+    pub fn build(&self, sample_rate: f64) -> Biquad {
+        match *self {
+            FilterSpec::Notch(frequency) => Biquad::notch(sample_rate, frequency),
+            FilterSpec::HighPass(cutoff) => Biquad::high_pass(sample_rate, cutoff),
+            FilterSpec::LowPass(cutoff) => Biquad::low_pass(sample_rate, cutoff),
+        }
+    }
+}
+
+impl FilterRule {
+    /// ID SRS: SRS-FN-FILTERRULE-001
+    /// Title: parse_list
+    ///
+    /// Description: VRConnect shall parse filter rules separated by ";", each of
+    /// the form "PATTERN=filter,filter" (e.g.
+    /// "ECG=notch:50,highpass:0.5,lowpass:40;PLETH=lowpass:10"). An empty
+    /// string yields no rules.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `spec` - Rule list
+    ///
+    /// # Returns
+    /// Parsed rules or error message
+    pub fn parse_list(spec: &str) -> Result<Vec<Self>, String> {
+        spec.split(';')
+            .map(str::trim)
+            .filter(|rule| !rule.is_empty())
+            .map(|rule| {
+                let (pattern, filters) = rule
+                    .split_once('=')
+                    .ok_or_else(|| format!("Invalid filter rule '{}', expected PATTERN=filters", rule))?;
+
+                let pattern = pattern.trim();
+                if pattern.is_empty() {
+                    return Err(format!("Missing track pattern in filter rule '{}'", rule));
+                }
+
+                let filters = filters
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|filter| !filter.is_empty())
+                    .map(FilterSpec::parse)
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok(FilterRule {
+                    pattern: pattern.to_uppercase(),
+                    filters,
+                })
+            })
+            .collect()
+    }
+
+    /// ID SRS: SRS-FN-FILTERRULE-002
+    /// Title: matches
+    ///
+    /// Description: VRConnect shall determine whether the rule applies to a
+    /// track name.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `track_name` - Track name, optionally prefixed by "Device/"
+    ///
+    /// # Returns
+    /// True if the short track name starts with the rule pattern
+    pub fn matches(&self, track_name: &str) -> bool {
+        let short_name = track_name.rsplit('/').next().unwrap_or(track_name);
+        short_name.to_uppercase().starts_with(&self.pattern)
+    }
+}
+
+impl FilterChain {
+    /// ID SRS: SRS-FN-FILTERCHAIN-001
+    /// Title: new
+    ///
+    /// Description: VRConnect shall construct a filter chain for a sampling rate.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `filters` - Filters applied in order
+    /// * `sample_rate` - Sampling frequency in Hz
+    ///
+    /// # Returns
+    /// New FilterChain instance
+    pub fn new(filters: &[FilterSpec], sample_rate: f64) -> Self {
+        Self {
+            sections: filters.iter().map(|filter| filter.build(sample_rate)).collect(),
+            primed: false,
+        }
+    }
+
+    /// ID SRS: SRS-FN-FILTERCHAIN-002
+    /// Title: process
+    ///
+    /// Description: VRConnect shall filter a segment in place, continuing from
+    /// the state left by the previous segment. The first segment primes the
+    /// chain with its first sample to avoid an offset transient.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `samples` - Segment samples, replaced by filtered values
+    pub fn process(&mut self, samples: &mut [f64]) {
+        if !self.primed {
+            if let Some(&first) = samples.first() {
+                self.sections.iter_mut().fold(first, |x, section| section.prime(x));
+                self.primed = true;
+            }
+        }
+
+        for sample in samples.iter_mut() {
+            *sample = self
+                .sections
+                .iter_mut()
+                .fold(*sample, |x, section| section.process(x));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn amplitude_after(mut filter: Biquad, sample_rate: f64, frequency: f64) -> f64 {
+        let n = (sample_rate * 4.0) as usize;
+        (0..n)
+            .map(|i| filter.process((2.0 * PI * frequency * i as f64 / sample_rate).sin()))
+            .skip(n / 2)
+            .fold(0.0, |acc: f64, y| acc.max(y.abs()))
+    }
+
+    #[test]
+    fn test_low_pass_response() {
+        assert!(amplitude_after(Biquad::low_pass(500.0, 20.0), 500.0, 2.0) > 0.95);
+        assert!(amplitude_after(Biquad::low_pass(500.0, 20.0), 500.0, 200.0) < 0.02);
+    }
+
+    #[test]
+    fn test_high_pass_response() {
+        assert!(amplitude_after(Biquad::high_pass(500.0, 5.0), 500.0, 0.2) < 0.01);
+        assert!(amplitude_after(Biquad::high_pass(500.0, 5.0), 500.0, 50.0) > 0.95);
+    }
+
+    #[test]
+    fn test_notch_response() {
+        assert!(amplitude_after(Biquad::notch(500.0, 50.0), 500.0, 50.0) < 0.05);
+        assert!(amplitude_after(Biquad::notch(500.0, 50.0), 500.0, 10.0) > 0.95);
+    }
+
+    #[test]
+    fn test_parse_rules() {
+        let rules = FilterRule::parse_list("ECG=notch:50, highpass:0.5,lowpass:40; pleth = lowpass:10").unwrap();
+        assert_eq!(rules.len(), 2);
+        assert_eq!(
+            rules[0].filters,
+            vec![FilterSpec::Notch(50.0), FilterSpec::HighPass(0.5), FilterSpec::LowPass(40.0)]
+        );
+        assert!(rules[1].matches("Solar8000/PLETH"));
+        assert!(!rules[1].matches("ECG_II"));
+
+        assert!(FilterRule::parse_list("").unwrap().is_empty());
+        assert!(FilterRule::parse_list("ECG=bandstop:50").is_err());
+        assert!(FilterRule::parse_list("ECG=notch:-1").is_err());
+        assert!(FilterRule::parse_list("notch:50").is_err());
+    }
+
+    #[test]
+    fn test_chain_continuity_across_segments() {
+        let signal: Vec<f64> = (0..1000)
+            .map(|i| 2.0 + (2.0 * PI * 3.0 * i as f64 / 250.0).sin())
+            .collect();
+        let filters = [FilterSpec::HighPass(0.5), FilterSpec::LowPass(40.0)];
+
+        let mut whole = signal.clone();
+        FilterChain::new(&filters, 250.0).process(&mut whole);
+
+        let mut chain = FilterChain::new(&filters, 250.0);
+        let mut segmented = signal.clone();
+        for segment in segmented.chunks_mut(250) {
+            chain.process(segment);
+        }
+
+        assert_eq!(whole, segmented);
+        // Primed with the offset: no large start-up step
+        assert!(whole[0].abs() < 0.5);
+    }
+}
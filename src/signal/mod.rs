@@ -0,0 +1,17 @@
+// /src/signal/mod.rs
+// Module: signal
+// Purpose: Digital signal processing on waveform points
+
+pub mod decimation;
+pub mod filter;
+pub mod qrs;
+pub mod quality;
+pub mod statistics;
+pub mod waveform_filter;
+
+pub use decimation::Decimation;
+pub use filter::{Biquad, FilterRule};
+pub use qrs::QrsDetector;
+pub use quality::QualityAssessor;
+pub use statistics::WaveformStatistics;
+pub use waveform_filter::WaveformFilter;
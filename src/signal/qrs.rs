@@ -0,0 +1,253 @@
+// /src/signal/qrs.rs
+// Module: signal.qrs
+// Purpose: QRS complex (R-peak) detection on ECG waveforms (Pan–Tompkins)
+
+use crate::signal::Biquad;
+
+/// Pan–Tompkins band-pass lower cut-off (Hz)
+const BAND_LOW_HZ: f64 = 5.0;
+
+/// Pan–Tompkins band-pass upper cut-off (Hz)
+const BAND_HIGH_HZ: f64 = 15.0;
+
+/// Moving-window integration width (s)
+const INTEGRATION_WINDOW_SECS: f64 = 0.150;
+
+/// Physiological refractory period between two QRS complexes (s)
+const REFRACTORY_SECS: f64 = 0.200;
+
+/// Duration used to initialise the adaptive thresholds (s)
+const LEARNING_SECS: f64 = 2.0;
+
+/// ID SRS: SRS-MOD-QRS-001
+/// Title: QrsDetector
+///
+/// Description: VRConnect shall detect R-peaks in an ECG segment with the
+/// Pan–Tompkins algorithm: 5–15 Hz band-pass, five-point derivative, squaring,
+/// 150 ms moving-window integration and adaptive signal/noise thresholds with
+/// a 200 ms refractory period.
+///
+/// Version: V1.0
+#[derive(Debug, Clone)]
+pub struct QrsDetector {
+    sample_rate: f64,
+}
+
+impl QrsDetector {
+    /// ID SRS: SRS-FN-QRS-001
+    /// Title: new
+    ///
+    /// Description: VRConnect shall construct a QrsDetector for a sampling rate.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `sample_rate` - ECG sampling frequency in Hz
+    ///
+    /// # Returns
+    /// New QrsDetector instance
+    pub fn new(sample_rate: f64) -> Self {
+        Self { sample_rate }
+    }
+
+    /// ID SRS: SRS-FN-QRS-002
+    /// Title: detect
+    ///
+    /// Description: VRConnect shall return the sample indices of the R-peaks
+    /// found in an ECG segment. Segments shorter than the learning period
+    /// yield no beats.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `samples` - ECG samples
+    ///
+    /// # Returns
+    /// Increasing R-peak sample indices
+    pub fn detect(&self, samples: &[f64]) -> Vec<usize> {
+        let fs = self.sample_rate;
+        let learning = (LEARNING_SECS * fs) as usize;
+
+        if fs <= 0.0 || samples.len() < learning.max(8) {
+            return Vec::new();
+        }
+
+        let filtered = self.band_pass(samples);
+        let integrated = self.integrate(&filtered);
+
+        let width = ((INTEGRATION_WINDOW_SECS * fs).round() as usize).max(1);
+        let refractory = (REFRACTORY_SECS * fs).round() as usize;
+        // Skip the filter start-up transient
+        let warm_up = refractory;
+
+        let learning_max = integrated[..learning].iter().copied().fold(0.0, f64::max);
+        let learning_mean = integrated[..learning].iter().sum::<f64>() / learning as f64;
+        let mut signal_peak = 0.25 * learning_max;
+        let mut noise_peak = 0.5 * learning_mean;
+
+        let mut beats: Vec<usize> = Vec::new();
+
+        for i in warm_up.max(1)..integrated.len() - 1 {
+            let value = integrated[i];
+            if value <= integrated[i - 1] || value < integrated[i + 1] {
+                continue;
+            }
+
+            let threshold = noise_peak + 0.25 * (signal_peak - noise_peak);
+            let outside_refractory = beats.last().is_none_or(|&last| i - last > refractory);
+
+            if value > threshold && outside_refractory {
+                // The integrated peak lags the R-peak by up to one window width
+                let start = i.saturating_sub(width + refractory / 4);
+                let candidate = (start..=i)
+                    .max_by(|&a, &b| filtered[a].abs().total_cmp(&filtered[b].abs()))
+                    .unwrap_or(i);
+                let r_peak = Self::refine(samples, candidate, refractory / 2);
+
+                if beats.last().is_none_or(|&last| r_peak > last + refractory) {
+                    beats.push(r_peak);
+                }
+                signal_peak = 0.125 * value + 0.875 * signal_peak;
+            } else {
+                noise_peak = 0.125 * value + 0.875 * noise_peak;
+            }
+        }
+
+        beats
+    }
+
+    /// ID SRS: SRS-FN-QRS-003
+    /// Title: refine
+    ///
+    /// Description: VRConnect shall locate the R-peak in the unfiltered ECG
+    /// shortly before the band-passed maximum, compensating the filter delay,
+    /// as the sample deviating most from the local mean (either polarity).
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `samples` - ECG samples
+    /// * `candidate` - Band-passed maximum index
+    /// * `search` - Search half-width in samples
+    ///
+    /// # Returns
+    /// R-peak sample index
+    fn refine(samples: &[f64], candidate: usize, search: usize) -> usize {
+        let start = candidate.saturating_sub(search);
+        let end = (candidate + search / 4).min(samples.len() - 1);
+        let window = &samples[start..=end];
+        let mean = window.iter().sum::<f64>() / window.len() as f64;
+
+        (start..=end)
+            .max_by(|&a, &b| (samples[a] - mean).abs().total_cmp(&(samples[b] - mean).abs()))
+            .unwrap_or(candidate)
+    }
+
+    /// ID SRS: SRS-FN-QRS-004
+    /// Title: band_pass
+    ///
+    /// Description: VRConnect shall band-pass the ECG between 5 and 15 Hz after
+    /// removing the initial offset, emphasising QRS energy.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `samples` - ECG samples
+    ///
+    /// # Returns
+    /// Band-passed samples
+    fn band_pass(&self, samples: &[f64]) -> Vec<f64> {
+        let mut high_pass = Biquad::high_pass(self.sample_rate, BAND_LOW_HZ);
+        let mut low_pass = Biquad::low_pass(self.sample_rate, BAND_HIGH_HZ);
+        let offset = samples[0];
+
+        samples
+            .iter()
+            .map(|x| low_pass.process(high_pass.process(x - offset)))
+            .collect()
+    }
+
+    /// ID SRS: SRS-FN-QRS-005
+    /// Title: integrate
+    ///
+    /// Description: VRConnect shall apply the five-point derivative, squaring
+    /// and moving-window integration of the Pan–Tompkins algorithm.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `filtered` - Band-passed samples
+    ///
+    /// # Returns
+    /// Integrated energy signal, same length as the input
+    fn integrate(&self, filtered: &[f64]) -> Vec<f64> {
+        let width = ((INTEGRATION_WINDOW_SECS * self.sample_rate).round() as usize).max(1);
+        let at = |i: isize| if i < 0 { 0.0 } else { filtered[i as usize] };
+
+        let squared: Vec<f64> = (0..filtered.len() as isize)
+            .map(|n| {
+                let derivative = (2.0 * at(n) + at(n - 1) - at(n - 3) - 2.0 * at(n - 4)) / 8.0;
+                derivative * derivative
+            })
+            .collect();
+
+        let mut integrated = Vec::with_capacity(squared.len());
+        let mut sum = 0.0;
+        for (n, value) in squared.iter().enumerate() {
+            sum += value;
+            if n >= width {
+                sum -= squared[n - width];
+            }
+            integrated.push(sum / width as f64);
+        }
+
+        integrated
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    /// Synthetic ECG: narrow R waves every `rr_secs`, baseline wander and mains noise
+    pub(crate) fn synthetic_ecg(sample_rate: f64, secs: f64, rr_secs: f64, first_beat_secs: f64) -> Vec<f64> {
+        let n = (sample_rate * secs) as usize;
+        (0..n)
+            .map(|i| {
+                let t = i as f64 / sample_rate;
+                let phase = (t - first_beat_secs).rem_euclid(rr_secs);
+                let distance = phase.min(rr_secs - phase);
+                let r_wave = (-(distance / 0.012).powi(2)).exp();
+                let t_wave = 0.2 * (-((phase - 0.3) / 0.05).powi(2)).exp();
+                let wander = 0.3 * (2.0 * PI * 0.25 * t).sin();
+                let mains = 0.02 * (2.0 * PI * 50.0 * t).sin();
+                r_wave + t_wave + wander + mains
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_detect_regular_rhythm() {
+        let fs = 250.0;
+        let ecg = synthetic_ecg(fs, 10.0, 0.8, 0.5);
+        let beats = QrsDetector::new(fs).detect(&ecg);
+
+        assert!(beats.len() >= 11, "detected {} beats", beats.len());
+        for pair in beats.windows(2) {
+            let rr = (pair[1] - pair[0]) as f64 / fs;
+            assert!((rr - 0.8).abs() < 0.02, "RR interval {}", rr);
+        }
+        for beat in &beats {
+            let phase = (*beat as f64 / fs - 0.5).rem_euclid(0.8);
+            assert!(phase.min(0.8 - phase) < 0.02, "beat off R wave at {}", beat);
+        }
+    }
+
+    #[test]
+    fn test_detect_short_segment() {
+        let ecg = synthetic_ecg(250.0, 1.0, 0.8, 0.5);
+        assert!(QrsDetector::new(250.0).detect(&ecg).is_empty());
+        assert!(QrsDetector::new(0.0).detect(&ecg).is_empty());
+    }
+}
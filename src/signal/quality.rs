@@ -0,0 +1,256 @@
+// /src/signal/quality.rs
+// Module: signal.quality
+// Purpose: Signal quality index and artifact flagging per track
+
+use crate::domain::{ArtifactFlag, ProcessedData, ProcessedTrack, SignalQuality, TrackType, VitalSign};
+use std::collections::HashMap;
+
+/// Relative amplitude below which a segment is considered flat
+const FLAT_TOLERANCE: f64 = 1e-6;
+
+/// Fraction of a segment stuck at one extreme that indicates clipping
+const CLIPPING_FRACTION: f64 = 0.05;
+
+/// Mean absolute second difference, relative to the range, above which a segment is noisy
+const NOISE_RATIO: f64 = 0.25;
+
+/// Minimum number of points needed to assess a waveform segment
+const MIN_POINTS: usize = 4;
+
+/// ID SRS: SRS-MOD-QUALITY-001
+/// Title: QualityAssessor
+///
+/// Description: VRConnect shall assess the quality of every track and attach
+/// a score and artifact flags: flat line, clipping/saturation and excessive
+/// noise for waveform segments; out-of-range values and implausible jumps
+/// between consecutive values for recognised numeric vital signs.
+///
+/// Version: V1.0
+pub struct QualityAssessor {
+    previous: HashMap<(String, String, String), f64>,
+}
+
+impl QualityAssessor {
+    /// ID SRS: SRS-FN-QUALITY-001
+    /// Title: new
+    ///
+    /// Description: VRConnect shall construct a QualityAssessor without value
+    /// history.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Returns
+    /// New QualityAssessor instance
+    pub fn new() -> Self {
+        Self {
+            previous: HashMap::new(),
+        }
+    }
+
+    /// ID SRS: SRS-FN-QUALITY-002
+    /// Title: apply
+    ///
+    /// Description: VRConnect shall set the quality of every assessable track
+    /// of the processed data.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `data` - Processed vital data, modified in place
+    pub fn apply(&mut self, data: &mut ProcessedData) {
+        for room in data.rooms.iter_mut() {
+            for track in room.tracks.iter_mut() {
+                track.quality = match track.track_type {
+                    TrackType::Waveform => track.waveform_points.as_deref().and_then(Self::assess_waveform),
+                    _ => self.assess_value(&data.device_id, track),
+                };
+
+                if let Some(quality) = track.quality.as_ref().filter(|quality| !quality.artifacts.is_empty()) {
+                    log::debug!(
+                        "Artifacts on {}/{}/{}: {:?} (score {:.2})",
+                        data.device_id,
+                        track.room_name,
+                        track.name,
+                        quality.artifacts,
+                        quality.score
+                    );
+                }
+            }
+        }
+
+        data.refresh_all_tracks();
+    }
+
+    /// ID SRS: SRS-FN-QUALITY-003
+    /// Title: assess_waveform
+    ///
+    /// Description: VRConnect shall assess a waveform segment: flat line (score
+    /// 0), clipping when at least 5% of the points are stuck at the minimum or
+    /// maximum (a zero baseline, as in capnography, is not clipping), and noise
+    /// when the mean absolute second difference exceeds a quarter of the range
+    /// (each halving the score).
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `points` - Waveform points
+    ///
+    /// # Returns
+    /// Quality, or None if the segment is too short
+    pub fn assess_waveform(points: &[f64]) -> Option<SignalQuality> {
+        if points.len() < MIN_POINTS {
+            return None;
+        }
+
+        let min = points.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = points.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let range = max - min;
+        let scale = min.abs().max(max.abs()).max(1.0);
+
+        if range <= FLAT_TOLERANCE * scale {
+            return Some(SignalQuality {
+                score: 0.0,
+                artifacts: vec![ArtifactFlag::FlatLine],
+            });
+        }
+
+        let mut artifacts = Vec::new();
+        let mut score: f64 = 1.0;
+
+        let stuck_at = |rail: f64| {
+            rail != 0.0
+                && points.iter().filter(|x| (*x - rail).abs() <= FLAT_TOLERANCE * scale).count() as f64
+                    >= CLIPPING_FRACTION * points.len() as f64
+        };
+        if stuck_at(max) || stuck_at(min) {
+            artifacts.push(ArtifactFlag::Clipping);
+            score *= 0.5;
+        }
+
+        let roughness = points
+            .windows(3)
+            .map(|w| (w[2] - 2.0 * w[1] + w[0]).abs())
+            .sum::<f64>()
+            / (points.len() - 2) as f64;
+        if roughness > NOISE_RATIO * range {
+            artifacts.push(ArtifactFlag::Noise);
+            score *= 0.5;
+        }
+
+        Some(SignalQuality { score, artifacts })
+    }
+
+    /// ID SRS: SRS-FN-QUALITY-004
+    /// Title: assess_value
+    ///
+    /// Description: VRConnect shall assess a numeric vital sign value: out of
+    /// the plausible range (score 0), or an implausible jump from the previous
+    /// value of the same track (score 0.3). The value is kept as reference for
+    /// the next one.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `device_id` - VitalRecorder device identifier
+    /// * `track` - Numeric or composite track
+    ///
+    /// # Returns
+    /// Quality, or None if the track is not a recognised vital sign
+    fn assess_value(&mut self, device_id: &str, track: &ProcessedTrack) -> Option<SignalQuality> {
+        let (sign, value) = VitalSign::from_track(track)?;
+        let (low, high) = sign.plausible_range();
+
+        if !(low..=high).contains(&value) {
+            return Some(SignalQuality {
+                score: 0.0,
+                artifacts: vec![ArtifactFlag::OutOfRange],
+            });
+        }
+
+        let key = (device_id.to_string(), track.room_name.clone(), track.name.clone());
+        let previous = self.previous.insert(key, value);
+
+        if previous.is_some_and(|previous| (value - previous).abs() > sign.max_jump()) {
+            return Some(SignalQuality {
+                score: 0.3,
+                artifacts: vec![ArtifactFlag::Jump],
+            });
+        }
+
+        Some(SignalQuality {
+            score: 1.0,
+            artifacts: Vec::new(),
+        })
+    }
+}
+
+impl Default for QualityAssessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::ProcessedRoom;
+    use chrono::Utc;
+    use std::f64::consts::PI;
+
+    fn sine(n: usize) -> Vec<f64> {
+        (0..n).map(|i| (2.0 * PI * i as f64 / 100.0).sin()).collect()
+    }
+
+    #[test]
+    fn test_clean_waveform() {
+        let quality = QualityAssessor::assess_waveform(&sine(500)).unwrap();
+        assert_eq!(quality.score, 1.0);
+        assert!(quality.artifacts.is_empty());
+    }
+
+    #[test]
+    fn test_waveform_artifacts() {
+        let flat = QualityAssessor::assess_waveform(&[0.8; 100]).unwrap();
+        assert_eq!(flat.artifacts, vec![ArtifactFlag::FlatLine]);
+        assert_eq!(flat.score, 0.0);
+
+        let clipped: Vec<f64> = sine(500).iter().map(|x| x.min(0.5)).collect();
+        let quality = QualityAssessor::assess_waveform(&clipped).unwrap();
+        assert_eq!(quality.artifacts, vec![ArtifactFlag::Clipping]);
+
+        let noisy: Vec<f64> = sine(500)
+            .iter()
+            .enumerate()
+            .map(|(i, x)| x + if i % 2 == 0 { 0.6 } else { -0.6 })
+            .collect();
+        let quality = QualityAssessor::assess_waveform(&noisy).unwrap();
+        assert_eq!(quality.artifacts, vec![ArtifactFlag::Noise]);
+        assert_eq!(quality.score, 0.5);
+
+        // Zero baseline (capnography) is not clipping
+        let capno: Vec<f64> = sine(500).iter().map(|x| x.max(0.0) * 38.0).collect();
+        assert!(QualityAssessor::assess_waveform(&capno).unwrap().artifacts.is_empty());
+    }
+
+    #[test]
+    fn test_numeric_range_and_jump() {
+        let mut assessor = QualityAssessor::new();
+        let mut frame = |value: f64| {
+            let mut data = ProcessedData::new(
+                "VR1".to_string(),
+                vec![ProcessedRoom {
+                    room_index: 0,
+                    room_name: "OR-1".to_string(),
+                    tracks: vec![ProcessedTrack::synthetic("HR", Some(value), "bpm", 0, "OR-1", Utc::now())],
+                }],
+            );
+            assessor.apply(&mut data);
+            data.all_tracks[0].quality.clone().unwrap()
+        };
+
+        assert!(frame(72.0).artifacts.is_empty());
+        assert_eq!(frame(140.0).artifacts, vec![ArtifactFlag::Jump]);
+        assert!(frame(142.0).artifacts.is_empty());
+        assert_eq!(frame(400.0).artifacts, vec![ArtifactFlag::OutOfRange]);
+    }
+}
@@ -0,0 +1,265 @@
+// /src/signal/statistics.rs
+// Module: signal.statistics
+// Purpose: Extended waveform statistics (dispersion, percentiles, frequency content)
+
+use crate::domain::{ExtendedWaveformStats, Percentile, ProcessedData, TrackType};
+use rustfft::{num_complex::Complex, FftPlanner};
+
+/// ID SRS: SRS-MOD-STATISTICS-001
+/// Title: WaveformStatistics
+///
+/// Description: VRConnect shall compute extended statistics of waveform
+/// segments: standard deviation, RMS, peak-to-peak, configured percentiles,
+/// zero (mean) crossing rate and, when the sample rate is known, the dominant
+/// frequency of the Hann-windowed spectrum.
+///
+/// Version: V1.0
+pub struct WaveformStatistics {
+    percentiles: Vec<f64>,
+    planner: FftPlanner<f64>,
+}
+
+impl WaveformStatistics {
+    /// ID SRS: SRS-FN-STATISTICS-001
+    /// Title: new
+    ///
+    /// Description: VRConnect shall construct a WaveformStatistics calculator
+    /// for a list of percentiles.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `percentiles` - Percentiles between 0 and 100
+    ///
+    /// # Returns
+    /// New WaveformStatistics instance
+    pub fn new(percentiles: Vec<f64>) -> Self {
+        Self {
+            percentiles,
+            planner: FftPlanner::new(),
+        }
+    }
+
+    /// ID SRS: SRS-FN-STATISTICS-002
+    /// Title: parse_percentiles
+    ///
+    /// Description: VRConnect shall parse a comma-separated percentile list
+    /// (e.g. "5,50,95"), each between 0 and 100.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `spec` - Comma-separated percentiles
+    ///
+    /// # Returns
+    /// Parsed percentiles or error message
+    pub fn parse_percentiles(spec: &str) -> Result<Vec<f64>, String> {
+        spec.split(',')
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+            .map(|p| {
+                p.parse::<f64>()
+                    .ok()
+                    .filter(|value| (0.0..=100.0).contains(value))
+                    .ok_or_else(|| format!("Invalid percentile: {}", p))
+            })
+            .collect()
+    }
+
+    /// ID SRS: SRS-FN-STATISTICS-003
+    /// Title: apply
+    ///
+    /// Description: VRConnect shall attach extended statistics to the waveform
+    /// statistics of every waveform track.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `data` - Processed vital data, modified in place
+    pub fn apply(&mut self, data: &mut ProcessedData) {
+        for room in data.rooms.iter_mut() {
+            for track in room.tracks.iter_mut() {
+                if track.track_type != TrackType::Waveform {
+                    continue;
+                }
+                let (Some(points), Some(stats)) = (&track.waveform_points, track.waveform_stats.as_mut()) else {
+                    continue;
+                };
+                stats.extended = self.compute(points, track.sample_rate);
+            }
+        }
+
+        data.refresh_all_tracks();
+    }
+
+    /// ID SRS: SRS-FN-STATISTICS-004
+    /// Title: compute
+    ///
+    /// Description: VRConnect shall compute extended statistics in a single
+    /// pass for the moments (Welford), a selection per percentile (linear
+    /// interpolation between ranks) and one FFT for the dominant frequency.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `points` - Waveform points
+    /// * `sample_rate` - Sample rate in Hz, if known
+    ///
+    /// # Returns
+    /// Extended statistics, or None for an empty segment
+    pub fn compute(&mut self, points: &[f64], sample_rate: Option<f64>) -> Option<ExtendedWaveformStats> {
+        if points.is_empty() {
+            return None;
+        }
+
+        let mut mean = 0.0;
+        let mut m2 = 0.0;
+        let mut sum_squares = 0.0;
+        let mut min = f64::INFINITY;
+        let mut max = f64::NEG_INFINITY;
+
+        for (i, x) in points.iter().enumerate() {
+            let delta = x - mean;
+            mean += delta / (i + 1) as f64;
+            m2 += delta * (x - mean);
+            sum_squares += x * x;
+            min = min.min(*x);
+            max = max.max(*x);
+        }
+
+        let n = points.len() as f64;
+        let crossings = points
+            .windows(2)
+            .filter(|w| (w[0] - mean) * (w[1] - mean) < 0.0)
+            .count() as f64;
+        let rate = sample_rate.filter(|rate| *rate > 0.0);
+
+        Some(ExtendedWaveformStats {
+            std_dev: (m2 / n).sqrt(),
+            rms: (sum_squares / n).sqrt(),
+            peak_to_peak: max - min,
+            percentiles: self.percentile_values(points),
+            zero_crossing_rate: match rate {
+                Some(rate) => crossings * rate / n,
+                None => crossings / n,
+            },
+            dominant_frequency: rate.and_then(|rate| self.dominant_frequency(points, mean, rate)),
+        })
+    }
+
+    /// ID SRS: SRS-FN-STATISTICS-005
+    /// Title: percentile_values
+    ///
+    /// Description: VRConnect shall compute the configured percentiles with
+    /// linear interpolation between closest ranks.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `points` - Waveform points
+    ///
+    /// # Returns
+    /// Percentile values in configuration order
+    fn percentile_values(&self, points: &[f64]) -> Vec<Percentile> {
+        if self.percentiles.is_empty() {
+            return Vec::new();
+        }
+
+        let mut sorted = points.to_vec();
+        sorted.sort_unstable_by(f64::total_cmp);
+        let last = (sorted.len() - 1) as f64;
+
+        self.percentiles
+            .iter()
+            .map(|&percentile| {
+                let rank = percentile / 100.0 * last;
+                let lower = rank.floor() as usize;
+                let upper = rank.ceil() as usize;
+                let value = sorted[lower] + (rank - lower as f64) * (sorted[upper] - sorted[lower]);
+                Percentile { percentile, value }
+            })
+            .collect()
+    }
+
+    /// ID SRS: SRS-FN-STATISTICS-006
+    /// Title: dominant_frequency
+    ///
+    /// Description: VRConnect shall find the frequency of the largest non-DC
+    /// magnitude in the spectrum of the mean-removed, Hann-windowed segment.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `points` - Waveform points
+    /// * `mean` - Segment mean
+    /// * `sample_rate` - Sample rate in Hz
+    ///
+    /// # Returns
+    /// Dominant frequency in Hz, or None for segments too short or flat
+    fn dominant_frequency(&mut self, points: &[f64], mean: f64, sample_rate: f64) -> Option<f64> {
+        let n = points.len();
+        if n < 4 {
+            return None;
+        }
+
+        let mut buffer: Vec<Complex<f64>> = points
+            .iter()
+            .enumerate()
+            .map(|(i, x)| {
+                let window = 0.5 - 0.5 * (2.0 * std::f64::consts::PI * i as f64 / (n - 1) as f64).cos();
+                Complex::new((x - mean) * window, 0.0)
+            })
+            .collect();
+
+        self.planner.plan_fft_forward(n).process(&mut buffer);
+
+        let (bin, magnitude) = buffer[1..=n / 2]
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (i + 1, c.norm_sqr()))
+            .max_by(|a, b| a.1.total_cmp(&b.1))?;
+
+        (magnitude > 0.0).then(|| bin as f64 * sample_rate / n as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn test_moments_and_percentiles() {
+        let mut statistics = WaveformStatistics::new(vec![0.0, 50.0, 100.0]);
+        let stats = statistics.compute(&[1.0, 2.0, 3.0, 4.0, 5.0], None).unwrap();
+
+        assert!((stats.std_dev - 2.0_f64.sqrt()).abs() < 1e-12);
+        assert!((stats.rms - 11.0_f64.sqrt()).abs() < 1e-12);
+        assert_eq!(stats.peak_to_peak, 4.0);
+        let values: Vec<f64> = stats.percentiles.iter().map(|p| p.value).collect();
+        assert_eq!(values, vec![1.0, 3.0, 5.0]);
+        assert_eq!(stats.dominant_frequency, None);
+    }
+
+    #[test]
+    fn test_frequency_content() {
+        // 1.25 Hz sine with offset, 4 s at 200 Hz
+        let points: Vec<f64> = (0..800)
+            .map(|i| 3.0 + (2.0 * PI * 1.25 * i as f64 / 200.0).sin())
+            .collect();
+        let stats = WaveformStatistics::new(Vec::new()).compute(&points, Some(200.0)).unwrap();
+
+        assert!((stats.dominant_frequency.unwrap() - 1.25).abs() < 1e-9);
+        // Two mean crossings per period
+        assert!((stats.zero_crossing_rate - 2.5).abs() < 0.3);
+    }
+
+    #[test]
+    fn test_parse_percentiles() {
+        assert_eq!(
+            WaveformStatistics::parse_percentiles("5, 50,95").unwrap(),
+            vec![5.0, 50.0, 95.0]
+        );
+        assert!(WaveformStatistics::parse_percentiles("101").is_err());
+    }
+}
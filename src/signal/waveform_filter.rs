@@ -0,0 +1,197 @@
+// /src/signal/waveform_filter.rs
+// Module: signal.waveform_filter
+// Purpose: Per-track stateful filtering of waveform points
+
+use crate::domain::{ProcessedData, ProcessedTrack, TrackType, WaveformStats};
+use crate::signal::filter::{FilterChain, FilterRule};
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+
+/// Maximum gap between consecutive records before the filter state is reset (ms)
+const MAX_GAP_MS: i64 = 1000;
+
+/// ID SRS: SRS-MOD-WAVEFORMFILTER-001
+/// Title: WaveformFilter
+///
+/// Description: VRConnect shall filter waveform points with the chain of the
+/// first matching rule, keeping one filter state per device, room and track
+/// across consecutive records. The state is reset when the sample rate
+/// changes or records are not contiguous.
+///
+/// Version: V1.0
+pub struct WaveformFilter {
+    rules: Vec<FilterRule>,
+    states: HashMap<(String, String, String), TrackFilterState>,
+}
+
+/// Filter state of one waveform track
+#[derive(Debug, Clone)]
+struct TrackFilterState {
+    sample_rate: f64,
+    next_timestamp: DateTime<Utc>,
+    chain: FilterChain,
+}
+
+impl WaveformFilter {
+    /// ID SRS: SRS-FN-WAVEFORMFILTER-001
+    /// Title: new
+    ///
+    /// Description: VRConnect shall construct a WaveformFilter from filter rules.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `rules` - Filter rules, first match wins
+    ///
+    /// # Returns
+    /// New WaveformFilter instance
+    pub fn new(rules: Vec<FilterRule>) -> Self {
+        Self {
+            rules,
+            states: HashMap::new(),
+        }
+    }
+
+    /// ID SRS: SRS-FN-WAVEFORMFILTER-002
+    /// Title: apply
+    ///
+    /// Description: VRConnect shall filter the points of every matching
+    /// waveform track in place and update its statistics and display value.
+    /// Tracks without sample rate are left untouched.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `data` - Processed vital data, modified in place
+    pub fn apply(&mut self, data: &mut ProcessedData) {
+        let mut filtered = false;
+
+        for room in data.rooms.iter_mut() {
+            for track in room.tracks.iter_mut() {
+                filtered |= self.filter_track(&data.device_id, track);
+            }
+        }
+
+        if filtered {
+            data.refresh_all_tracks();
+        }
+    }
+
+    /// ID SRS: SRS-FN-WAVEFORMFILTER-003
+    /// Title: filter_track
+    ///
+    /// Description: VRConnect shall filter one waveform track with its
+    /// persistent filter chain.
+    ///
+    /// Version: V1.0
+    ///
+    /// # Arguments
+    /// * `device_id` - VitalRecorder device identifier
+    /// * `track` - Track, modified in place
+    ///
+    /// # Returns
+    /// True if the track was filtered
+    fn filter_track(&mut self, device_id: &str, track: &mut ProcessedTrack) -> bool {
+        if track.track_type != TrackType::Waveform {
+            return false;
+        }
+        let Some(rule) = self.rules.iter().find(|rule| rule.matches(&track.name)) else {
+            return false;
+        };
+        let (Some(points), Some(sample_rate)) = (track.waveform_points.as_mut(), track.sample_rate) else {
+            return false;
+        };
+        if points.is_empty() || sample_rate <= 0.0 {
+            return false;
+        }
+
+        let key = (device_id.to_string(), track.room_name.clone(), track.name.clone());
+        let contiguous = self.states.get(&key).is_some_and(|state| {
+            state.sample_rate == sample_rate
+                && (track.timestamp - state.next_timestamp).num_milliseconds().abs() <= MAX_GAP_MS
+        });
+
+        if !contiguous {
+            log::debug!("Starting filter chain for {} on {}/{}", track.name, device_id, track.room_name);
+        }
+
+        let state = self
+            .states
+            .entry(key)
+            .and_modify(|state| {
+                if !contiguous {
+                    state.sample_rate = sample_rate;
+                    state.chain = FilterChain::new(&rule.filters, sample_rate);
+                }
+            })
+            .or_insert_with(|| TrackFilterState {
+                sample_rate,
+                next_timestamp: track.timestamp,
+                chain: FilterChain::new(&rule.filters, sample_rate),
+            });
+
+        state.chain.process(points);
+        state.next_timestamp = track.timestamp
+            + Duration::microseconds((points.len() as f64 / sample_rate * 1_000_000.0).round() as i64);
+
+        track.waveform_stats = WaveformStats::from_points(points);
+        if let Some(stats) = &track.waveform_stats {
+            track.display_value = stats.describe();
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::ProcessedRoom;
+    use chrono::TimeZone;
+    use std::f64::consts::PI;
+
+    fn frame(name: &str, points: Vec<f64>, start: DateTime<Utc>) -> ProcessedData {
+        let mut track = ProcessedTrack::synthetic(name, None, "mV", 0, "OR-1", start);
+        track.track_type = TrackType::Waveform;
+        track.waveform_points = Some(points);
+        track.sample_rate = Some(250.0);
+
+        ProcessedData::new(
+            "VR1".to_string(),
+            vec![ProcessedRoom {
+                room_index: 0,
+                room_name: "OR-1".to_string(),
+                tracks: vec![track],
+            }],
+        )
+    }
+
+    #[test]
+    fn test_mains_removed_across_records() {
+        let rules = FilterRule::parse_list("ECG=notch:50,highpass:0.5").unwrap();
+        let mut filter = WaveformFilter::new(rules);
+        let start = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+        let signal: Vec<f64> = (0..2500)
+            .map(|i| 1.5 + 0.5 * (2.0 * PI * 50.0 * i as f64 / 250.0).sin())
+            .collect();
+
+        let mut last = Vec::new();
+        for (second, chunk) in signal.chunks(250).enumerate() {
+            let mut data = frame("Monitor/ECG_II", chunk.to_vec(), start + Duration::seconds(second as i64));
+            filter.apply(&mut data);
+            last = data.all_tracks[0].waveform_points.clone().unwrap();
+            assert_eq!(data.rooms[0].tracks[0].waveform_points.as_ref(), Some(&last));
+        }
+
+        // Offset and 50 Hz interference removed once settled
+        assert!(last.iter().all(|x| x.abs() < 0.05));
+    }
+
+    #[test]
+    fn test_unmatched_track_untouched() {
+        let mut filter = WaveformFilter::new(FilterRule::parse_list("ECG=lowpass:10").unwrap());
+        let mut data = frame("Monitor/PLETH", vec![1.0, -1.0, 1.0, -1.0], Utc::now());
+        filter.apply(&mut data);
+        assert_eq!(data.all_tracks[0].waveform_points, Some(vec![1.0, -1.0, 1.0, -1.0]));
+    }
+}
@@ -170,7 +170,13 @@ impl log::Log for Logger {
     }
 
     fn flush(&self) {
-        // Nothing to flush with file-based logging
+        // Entries are written unbuffered; sync the current file to disk
+        let current = self.current_file.lock().ok().and_then(|current| current.clone());
+        if let Some(path) = current {
+            if let Ok(file) = OpenOptions::new().append(true).open(path) {
+                let _ = file.sync_all();
+            }
+        }
     }
 }
 